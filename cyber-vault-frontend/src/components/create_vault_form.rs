@@ -6,12 +6,13 @@ use std::str::FromStr;
 #[component]
 pub fn CreateVaultForm(
     public_key: Option<Pubkey>,
-    on_create_vault: EventHandler<(String, i64, u64, String)>,
+    on_create_vault: EventHandler<(String, i64, u64, String, bool)>,
 ) -> Element {
     let mut beneficiary = use_signal(|| String::new());
     let mut inactivity_days = use_signal(|| 30);
     let mut amount = use_signal(|| String::new());
     let mut selected_token = use_signal(|| Some(TokenInfo::wrapped_sol()));
+    let mut accept_freeze_authority = use_signal(|| false);
     let mut form_error = use_signal(|| String::new());
     let mut is_creating = use_signal(|| false);
 
@@ -88,6 +89,7 @@ pub fn CreateVaultForm(
             days as i64 * 86400,
             amount_value,
             token_info_clone.mint,
+            *accept_freeze_authority.read(),
         ));
 
         // Reset form after a delay
@@ -97,6 +99,7 @@ pub fn CreateVaultForm(
             amount.set(String::new());
             inactivity_days.set(30);
             selected_token.set(Some(TokenInfo::wrapped_sol()));
+            accept_freeze_authority.set(false);
             is_creating.set(false);
         });
     };
@@ -211,6 +214,20 @@ pub fn CreateVaultForm(
                     }
                 }
 
+                // Freeze authority acknowledgement
+                div {
+                    label { class: "flex items-start space-x-2 text-xs text-gray-400 font-mono cursor-pointer",
+                        input {
+                            r#type: "checkbox",
+                            class: "mt-0.5",
+                            checked: *accept_freeze_authority.read(),
+                            onchange: move |e| accept_freeze_authority.set(e.checked()),
+                            disabled: *is_creating.read()
+                        }
+                        span { "I accept that the token issuer's freeze authority, if any, can lock this vault" }
+                    }
+                }
+
                 // Submit Button
                 button {
                     class: "cypher-button w-full py-4 text-base font-medium disabled:opacity-30 disabled:cursor-not-allowed border-green-400 text-green-400 hover:bg-green-400 hover:text-black font-mono uppercase tracking-wider",
@@ -1,3 +1,4 @@
+use crate::vault_operations::ProtocolStats;
 use crate::wallet::format_public_key;
 use dioxus::prelude::*;
 use solana_sdk::pubkey::Pubkey;
@@ -6,6 +7,7 @@ use solana_sdk::pubkey::Pubkey;
 pub fn Header(
     wallet_connected: bool,
     public_key: Option<Pubkey>,
+    stats: Option<ProtocolStats>,
     on_connect: EventHandler<MouseEvent>,
     on_disconnect: EventHandler<MouseEvent>,
 ) -> Element {
@@ -22,6 +24,14 @@ pub fn Header(
                     }
 
                     div { class: "flex items-center space-x-4",
+                        if let Some(stats) = stats {
+                            div { class: "hidden md:flex items-center space-x-4 text-xs font-mono text-gray-400",
+                                span { "VAULTS: " span { class: "text-green-400", "{stats.active_vaults}" } }
+                                span { "CREATED: " span { class: "text-green-400", "{stats.vaults_created}" } }
+                                span { "CLAIMED: " span { class: "text-green-400", "{stats.vaults_claimed}" } }
+                                span { "CLOSED: " span { class: "text-green-400", "{stats.vaults_closed}" } }
+                            }
+                        }
                         if wallet_connected {
                             div { class: "flex items-center space-x-3 bg-black px-4 py-2 border border-gray-800",
                                 div { class: "flex items-center space-x-2",
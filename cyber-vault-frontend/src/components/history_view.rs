@@ -0,0 +1,42 @@
+use crate::vault_operations::HistoryEntry;
+use crate::wallet::format_public_key;
+use chrono::DateTime;
+use dioxus::prelude::*;
+
+#[component]
+pub fn HistoryView(entries: Vec<HistoryEntry>) -> Element {
+    rsx! {
+        div { class: "cypher-card",
+            div { class: "flex items-center justify-between mb-6 border-b border-gray-800 pb-4",
+                h3 { class: "text-lg text-green-400 font-semibold", "[VAULT] HISTORY" }
+                div { class: "status-badge success", "ENTRIES: {entries.len()}" }
+            }
+
+            if entries.is_empty() {
+                p { class: "text-gray-500 font-mono text-sm", "> No heartbeat history recorded for this vault" }
+            } else {
+                div { class: "space-y-2 font-mono text-xs",
+                    // Newest first
+                    {entries.iter().rev().map(|entry| {
+                        let when = DateTime::from_timestamp(entry.timestamp, 0)
+                            .map(|t| t.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+                            .unwrap_or_else(|| entry.timestamp.to_string());
+                        let signer = format_public_key(&entry.signer);
+
+                        rsx! {
+                            div { class: "flex items-center justify-between border-b border-gray-900 pb-2",
+                                span { class: "text-gray-400", "{when}" }
+                                if entry.is_withdrawal {
+                                    span { class: "text-red-400", "WITHDRAW {entry.amount}" }
+                                } else {
+                                    span { class: "text-green-400", "HEARTBEAT" }
+                                }
+                                span { class: "text-gray-600", "{signer}" }
+                            }
+                        }
+                    })}
+                }
+            }
+        }
+    }
+}
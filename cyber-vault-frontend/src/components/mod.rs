@@ -3,6 +3,7 @@
 
 mod create_vault_form;
 mod header;
+mod history_view;
 mod token_selector;
 pub mod ui;
 mod vault_list;
@@ -10,6 +11,7 @@ mod wallet_connector;
 
 pub use create_vault_form::CreateVaultForm;
 pub use header::Header;
+pub use history_view::HistoryView;
 pub use token_selector::TokenSelector;
 pub use vault_list::VaultList;
 // pub use wallet_connector::WalletConnector; // Currently unused
@@ -14,8 +14,8 @@ mod components;
 mod vault_operations;
 mod wallet;
 
-use components::{CreateVaultForm, Header, VaultList};
-use vault_operations::VaultOperations;
+use components::{CreateVaultForm, Header, HistoryView, VaultList};
+use vault_operations::{HistoryEntry, ProtocolStats, VaultOperations};
 use wallet::{format_public_key, WalletProvider};
 
 // Wallet connection state
@@ -44,6 +44,7 @@ pub struct VaultInfo {
     pub balance: u64,
     pub inactivity_period: i64,
     pub last_heartbeat: i64,
+    pub history: Vec<HistoryEntry>, // empty when the vault keeps no history
 }
 
 // Application state
@@ -55,7 +56,32 @@ pub struct AppState {
     pub error: Option<String>,
     pub success: Option<String>,
     pub is_loading: bool,
-    // pub vault_ops: Option<VaultOperations>,
+    pub stats: Option<ProtocolStats>, // from the GlobalStats account, None until fetched
+}
+
+// Reload the protocol counters shown in the header, keeping the last ones on failure
+async fn refresh_stats(mut state: Signal<AppState>) {
+    match VaultOperations::fetch_global_stats().await {
+        Ok(stats) => state.write().stats = stats,
+        Err(e) => web_sys::console::log_1(&format!("Failed to load protocol stats: {}", e).into()),
+    }
+}
+
+// Reload the connected wallet's vaults from its registry and index, keeping the selection if it still exists
+async fn refresh_vaults(mut state: Signal<AppState>) {
+    let Some(wallet) = state.read().wallet.public_key else {
+        return;
+    };
+    match VaultOperations::fetch_vaults(&wallet).await {
+        Ok(vaults) => {
+            let mut state = state.write();
+            let selected = state.selected_vault.take();
+            state.selected_vault =
+                selected.and_then(|s| vaults.iter().find(|v| v.pubkey == s.pubkey).cloned());
+            state.vaults = vaults;
+        }
+        Err(e) => state.write().error = Some(format!("Failed to load vaults: {}", e)),
+    }
 }
 
 /// The main App component is the root of your application. Every component in Dioxus is a function
@@ -84,6 +110,9 @@ fn App() -> Element {
         });
     }
 
+    // Load the protocol stats once on startup
+    use_future(move || refresh_stats(state));
+
     // Handle wallet connection
     let handle_wallet_connect = Callback::new(move |_| {
         let mut state_clone = state.clone();
@@ -97,6 +126,7 @@ fn App() -> Element {
                     state_clone.write().error = None;
                     state_clone.write().success =
                         Some("Wallet connected successfully! 🎉".to_string());
+                    refresh_vaults(state_clone).await;
                 }
                 Err(e) => {
                     state_clone.write().error =
@@ -133,7 +163,13 @@ fn App() -> Element {
 
     // Action handlers with better UX feedback
     let handle_create_vault = Callback::new({
-        move |(beneficiary, period, amount, mint): (String, i64, u64, String)| {
+        move |(beneficiary, period, amount, mint, acknowledge_freeze_authority): (
+            String,
+            i64,
+            u64,
+            String,
+            bool,
+        )| {
             let mut state_clone = state.clone();
             let wallet_clone = wallet_provider.clone();
 
@@ -194,6 +230,7 @@ fn App() -> Element {
                     &mint_pubkey,
                     period,
                     amount,
+                    acknowledge_freeze_authority,
                 ) {
                     Ok(inst) => inst,
                     Err(e) => {
@@ -225,17 +262,27 @@ fn App() -> Element {
                             signature
                         ));
 
-                        // Add the new vault to the list (mock data for now)
+                        // Add the new vault to the list, with its history if it keeps one
+                        let (vault_pda, _) = VaultOperations::find_vault_pda(
+                            &owner_pubkey,
+                            &beneficiary_pubkey,
+                            &mint_pubkey,
+                        );
+                        let history = VaultOperations::fetch_history(&vault_pda)
+                            .await
+                            .unwrap_or_default();
                         let new_vault = VaultInfo {
-                            pubkey: format!("vault_{}", &signature[..8]),
+                            pubkey: vault_pda.to_string(),
                             owner: owner_pubkey.to_string(),
                             beneficiary: beneficiary_pubkey.to_string(),
                             token_mint: mint_pubkey.to_string(),
                             balance: amount,
                             inactivity_period: period,
                             last_heartbeat: Utc::now().timestamp(),
+                            history,
                         };
                         state_clone.write().vaults.push(new_vault);
+                        refresh_stats(state_clone).await;
                     }
                     Err(e) => {
                         state_clone.write().error =
@@ -279,6 +326,7 @@ fn App() -> Element {
             Header {
                 wallet_connected: state.read().wallet.connected,
                 public_key: state.read().wallet.public_key,
+                stats: state.read().stats.clone(),
                 on_connect: handle_wallet_connect,
                 on_disconnect: handle_wallet_disconnect,
             }
@@ -353,7 +401,32 @@ fn App() -> Element {
                             vaults: state.read().vaults.clone(),
                             selected_vault: state.read().selected_vault.clone(),
                             on_select: Callback::new(move |vault: VaultInfo| {
-                                state.write().selected_vault = Some(vault);
+                                state.write().selected_vault = Some(vault.clone());
+                                // Refresh the history, it grows with every heartbeat and withdrawal
+                                spawn(async move {
+                                    let Ok(vault_pubkey) = Pubkey::from_str(&vault.pubkey) else {
+                                        return;
+                                    };
+                                    match VaultOperations::fetch_history(&vault_pubkey).await {
+                                        Ok(history) => {
+                                            let mut state = state.write();
+                                            for known in state.vaults.iter_mut() {
+                                                if known.pubkey == vault.pubkey {
+                                                    known.history = history.clone();
+                                                }
+                                            }
+                                            if let Some(selected) = state.selected_vault.as_mut() {
+                                                if selected.pubkey == vault.pubkey {
+                                                    selected.history = history;
+                                                }
+                                            }
+                                        }
+                                        Err(e) => {
+                                            state.write().error =
+                                                Some(format!("Failed to load vault history: {}", e));
+                                        }
+                                    }
+                                });
                             }),
                         }
 
@@ -425,6 +498,8 @@ fn App() -> Element {
                                     }
                                 }
                             }
+
+                            HistoryView { entries: selected_vault.history.clone() }
                         }
                     }
                 } else {
@@ -5,19 +5,52 @@ use solana_sdk::{
     transaction::Transaction,
 };
 use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::window;
 
 // Program ID from IDL
 const PROGRAM_ID: &str = "5QTdo3dK7pQZuYrL9ZCUWzAywpohu3gGEJBmbxqAA1gW";
 
+// Cluster the program is deployed to (see Anchor.toml)
+const RPC_URL: &str = "https://api.devnet.solana.com";
+
 // Token Program ID
 const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
 
+// Associated Token Program ID
+const ASSOCIATED_TOKEN_PROGRAM_ID: &str = "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+
 // System Program ID
 const SYSTEM_PROGRAM_ID: &str = "11111111111111111111111111111111";
 
 // Rent Sysvar ID
 const RENT_ID: &str = "SysvarRent111111111111111111111111111111111";
 
+// Wrapped SOL mint
+const NATIVE_MINT: &str = "So11111111111111111111111111111111111111112";
+
+// Entries kept by a vault's heartbeat history (HISTORY_LEN on-chain)
+const HISTORY_LEN: usize = 32;
+
+// One heartbeat or withdrawal from a vault's on-chain history
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoryEntry {
+    pub timestamp: i64,
+    pub amount: u64,
+    pub signer: Pubkey,
+    pub is_withdrawal: bool,
+}
+
+// Protocol-wide counters from the GlobalStats account
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProtocolStats {
+    pub vaults_created: u64,
+    pub active_vaults: u64,
+    pub vaults_claimed: u64,
+    pub vaults_closed: u64,
+}
+
 #[derive(Clone)]
 pub struct VaultOperations {
     pub program_id: Pubkey,
@@ -52,6 +85,16 @@ impl VaultOperations {
         vec![239, 45, 203, 64, 150, 73, 218, 92]
     }
 
+    // Close vault instruction discriminator
+    fn close_vault_discriminator() -> Vec<u8> {
+        vec![141, 103, 17, 126, 72, 75, 29, 29]
+    }
+
+    // Create history instruction discriminator
+    fn create_history_discriminator() -> Vec<u8> {
+        vec![17, 80, 83, 78, 168, 45, 161, 35]
+    }
+
     // Get vault PDA seeds
     fn get_vault_seeds(owner: &Pubkey, beneficiary: &Pubkey, token_mint: &Pubkey) -> Vec<Vec<u8>> {
         vec![
@@ -89,6 +132,273 @@ impl VaultOperations {
         )
     }
 
+    // Find owner registry PDA
+    pub fn find_owner_registry_pda(owner: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[b"owner_registry", owner.as_ref()],
+            &Pubkey::from_str(PROGRAM_ID).unwrap(),
+        )
+    }
+
+    // Find beneficiary index PDA
+    pub fn find_beneficiary_index_pda(beneficiary: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[b"beneficiary_index", beneficiary.as_ref()],
+            &Pubkey::from_str(PROGRAM_ID).unwrap(),
+        )
+    }
+
+    // Find the temporary wSOL account used to pay out native SOL
+    pub fn find_unwrap_pda(vault: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[b"unwrap", vault.as_ref()],
+            &Pubkey::from_str(PROGRAM_ID).unwrap(),
+        )
+    }
+
+    // Find the heartbeat history PDA
+    pub fn find_history_pda(vault: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[b"history", vault.as_ref()],
+            &Pubkey::from_str(PROGRAM_ID).unwrap(),
+        )
+    }
+
+    // Find the protocol-wide stats PDA
+    pub fn find_global_stats_pda() -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"global_stats"], &Pubkey::from_str(PROGRAM_ID).unwrap())
+    }
+
+    // Find the per-mint stats PDA
+    pub fn find_mint_stats_pda(token_mint: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[b"mint_stats", token_mint.as_ref()],
+            &Pubkey::from_str(PROGRAM_ID).unwrap(),
+        )
+    }
+
+    // Find the admin-managed mint policy PDA
+    pub fn find_mint_policy_pda() -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"mint_policy"], &Pubkey::from_str(PROGRAM_ID).unwrap())
+    }
+
+    // Decode a GlobalStats account
+    pub fn parse_global_stats(data: &[u8]) -> Result<ProtocolStats, String> {
+        if data.len() < 8 + 8 * 4 {
+            return Err("Account is too small to be global stats".to_string());
+        }
+
+        let read_u64 = |at: usize| u64::from_le_bytes(data[at..at + 8].try_into().unwrap());
+        Ok(ProtocolStats {
+            vaults_created: read_u64(8),
+            active_vaults: read_u64(16),
+            vaults_claimed: read_u64(24),
+            vaults_closed: read_u64(32),
+        })
+    }
+
+    // Decode a HeartbeatHistory account, oldest entry first
+    pub fn parse_history(data: &[u8]) -> Result<Vec<HistoryEntry>, String> {
+        const ENTRIES_OFFSET: usize = 56; // discriminator, vault, next, len, bump, padding
+        const ENTRY_SIZE: usize = 56;
+        if data.len() < ENTRIES_OFFSET + ENTRY_SIZE * HISTORY_LEN {
+            return Err("Account is too small to be a heartbeat history".to_string());
+        }
+
+        let read_u32 = |at: usize| u32::from_le_bytes(data[at..at + 4].try_into().unwrap());
+        let next = read_u32(40) as usize % HISTORY_LEN;
+        let len = (read_u32(44) as usize).min(HISTORY_LEN);
+
+        // Once the buffer has wrapped, the oldest entry sits at `next`
+        let first = if len == HISTORY_LEN { next } else { 0 };
+        Ok((0..len)
+            .map(|i| {
+                let at = ENTRIES_OFFSET + ((first + i) % HISTORY_LEN) * ENTRY_SIZE;
+                let entry = &data[at..at + ENTRY_SIZE];
+                HistoryEntry {
+                    timestamp: i64::from_le_bytes(entry[0..8].try_into().unwrap()),
+                    amount: u64::from_le_bytes(entry[8..16].try_into().unwrap()),
+                    signer: Pubkey::new_from_array(entry[16..48].try_into().unwrap()),
+                    is_withdrawal: entry[48] == 1,
+                }
+            })
+            .collect())
+    }
+
+    // Decode the vault list of an OwnerRegistry or BeneficiaryIndex (both lay out key, vec, bump)
+    pub fn parse_registry(data: &[u8]) -> Result<Vec<Pubkey>, String> {
+        const VAULTS_OFFSET: usize = 44; // discriminator, owner or beneficiary, vec length
+        if data.len() < VAULTS_OFFSET {
+            return Err("Account is too small to be a vault registry".to_string());
+        }
+
+        let len = u32::from_le_bytes(data[40..44].try_into().unwrap()) as usize;
+        if data.len() < VAULTS_OFFSET + 32 * len {
+            return Err("Vault registry is truncated".to_string());
+        }
+        Ok((0..len)
+            .map(|i| {
+                let at = VAULTS_OFFSET + 32 * i;
+                Pubkey::new_from_array(data[at..at + 32].try_into().unwrap())
+            })
+            .collect())
+    }
+
+    // Decode a Vault account; the balance and history are filled in by the caller
+    pub fn parse_vault(vault: &Pubkey, data: &[u8]) -> Result<(crate::VaultInfo, Pubkey), String> {
+        if data.len() < 8 + 32 * 6 + 8 * 2 {
+            return Err("Account is too small to be a vault".to_string());
+        }
+
+        let read_key = |at: usize| Pubkey::new_from_array(data[at..at + 32].try_into().unwrap());
+        let read_i64 = |at: usize| i64::from_le_bytes(data[at..at + 8].try_into().unwrap());
+        let info = crate::VaultInfo {
+            pubkey: vault.to_string(),
+            owner: read_key(8).to_string(),
+            beneficiary: read_key(40).to_string(),
+            token_mint: read_key(72).to_string(),
+            balance: 0,
+            inactivity_period: read_i64(200),
+            last_heartbeat: read_i64(208),
+            history: Vec::new(),
+        };
+        Ok((info, read_key(104)))
+    }
+
+    // Fetch every vault the wallet owns or inherits, as listed by its registry and index
+    pub async fn fetch_vaults(wallet: &Pubkey) -> Result<Vec<crate::VaultInfo>, String> {
+        let mut addresses = Vec::new();
+        for registry in [
+            Self::find_owner_registry_pda(wallet).0,
+            Self::find_beneficiary_index_pda(wallet).0,
+        ] {
+            if let Some(data) = Self::get_account_data(&registry).await? {
+                for vault in Self::parse_registry(&data)? {
+                    if !addresses.contains(&vault) {
+                        addresses.push(vault);
+                    }
+                }
+            }
+        }
+
+        let mut vaults = Vec::new();
+        for address in addresses {
+            // The registry can briefly list a vault that was just closed
+            let Some(data) = Self::get_account_data(&address).await? else {
+                continue;
+            };
+            let (mut info, vault_token_account) = Self::parse_vault(&address, &data)?;
+            // SPL token accounts keep their amount after the mint and owner keys
+            if let Some(token_data) = Self::get_account_data(&vault_token_account).await? {
+                if token_data.len() >= 72 {
+                    info.balance = u64::from_le_bytes(token_data[64..72].try_into().unwrap());
+                }
+            }
+            vaults.push(info);
+        }
+        Ok(vaults)
+    }
+
+    // Fetch an account's data over JSON-RPC, None when the account does not exist
+    pub async fn get_account_data(pubkey: &Pubkey) -> Result<Option<Vec<u8>>, String> {
+        let window = window().ok_or("No window object available")?;
+        let js_err = |e: JsValue| format!("RPC request failed: {:?}", e);
+
+        let body = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "getAccountInfo",
+            "params": [pubkey.to_string(), { "encoding": "base64" }],
+        });
+        let headers = js_sys::Object::new();
+        js_sys::Reflect::set(&headers, &"Content-Type".into(), &"application/json".into())
+            .map_err(js_err)?;
+        let init = js_sys::Object::new();
+        js_sys::Reflect::set(&init, &"method".into(), &"POST".into()).map_err(js_err)?;
+        js_sys::Reflect::set(&init, &"headers".into(), &headers).map_err(js_err)?;
+        js_sys::Reflect::set(&init, &"body".into(), &body.to_string().into()).map_err(js_err)?;
+
+        let fetch: js_sys::Function = js_sys::Reflect::get(&window, &"fetch".into())
+            .map_err(js_err)?
+            .dyn_into()
+            .map_err(js_err)?;
+        let response = JsFuture::from(js_sys::Promise::from(
+            fetch
+                .call2(&window, &RPC_URL.into(), &init)
+                .map_err(js_err)?,
+        ))
+        .await
+        .map_err(js_err)?;
+        let text: js_sys::Function = js_sys::Reflect::get(&response, &"text".into())
+            .map_err(js_err)?
+            .dyn_into()
+            .map_err(js_err)?;
+        let text = JsFuture::from(js_sys::Promise::from(
+            text.call0(&response).map_err(js_err)?,
+        ))
+        .await
+        .map_err(js_err)?
+        .as_string()
+        .ok_or("RPC response is not text")?;
+
+        let json: serde_json::Value =
+            serde_json::from_str(&text).map_err(|e| format!("Invalid RPC response: {}", e))?;
+        if let Some(error) = json.get("error") {
+            return Err(format!("RPC error: {}", error));
+        }
+        let value = &json["result"]["value"];
+        if value.is_null() {
+            return Ok(None);
+        }
+
+        // Account data comes back as ["<base64>", "base64"]
+        let encoded = value["data"][0]
+            .as_str()
+            .ok_or("RPC response has no account data")?;
+        let atob: js_sys::Function = js_sys::Reflect::get(&window, &"atob".into())
+            .map_err(js_err)?
+            .dyn_into()
+            .map_err(js_err)?;
+        let decoded = atob
+            .call1(&window, &encoded.into())
+            .map_err(js_err)?
+            .as_string()
+            .ok_or("Account data is not valid base64")?;
+        Ok(Some(decoded.chars().map(|c| c as u8).collect()))
+    }
+
+    // Fetch a vault's heartbeat history, empty when the vault keeps none
+    pub async fn fetch_history(vault: &Pubkey) -> Result<Vec<HistoryEntry>, String> {
+        let (history_pda, _) = Self::find_history_pda(vault);
+        match Self::get_account_data(&history_pda).await? {
+            Some(data) => Self::parse_history(&data),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    // Fetch the protocol-wide counters, None before the first vault is created
+    pub async fn fetch_global_stats() -> Result<Option<ProtocolStats>, String> {
+        let (global_stats_pda, _) = Self::find_global_stats_pda();
+        Self::get_account_data(&global_stats_pda)
+            .await?
+            .map(|data| Self::parse_global_stats(&data))
+            .transpose()
+    }
+
+    fn is_native_mint(token_mint: &Pubkey) -> bool {
+        *token_mint == Pubkey::from_str(NATIVE_MINT).unwrap()
+    }
+
+    // Find the beneficiary's associated token account for the vault mint
+    pub fn find_associated_token_address(wallet: &Pubkey, token_mint: &Pubkey) -> Pubkey {
+        let token_program = Pubkey::from_str(TOKEN_PROGRAM_ID).unwrap();
+        Pubkey::find_program_address(
+            &[wallet.as_ref(), token_program.as_ref(), token_mint.as_ref()],
+            &Pubkey::from_str(ASSOCIATED_TOKEN_PROGRAM_ID).unwrap(),
+        )
+        .0
+    }
+
     // Create vault instruction
     pub fn create_vault_instruction(
         &self,
@@ -97,20 +407,32 @@ impl VaultOperations {
         token_mint: &Pubkey,
         inactivity_period: i64,
         amount: u64,
+        acknowledge_freeze_authority: bool,
     ) -> Result<Instruction, String> {
         let (vault, _vault_bump) = Self::find_vault_pda(owner, beneficiary, token_mint);
         let (vault_token_account, _vault_token_bump) = Self::find_vault_token_pda(&vault);
+        let (owner_registry, _) = Self::find_owner_registry_pda(owner);
+        let (beneficiary_index, _) = Self::find_beneficiary_index_pda(beneficiary);
+        let (global_stats, _) = Self::find_global_stats_pda();
+        let (mint_stats, _) = Self::find_mint_stats_pda(token_mint);
+        let (mint_policy, _) = Self::find_mint_policy_pda();
 
         let mut instruction_data = Self::create_vault_discriminator();
         instruction_data.extend_from_slice(&beneficiary.to_bytes());
         instruction_data.extend_from_slice(&inactivity_period.to_le_bytes());
         instruction_data.extend_from_slice(&amount.to_le_bytes());
+        instruction_data.push(acknowledge_freeze_authority as u8);
 
         let accounts = vec![
             solana_sdk::instruction::AccountMeta::new(*owner, true),
             solana_sdk::instruction::AccountMeta::new(vault, false),
             solana_sdk::instruction::AccountMeta::new(vault_token_account, false),
-            solana_sdk::instruction::AccountMeta::new(*owner, false), // owner_token_account (will be set by frontend)
+            if Self::is_native_mint(token_mint) {
+                // No token account: the program wraps the deposit from the owner's SOL
+                solana_sdk::instruction::AccountMeta::new_readonly(self.program_id, false)
+            } else {
+                solana_sdk::instruction::AccountMeta::new(*owner, false) // owner_token_account (will be set by frontend)
+            },
             solana_sdk::instruction::AccountMeta::new_readonly(*token_mint, false),
             solana_sdk::instruction::AccountMeta::new_readonly(
                 Pubkey::from_str(TOKEN_PROGRAM_ID).unwrap(),
@@ -124,6 +446,11 @@ impl VaultOperations {
                 Pubkey::from_str(RENT_ID).unwrap(),
                 false,
             ),
+            solana_sdk::instruction::AccountMeta::new(owner_registry, false),
+            solana_sdk::instruction::AccountMeta::new(beneficiary_index, false),
+            solana_sdk::instruction::AccountMeta::new(global_stats, false),
+            solana_sdk::instruction::AccountMeta::new(mint_stats, false),
+            solana_sdk::instruction::AccountMeta::new_readonly(mint_policy, false),
         ];
 
         Ok(Instruction {
@@ -139,15 +466,20 @@ impl VaultOperations {
         owner: &Pubkey,
         beneficiary: &Pubkey,
         token_mint: &Pubkey,
+        has_history: bool,
     ) -> Result<Instruction, String> {
         let (vault, _vault_bump) = Self::find_vault_pda(owner, beneficiary, token_mint);
 
         let instruction_data = Self::heartbeat_discriminator();
 
-        let accounts = vec![
+        let mut accounts = vec![
             solana_sdk::instruction::AccountMeta::new(vault, false),
             solana_sdk::instruction::AccountMeta::new_readonly(*owner, true),
         ];
+        if has_history {
+            let (history, _) = Self::find_history_pda(&vault);
+            accounts.push(solana_sdk::instruction::AccountMeta::new(history, false));
+        }
 
         Ok(Instruction {
             program_id: self.program_id,
@@ -162,24 +494,52 @@ impl VaultOperations {
         owner: &Pubkey,
         beneficiary: &Pubkey,
         token_mint: &Pubkey,
-        beneficiary_token_account: &Pubkey,
     ) -> Result<Instruction, String> {
         let (vault, _vault_bump) = Self::find_vault_pda(owner, beneficiary, token_mint);
         let (vault_token_account, _vault_token_bump) = Self::find_vault_token_pda(&vault);
+        // Created by the program if the beneficiary does not have one yet
+        let beneficiary_token_account =
+            Self::find_associated_token_address(beneficiary, token_mint);
+        let (owner_registry, _) = Self::find_owner_registry_pda(owner);
+        let (beneficiary_index, _) = Self::find_beneficiary_index_pda(beneficiary);
+        let (global_stats, _) = Self::find_global_stats_pda();
+        let (mint_stats, _) = Self::find_mint_stats_pda(token_mint);
 
         let instruction_data = Self::claim_discriminator();
 
-        let accounts = vec![
+        let mut accounts = vec![
             solana_sdk::instruction::AccountMeta::new(vault, false),
             solana_sdk::instruction::AccountMeta::new(vault_token_account, false),
-            solana_sdk::instruction::AccountMeta::new(*beneficiary_token_account, false),
+            solana_sdk::instruction::AccountMeta::new_readonly(*token_mint, false),
+            solana_sdk::instruction::AccountMeta::new(beneficiary_token_account, false),
             solana_sdk::instruction::AccountMeta::new(*beneficiary, true),
             solana_sdk::instruction::AccountMeta::new_readonly(
                 Pubkey::from_str(TOKEN_PROGRAM_ID).unwrap(),
                 false,
             ),
+            solana_sdk::instruction::AccountMeta::new_readonly(
+                Pubkey::from_str(ASSOCIATED_TOKEN_PROGRAM_ID).unwrap(),
+                false,
+            ),
+            solana_sdk::instruction::AccountMeta::new_readonly(
+                Pubkey::from_str(SYSTEM_PROGRAM_ID).unwrap(),
+                false,
+            ),
+            solana_sdk::instruction::AccountMeta::new(owner_registry, false),
+            solana_sdk::instruction::AccountMeta::new(beneficiary_index, false),
+            solana_sdk::instruction::AccountMeta::new(global_stats, false),
+            solana_sdk::instruction::AccountMeta::new(mint_stats, false),
         ];
 
+        // wSOL vaults pay out native SOL through a temporary account
+        if Self::is_native_mint(token_mint) {
+            let (unwrap_account, _) = Self::find_unwrap_pda(&vault);
+            accounts.extend([
+                solana_sdk::instruction::AccountMeta::new_readonly(self.program_id, false), // no switch
+                solana_sdk::instruction::AccountMeta::new(unwrap_account, false),
+            ]);
+        }
+
         Ok(Instruction {
             program_id: self.program_id,
             accounts,
@@ -195,22 +555,121 @@ impl VaultOperations {
         token_mint: &Pubkey,
         owner_token_account: &Pubkey,
         amount: u64,
+        has_history: bool,
     ) -> Result<Instruction, String> {
         let (vault, _vault_bump) = Self::find_vault_pda(owner, beneficiary, token_mint);
         let (vault_token_account, _vault_token_bump) = Self::find_vault_token_pda(&vault);
+        let (mint_stats, _) = Self::find_mint_stats_pda(token_mint);
 
         let mut instruction_data = Self::emergency_withdraw_discriminator();
         instruction_data.extend_from_slice(&amount.to_le_bytes());
 
+        let native = Self::is_native_mint(token_mint);
+
+        let mut accounts = vec![
+            solana_sdk::instruction::AccountMeta::new(*owner, true),
+            solana_sdk::instruction::AccountMeta::new(vault, false),
+            if native {
+                solana_sdk::instruction::AccountMeta::new_readonly(self.program_id, false)
+            } else {
+                solana_sdk::instruction::AccountMeta::new(*owner_token_account, false)
+            },
+            solana_sdk::instruction::AccountMeta::new(vault_token_account, false),
+            solana_sdk::instruction::AccountMeta::new_readonly(
+                Pubkey::from_str(TOKEN_PROGRAM_ID).unwrap(),
+                false,
+            ),
+            solana_sdk::instruction::AccountMeta::new(mint_stats, false),
+        ];
+
+        // wSOL vaults pay out native SOL through a temporary account
+        if native {
+            let (unwrap_account, _) = Self::find_unwrap_pda(&vault);
+            accounts.extend([
+                solana_sdk::instruction::AccountMeta::new_readonly(*token_mint, false),
+                solana_sdk::instruction::AccountMeta::new(unwrap_account, false),
+                solana_sdk::instruction::AccountMeta::new_readonly(
+                    Pubkey::from_str(SYSTEM_PROGRAM_ID).unwrap(),
+                    false,
+                ),
+            ]);
+        }
+
+        // Vaults with a history must log the withdrawal to it
+        if has_history {
+            if !native {
+                let placeholder =
+                    solana_sdk::instruction::AccountMeta::new_readonly(self.program_id, false);
+                accounts.extend([placeholder.clone(), placeholder.clone(), placeholder]);
+            }
+            let (history, _) = Self::find_history_pda(&vault);
+            accounts.push(solana_sdk::instruction::AccountMeta::new(history, false));
+        }
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: instruction_data,
+        })
+    }
+
+    // Create heartbeat history instruction
+    pub fn create_history_instruction(
+        &self,
+        owner: &Pubkey,
+        beneficiary: &Pubkey,
+        token_mint: &Pubkey,
+    ) -> Result<Instruction, String> {
+        let (vault, _vault_bump) = Self::find_vault_pda(owner, beneficiary, token_mint);
+        let (history, _) = Self::find_history_pda(&vault);
+
+        let instruction_data = Self::create_history_discriminator();
+
+        let accounts = vec![
+            solana_sdk::instruction::AccountMeta::new(*owner, true),
+            solana_sdk::instruction::AccountMeta::new(vault, false),
+            solana_sdk::instruction::AccountMeta::new(history, false),
+            solana_sdk::instruction::AccountMeta::new_readonly(
+                Pubkey::from_str(SYSTEM_PROGRAM_ID).unwrap(),
+                false,
+            ),
+        ];
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: instruction_data,
+        })
+    }
+
+    // Close vault instruction
+    pub fn close_vault_instruction(
+        &self,
+        owner: &Pubkey,
+        beneficiary: &Pubkey,
+        token_mint: &Pubkey,
+    ) -> Result<Instruction, String> {
+        let (vault, _vault_bump) = Self::find_vault_pda(owner, beneficiary, token_mint);
+        let (vault_token_account, _vault_token_bump) = Self::find_vault_token_pda(&vault);
+        let (owner_registry, _) = Self::find_owner_registry_pda(owner);
+        let (beneficiary_index, _) = Self::find_beneficiary_index_pda(beneficiary);
+        let (global_stats, _) = Self::find_global_stats_pda();
+        let (mint_stats, _) = Self::find_mint_stats_pda(token_mint);
+
+        let instruction_data = Self::close_vault_discriminator();
+
         let accounts = vec![
             solana_sdk::instruction::AccountMeta::new(*owner, true),
             solana_sdk::instruction::AccountMeta::new(vault, false),
-            solana_sdk::instruction::AccountMeta::new(*owner_token_account, false),
             solana_sdk::instruction::AccountMeta::new(vault_token_account, false),
+            solana_sdk::instruction::AccountMeta::new(owner_registry, false),
+            solana_sdk::instruction::AccountMeta::new(beneficiary_index, false),
             solana_sdk::instruction::AccountMeta::new_readonly(
                 Pubkey::from_str(TOKEN_PROGRAM_ID).unwrap(),
                 false,
             ),
+            solana_sdk::instruction::AccountMeta::new(global_stats, false),
+            solana_sdk::instruction::AccountMeta::new(mint_stats, false),
         ];
 
         Ok(Instruction {
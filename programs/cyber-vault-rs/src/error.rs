@@ -18,4 +18,109 @@ pub enum VaultError {
     VaultInactive,
     #[msg("Insufficient balance in the vault.")]
     InsufficientBalance,
+    #[msg("Invalid guardian set. Provide 1-5 unique guardians other than the owner.")]
+    InvalidGuardianSet,
+    #[msg("Recovery threshold must be between 1 and the number of guardians.")]
+    InvalidThreshold,
+    #[msg("Signer is not a recovery guardian for this vault.")]
+    NotGuardian,
+    #[msg("New owner cannot be the current owner, the beneficiary or the duress key.")]
+    InvalidNewOwner,
+    #[msg("No owner recovery is pending.")]
+    RecoveryNotPending,
+    #[msg("A different owner recovery is already pending.")]
+    RecoveryMismatch,
+    #[msg("Not enough guardian approvals to rotate the owner.")]
+    RecoveryThresholdNotMet,
+    #[msg("Recovery timelock has not elapsed yet.")]
+    RecoveryTimelockActive,
+    #[msg("Owner activity detected after recovery started. Rotation cancelled.")]
+    RecoveryCancelled,
+    #[msg("Invalid withdrawal policy. A limit needs a period and at least a 1 hour delay.")]
+    InvalidWithdrawPolicy,
+    #[msg("Withdrawal exceeds the per-period limit. Queue it behind the delay instead.")]
+    WithdrawLimitExceeded,
+    #[msg("Queued withdrawal is still inside its veto delay.")]
+    WithdrawalDelayActive,
+    #[msg("Nothing pending to veto.")]
+    NothingToVeto,
+    #[msg("Vault is linked to a master switch. Pass the switch account.")]
+    SwitchRequired,
+    #[msg("Switch account does not match the vault's linked switch.")]
+    SwitchMismatch,
+    #[msg("Vault is already linked to a master switch.")]
+    SwitchAlreadyLinked,
+    #[msg("Vault is not linked to this master switch.")]
+    SwitchNotLinked,
+    #[msg("No vaults provided for the batch heartbeat.")]
+    EmptyVaultBatch,
+    #[msg("Account is not a writable vault PDA of this program.")]
+    InvalidVaultAccount,
+    /// No longer raised, a full registry skips the insert; kept so later codes keep their values
+    #[msg("Vault registry is full. Close or claim a vault first.")]
+    RegistryFull,
+    #[msg("Vault still holds tokens. Withdraw them before closing.")]
+    VaultNotEmpty,
+    #[msg("Token mint does not match the vault.")]
+    InvalidTokenMint,
+    #[msg("SOL wrapping and unwrapping require a wSOL vault.")]
+    NotNativeMint,
+    #[msg("Caller list is oversized, has duplicates or names this program.")]
+    InvalidCallerList,
+    #[msg("cpi_heartbeat must be invoked by another program.")]
+    NotCalledViaCpi,
+    #[msg("Calling program is not on the owner's allowlist.")]
+    CallerNotAllowed,
+    #[msg("Absence window must start now or later and end after it starts.")]
+    InvalidAbsenceWindow,
+    #[msg("An absence is already scheduled or in progress.")]
+    AbsenceAlreadyScheduled,
+    #[msg("Absence would exceed the yearly allowance.")]
+    AbsenceCapExceeded,
+    #[msg("Duress key must differ from the owner and beneficiary.")]
+    InvalidDuressKey,
+    #[msg("Vault is temporarily locked against withdrawals.")]
+    VaultFrozen,
+    #[msg("Absence windows and master switches require timestamp expiry mode.")]
+    ExpiryModeConflict,
+    #[msg("Vault keeps a heartbeat history; pass its account.")]
+    HistoryRequired,
+    #[msg("Mint has a freeze authority that could lock the vault; acknowledge it to proceed.")]
+    MintHasFreezeAuthority,
+    #[msg("Mint is not on the protocol's allowlist.")]
+    MintNotAllowed,
+    #[msg("Mint list is oversized or contains duplicates.")]
+    InvalidMintList,
+    #[msg("Beneficiary vault must be a vault of the same mint, or its heir once it is gone.")]
+    InvalidBeneficiaryVault,
+    #[msg("Deposit schedule needs a source account, an interval of at least an hour and a future end.")]
+    InvalidDepositSchedule,
+    #[msg("Vault has no deposit schedule in effect.")]
+    NoDepositSchedule,
+    #[msg("Next scheduled deposit is not due yet.")]
+    DepositNotDue,
+    #[msg("Installment missed: the source account has not delegated enough to the vault.")]
+    DepositMissed,
+    #[msg("No ownership transfer is pending.")]
+    OwnerTransferNotPending,
+    #[msg("Target is not owned by the program that manages this authority, or a stake handoff is missing the Clock sysvar.")]
+    InvalidHandoffTarget,
+    #[msg("Inactivity period has run out; the authority now goes to the beneficiary.")]
+    HandoffExpired,
+    #[msg("Mint is not an NFT: it needs 0 decimals and a supply of 1.")]
+    InvalidNftMint,
+    #[msg("Pass the next unclaimed NFTs in order, each as its escrow and a token account of the same mint.")]
+    InvalidNftEscrow,
+    #[msg("NFT vault is full. Put the rest of the collection in another vault.")]
+    NftVaultFull,
+    #[msg("No heartbeat memo for this vault signed by its owner in this transaction.")]
+    MemoHeartbeatNotFound,
+    #[msg("No absence window is scheduled or in progress.")]
+    AbsenceNotScheduled,
+    #[msg("Withdrawal period and delay may not exceed a year.")]
+    WithdrawWindowTooLong,
+    #[msg("Duress lock period may not exceed a year.")]
+    DuressLockTooLong,
+    #[msg("Pass exactly one of the beneficiary token account or the unwrap account.")]
+    InvalidClaimDestination,
 }
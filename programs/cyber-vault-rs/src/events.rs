@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+/// Raised by a heartbeat signed with the vault's duress key, for guardians
+/// and the beneficiary to pick up from the transaction logs.
+#[event]
+pub struct DuressAlarm {
+    pub vault: Pubkey,
+    pub owner: Pubkey,
+    pub beneficiary: Pubkey,
+    pub frozen_until: i64,
+}
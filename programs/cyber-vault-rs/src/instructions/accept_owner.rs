@@ -0,0 +1,92 @@
+use crate::error::VaultError;
+use crate::state::{OwnerRegistry, Switch, Vault};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AcceptOwner<'info> {
+    #[account(mut)]
+    pub new_owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::VAULT_SEED,
+            vault.load()?.creator.as_ref(),
+            vault.load()?.beneficiary.as_ref(),
+            vault.load()?.token_mint.as_ref(),
+        ],
+        bump = vault.load()?.bump,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [crate::OWNER_REGISTRY_SEED, vault.load()?.owner.as_ref()],
+        bump = old_owner_registry.bump,
+    )]
+    pub old_owner_registry: Account<'info, OwnerRegistry>,
+
+    #[account(
+        init_if_needed,
+        payer = new_owner,
+        space = OwnerRegistry::INIT_SPACE,
+        seeds = [crate::OWNER_REGISTRY_SEED, new_owner.key().as_ref()],
+        bump
+    )]
+    pub new_owner_registry: Account<'info, OwnerRegistry>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Required while the vault is linked; the previous owner's switch is released
+    #[account(mut, address = vault.load()?.switch @ VaultError::SwitchMismatch)]
+    pub switch: Option<Account<'info, Switch>>,
+}
+
+impl<'info> AcceptOwner<'info> {
+    pub fn accept_owner(&mut self, bumps: &AcceptOwnerBumps) -> Result<()> {
+        let clock = Clock::get()?;
+        let mut vault = self.vault.load_mut()?;
+        require!(vault.is_active(), VaultError::VaultInactive);
+        // A proposal made before the duress lock must wait it out too
+        require!(
+            !vault.is_frozen(clock.unix_timestamp),
+            VaultError::VaultFrozen
+        );
+        require!(
+            vault.pending_owner != Pubkey::default(),
+            VaultError::OwnerTransferNotPending
+        );
+        require!(
+            vault.pending_owner == self.new_owner.key(),
+            VaultError::UnauthorizedAccess
+        );
+
+        // The PDA is derived from `creator`, so only the owner field moves
+        let previous_owner = vault.owner;
+        vault.owner = vault.pending_owner;
+        vault.pending_owner = Pubkey::default();
+        vault.touch_by_owner(&clock);
+
+        // Installments were drawn from the previous owner's token account
+        vault.clear_deposit_schedule();
+
+        // The switch and duress key belong to the previous owner; the vault
+        // falls back to its own timer, just restarted above
+        vault.unlink(self.switch.as_deref_mut())?;
+        vault.duress_key = Pubkey::default();
+        vault.duress_lock_period = 0;
+
+        // Move the vault to the new owner's registry
+        let vault_key = self.vault.key();
+        self.old_owner_registry.remove(&vault_key);
+        self.new_owner_registry.owner = self.new_owner.key();
+        self.new_owner_registry.bump = bumps.new_owner_registry;
+        self.new_owner_registry.insert(vault_key);
+
+        msg!("🔑 Vault ownership transferred.");
+        msg!("Previous owner: {}", previous_owner);
+        msg!("New owner: {}", self.new_owner.key());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use crate::error::VaultError;
+use crate::state::NftVault;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct AddNft<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [crate::NFT_VAULT_SEED, nft_vault.owner.as_ref(), nft_vault.beneficiary.as_ref()],
+        bump = nft_vault.bump,
+        has_one = owner @ VaultError::UnauthorizedAccess,
+        constraint = !nft_vault.is_claiming() @ VaultError::VaultInactive,
+    )]
+    pub nft_vault: Account<'info, NftVault>,
+
+    #[account(constraint = mint.decimals == 0 && mint.supply == 1 @ VaultError::InvalidNftMint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [crate::NFT_ESCROW_SEED, nft_vault.key().as_ref(), mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = nft_vault,
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: mint policy singleton; until the admin creates it no allowlist applies
+    #[account(seeds = [crate::MINT_POLICY_SEED], bump)]
+    pub mint_policy: UncheckedAccount<'info>,
+}
+
+impl<'info> AddNft<'info> {
+    pub fn add_nft(&mut self) -> Result<()> {
+        let clock = Clock::get()?;
+
+        token::transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.owner_token_account.to_account_info(),
+                    to: self.escrow.to_account_info(),
+                    authority: self.owner.to_account_info(),
+                },
+            ),
+            1,
+        )?;
+
+        let nft_vault = &mut self.nft_vault;
+        nft_vault.insert(self.mint.key())?;
+        nft_vault.timer.touch(&clock);
+
+        msg!(
+            "🖼️ NFT {} added to vault {}",
+            self.mint.key(),
+            nft_vault.key()
+        );
+        msg!("NFTs held: {}", nft_vault.mints.len());
+
+        Ok(())
+    }
+}
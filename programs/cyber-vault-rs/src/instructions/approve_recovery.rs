@@ -0,0 +1,79 @@
+use crate::error::VaultError;
+use crate::state::{Recovery, Switch, Vault};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ApproveRecovery<'info> {
+    pub guardian: Signer<'info>,
+
+    #[account(
+        seeds = [
+            crate::VAULT_SEED,
+            vault.load()?.creator.as_ref(),
+            vault.load()?.beneficiary.as_ref(),
+            vault.load()?.token_mint.as_ref(),
+        ],
+        bump = vault.load()?.bump,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [crate::RECOVERY_SEED, vault.key().as_ref()],
+        bump = recovery.bump,
+        has_one = vault,
+    )]
+    pub recovery: Account<'info, Recovery>,
+
+    #[account(address = vault.load()?.switch @ VaultError::SwitchMismatch)]
+    pub switch: Option<Account<'info, Switch>>,
+}
+
+impl<'info> ApproveRecovery<'info> {
+    pub fn approve_recovery(&mut self, new_owner: Pubkey) -> Result<()> {
+        let vault = self.vault.load()?;
+        require!(vault.is_active(), VaultError::VaultInactive);
+        require!(
+            new_owner != vault.owner && new_owner != vault.beneficiary,
+            VaultError::InvalidNewOwner
+        );
+
+        let index = self
+            .recovery
+            .guardian_index(&self.guardian.key())
+            .ok_or(VaultError::NotGuardian)?;
+
+        let clock = Clock::get()?;
+
+        // A rotation the owner has signed over since it started is dead; start
+        // over. Same test as execute_recovery, so a dead rotation can be replaced
+        let (last_heartbeat, _) = vault.liveness(self.switch.as_deref())?;
+        let stale = vault.last_heartbeat > self.recovery.initiated_at
+            || last_heartbeat > self.recovery.initiated_at;
+        match self.recovery.pending_owner {
+            Some(pending) if !stale => {
+                require!(pending == new_owner, VaultError::RecoveryMismatch);
+            }
+            _ => {
+                self.recovery.pending_owner = Some(new_owner);
+                self.recovery.approvals = 0;
+                self.recovery.initiated_at = clock.unix_timestamp;
+            }
+        }
+
+        self.recovery.approvals |= 1 << index;
+
+        msg!(
+            "🗝️ Guardian {} approved owner recovery.",
+            self.guardian.key()
+        );
+        msg!("Proposed owner: {}", new_owner);
+        msg!(
+            "Approvals: {} of {}",
+            self.recovery.approval_count(),
+            self.recovery.threshold
+        );
+
+        Ok(())
+    }
+}
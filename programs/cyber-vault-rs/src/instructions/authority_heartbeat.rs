@@ -0,0 +1,32 @@
+use crate::error::VaultError;
+use crate::state::AuthorityVault;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AuthorityHeartbeat<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::AUTHORITY_VAULT_SEED,
+            authority_vault.target.as_ref(),
+            &[authority_vault.authority as u8],
+        ],
+        bump = authority_vault.bump,
+        has_one = owner @ VaultError::UnauthorizedAccess,
+    )]
+    pub authority_vault: Account<'info, AuthorityVault>,
+}
+
+impl<'info> AuthorityHeartbeat<'info> {
+    pub fn send_heartbeat(&mut self) -> Result<()> {
+        let clock = Clock::get()?;
+        self.authority_vault.timer.touch(&clock);
+
+        msg!("💓 Heartbeat detected. Authority handoff postponed.");
+        msg!("Authority vault: {}", self.authority_vault.key());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use crate::error::VaultError;
+use crate::state::Vault;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelAbsence<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::VAULT_SEED,
+            vault.load()?.creator.as_ref(),
+            vault.load()?.beneficiary.as_ref(),
+            vault.load()?.token_mint.as_ref(),
+        ],
+        bump = vault.load()?.bump,
+        has_one = owner @ VaultError::UnauthorizedAccess,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+}
+
+impl<'info> CancelAbsence<'info> {
+    pub fn cancel_absence(&mut self) -> Result<()> {
+        let clock = Clock::get()?;
+        let now = clock.unix_timestamp;
+        let mut vault = self.vault.load_mut()?;
+
+        require!(vault.is_active(), VaultError::VaultInactive);
+        require!(vault.absence_end > now, VaultError::AbsenceNotScheduled);
+
+        // Only the part of the window still ahead goes back to the allowance
+        let refund = vault.absence_end - vault.absence_start.max(now);
+        vault.absence_used = vault.absence_used.saturating_sub(refund);
+        vault.absence_start = 0;
+        vault.absence_end = 0;
+
+        // This also covers any time already spent inside the window
+        vault.touch_by_owner(&clock);
+
+        msg!("🏠 Absence cancelled for vault {}", self.vault.key());
+        msg!(
+            "Allowance used: {} of {} seconds",
+            vault.absence_used,
+            crate::MAX_ABSENCE_PER_YEAR
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use crate::error::VaultError;
+use crate::state::{Recovery, Vault};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelRecovery<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::VAULT_SEED,
+            vault.load()?.creator.as_ref(),
+            vault.load()?.beneficiary.as_ref(),
+            vault.load()?.token_mint.as_ref(),
+        ],
+        bump = vault.load()?.bump,
+        has_one = owner @ VaultError::UnauthorizedAccess,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [crate::RECOVERY_SEED, vault.key().as_ref()],
+        bump = recovery.bump,
+        has_one = vault,
+    )]
+    pub recovery: Account<'info, Recovery>,
+}
+
+impl<'info> CancelRecovery<'info> {
+    pub fn cancel_recovery(&mut self) -> Result<()> {
+        require!(
+            self.recovery.pending_owner.is_some(),
+            VaultError::RecoveryNotPending
+        );
+
+        let clock = Clock::get()?;
+
+        self.recovery.clear_pending();
+        self.vault.load_mut()?.touch_by_owner(&clock);
+
+        msg!("🚫 Owner recovery cancelled by the current owner.");
+        msg!("💓 Heartbeat updated. Digital sovereignty maintained.");
+
+        Ok(())
+    }
+}
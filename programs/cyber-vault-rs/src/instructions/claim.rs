@@ -1,15 +1,21 @@
-use crate::state::Vault;
+use crate::state::{
+    BeneficiaryIndex, GlobalStats, HeartbeatHistory, MintStats, OwnerRegistry, PendingWithdrawal,
+    Recovery, Switch, Vault,
+};
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::spl_token::native_mint;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 #[derive(Accounts)]
 pub struct Claim<'info> {
     #[account(
         mut,
-        seeds = [b"vault", vault.owner.as_ref(), vault.beneficiary.as_ref(), vault.token_mint.as_ref()],
-        bump = vault.bump
+        seeds = [b"vault", vault.load()?.creator.as_ref(), vault.load()?.beneficiary.as_ref(), vault.load()?.token_mint.as_ref()],
+        bump = vault.load()?.bump,
+        has_one = beneficiary @ crate::error::VaultError::UnauthorizedAccess,
     )]
-    pub vault: Account<'info, Vault>,
+    pub vault: AccountLoader<'info, Vault>,
 
     #[account(
         mut,
@@ -18,57 +24,157 @@ pub struct Claim<'info> {
     )]
     pub vault_token_account: Account<'info, TokenAccount>,
 
-    #[account(mut)]
-    pub beneficiary_token_account: Account<'info, TokenAccount>,
+    #[account(address = vault.load()?.token_mint @ crate::error::VaultError::InvalidTokenMint)]
+    pub token_mint: Account<'info, Mint>,
+
+    /// Payout account; omit it when the claim is unwrapped through `unwrap_account`
+    #[account(
+        init_if_needed,
+        payer = beneficiary,
+        associated_token::mint = token_mint,
+        associated_token::authority = beneficiary,
+    )]
+    pub beneficiary_token_account: Option<Account<'info, TokenAccount>>,
 
     #[account(mut)]
     pub beneficiary: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        seeds = [crate::OWNER_REGISTRY_SEED, vault.load()?.owner.as_ref()],
+        bump = owner_registry.bump,
+    )]
+    pub owner_registry: Account<'info, OwnerRegistry>,
+
+    #[account(
+        mut,
+        seeds = [crate::BENEFICIARY_INDEX_SEED, vault.load()?.beneficiary.as_ref()],
+        bump = beneficiary_index.bump,
+    )]
+    pub beneficiary_index: Account<'info, BeneficiaryIndex>,
+
+    #[account(mut, seeds = [crate::GLOBAL_STATS_SEED], bump = global_stats.bump)]
+    pub global_stats: Account<'info, GlobalStats>,
+
+    #[account(
+        mut,
+        seeds = [crate::MINT_STATS_SEED, vault.load()?.token_mint.as_ref()],
+        bump = mint_stats.bump,
+    )]
+    pub mint_stats: Account<'info, MintStats>,
+
+    #[account(mut, address = vault.load()?.switch @ crate::error::VaultError::SwitchMismatch)]
+    pub switch: Option<Account<'info, Switch>>,
+
+    /// Temporary wSOL account; when passed, the claim is paid out as native SOL
+    #[account(
+        init,
+        payer = beneficiary,
+        token::mint = token_mint,
+        token::authority = vault,
+        seeds = [crate::UNWRAP_SEED, vault.key().as_ref()],
+        bump,
+        constraint = token_mint.key() == native_mint::ID @ crate::error::VaultError::NotNativeMint,
+    )]
+    pub unwrap_account: Option<Account<'info, TokenAccount>>,
+
+    // Closed with the vault, or a vault re-created at this address would inherit them
+    #[account(
+        mut,
+        close = beneficiary,
+        seeds = [crate::RECOVERY_SEED, vault.key().as_ref()],
+        bump = recovery.bump,
+        has_one = vault,
+    )]
+    pub recovery: Option<Account<'info, Recovery>>,
+
+    #[account(
+        mut,
+        close = beneficiary,
+        seeds = [crate::WITHDRAWAL_SEED, vault.key().as_ref()],
+        bump = pending_withdrawal.bump,
+        has_one = vault,
+    )]
+    pub pending_withdrawal: Option<Account<'info, PendingWithdrawal>>,
+
+    /// Required once the vault keeps a history
+    #[account(
+        mut,
+        close = beneficiary,
+        seeds = [crate::HISTORY_SEED, vault.key().as_ref()],
+        bump = history.load()?.bump,
+    )]
+    pub history: Option<AccountLoader<'info, HeartbeatHistory>>,
 }
 
 impl<'info> Claim<'info> {
     pub fn claim_tokens(&mut self) -> Result<()> {
         let clock = Clock::get()?;
 
-        // Get all immutable data first before any mutable borrow
+        // Copy out what we need so the vault data is not borrowed across CPIs
+        let vault = *self.vault.load()?;
         let vault_key = self.vault.key();
-        let vault_beneficiary = self.vault.beneficiary;
+        let vault_beneficiary = vault.beneficiary;
         let vault_balance = self.vault_token_account.amount;
         let vault_info = self.vault.to_account_info();
         let vault_lamports = vault_info.lamports();
         let vault_authority = self.vault.to_account_info();
 
         // Check if inactivity period has expired
-        let time_elapsed = clock.unix_timestamp - self.vault.last_heartbeat;
         require!(
-            time_elapsed >= self.vault.inactivity_period,
+            vault.is_expired(self.switch.as_deref(), &clock)?,
             crate::error::VaultError::VaultNotExpired
         );
 
         require!(vault_balance > 0, crate::error::VaultError::EmptyVault);
+        require!(
+            !vault.has_history() || self.history.is_some(),
+            crate::error::VaultError::HistoryRequired
+        );
 
         // Transfer all tokens to beneficiary
         let seeds = &[
             b"vault",
-            self.vault.owner.as_ref(),
-            self.vault.beneficiary.as_ref(),
-            self.vault.token_mint.as_ref(),
-            &[self.vault.bump],
+            vault.creator.as_ref(),
+            vault.beneficiary.as_ref(),
+            vault.token_mint.as_ref(),
+            &[vault.bump],
         ];
         let signer = &[&seeds[..]];
 
         require!(vault_balance > 0, crate::error::VaultError::EmptyVault);
 
+        let destination = match (&self.beneficiary_token_account, &self.unwrap_account) {
+            (Some(token_account), None) => token_account.to_account_info(),
+            (None, Some(unwrap_account)) => unwrap_account.to_account_info(),
+            _ => return err!(crate::error::VaultError::InvalidClaimDestination),
+        };
         let cpi_accounts = Transfer {
             from: self.vault_token_account.to_account_info(),
-            to: self.beneficiary_token_account.to_account_info(),
+            to: destination,
             authority: vault_authority.clone(),
         };
         let cpi_program = self.token_program.to_account_info();
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
         token::transfer(cpi_ctx, vault_balance)?;
 
+        // Closing a wSOL account releases its balance as lamports
+        if let Some(unwrap_account) = &self.unwrap_account {
+            token::close_account(CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                anchor_spl::token::CloseAccount {
+                    account: unwrap_account.to_account_info(),
+                    destination: self.beneficiary.to_account_info(),
+                    authority: vault_authority.clone(),
+                },
+                signer,
+            ))?;
+        }
+
         // Close the vault token account
         token::close_account(CpiContext::new_with_signer(
             self.token_program.to_account_info(),
@@ -85,7 +191,15 @@ impl<'info> Claim<'info> {
         **self.beneficiary.try_borrow_mut_lamports()? += vault_lamports;
 
         // Mark vault as inactive - the digital will has been executed
-        self.vault.is_active = false;
+        self.vault.load_mut()?.set_active(false);
+        self.vault.load_mut()?.unlink(self.switch.as_deref_mut())?;
+
+        self.owner_registry.remove(&vault_key);
+        self.beneficiary_index.remove(&vault_key);
+
+        self.global_stats.record_claimed();
+        self.mint_stats.retire_vault();
+        self.mint_stats.withdraw(vault_balance);
 
         msg!("💀 Digital silence detected. Dead man's switch activated.");
         msg!("Vault claimed: {}", vault_key);
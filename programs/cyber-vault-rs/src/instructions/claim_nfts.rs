@@ -0,0 +1,161 @@
+use crate::error::VaultError;
+use crate::state::NftVault;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct ClaimNfts<'info> {
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [crate::NFT_VAULT_SEED, nft_vault.owner.as_ref(), nft_vault.beneficiary.as_ref()],
+        bump = nft_vault.bump,
+        has_one = beneficiary @ VaultError::UnauthorizedAccess,
+    )]
+    pub nft_vault: Account<'info, NftVault>,
+
+    pub token_program: Program<'info, Token>,
+    // The next unclaimed NFTs are passed in vault order as (escrow,
+    // destination token account) pairs in `remaining_accounts`; once the
+    // cursor is through, any deferred escrows in any order
+}
+
+impl<'info> ClaimNfts<'info> {
+    pub fn claim_nfts(&mut self, accounts: &'info [AccountInfo<'info>]) -> Result<()> {
+        let clock = Clock::get()?;
+        let nft_vault_key = self.nft_vault.key();
+        let nft_vault = &self.nft_vault;
+
+        // Once a claim has started the owner can no longer interfere, so
+        // later batches do not depend on the vault still being expired
+        require!(
+            nft_vault.is_claiming() || nft_vault.timer.is_expired(&clock),
+            VaultError::VaultNotExpired
+        );
+
+        // Deferred escrows are retried once the cursor has passed every NFT
+        let retrying = nft_vault.unclaimed().is_empty();
+        let pending = if retrying {
+            nft_vault.deferred.len()
+        } else {
+            nft_vault.unclaimed().len()
+        };
+
+        // Every batch must make progress, except the one that closes an empty vault
+        let batch = accounts.len() / 2;
+        require!(
+            accounts.len() % 2 == 0 && batch <= pending && (batch > 0 || pending == 0),
+            VaultError::InvalidNftEscrow
+        );
+
+        let mut deferred = Vec::new();
+        let mut released = Vec::new();
+        for (index, pair) in accounts.chunks(2).enumerate() {
+            let (escrow_info, destination_info) = (&pair[0], &pair[1]);
+
+            // Checks token program ownership and the account layout
+            let escrow = Account::<TokenAccount>::try_from(escrow_info)?;
+
+            // The cursor fixes the order; a retry may pick any deferred escrow
+            let mint = if retrying {
+                require!(
+                    nft_vault.deferred.contains(&escrow.mint),
+                    VaultError::InvalidNftEscrow
+                );
+                escrow.mint
+            } else {
+                nft_vault.unclaimed()[index]
+            };
+
+            let (expected, _) = Pubkey::find_program_address(
+                &[
+                    crate::NFT_ESCROW_SEED,
+                    nft_vault_key.as_ref(),
+                    mint.as_ref(),
+                ],
+                &crate::ID,
+            );
+            require_keys_eq!(expected, escrow_info.key(), VaultError::InvalidNftEscrow);
+
+            // A frozen escrow would fail the whole batch, so the cursor moves on without it
+            if escrow.is_frozen() && !retrying {
+                msg!("Escrow frozen, deferred: {}", mint);
+                deferred.push(mint);
+                continue;
+            }
+
+            let destination = Account::<TokenAccount>::try_from(destination_info)?;
+            require_keys_eq!(destination.mint, mint, VaultError::InvalidTokenMint);
+
+            self.release(escrow_info, destination_info, escrow.amount)?;
+            released.push(mint);
+        }
+
+        let nft_vault = &mut self.nft_vault;
+        if retrying {
+            nft_vault.deferred.retain(|mint| !released.contains(mint));
+        } else {
+            nft_vault.claim_cursor += batch as u32;
+            nft_vault.deferred.extend(deferred);
+        }
+
+        msg!("💀 Digital silence detected. Dead man's switch activated.");
+        msg!("NFT vault: {}", nft_vault_key);
+        msg!("Claimed this batch: {}", released.len());
+        msg!(
+            "Claim progress: {}/{}",
+            nft_vault.claim_cursor,
+            nft_vault.mints.len()
+        );
+        msg!("Deferred: {}", nft_vault.deferred.len());
+
+        // Only the last release closes the vault, so every escrow stays claimable until then
+        if nft_vault.is_settled() {
+            nft_vault.close(self.beneficiary.to_account_info())?;
+        }
+
+        Ok(())
+    }
+
+    /// Pays one escrow out and returns its rent to the beneficiary.
+    fn release(
+        &self,
+        escrow: &AccountInfo<'info>,
+        destination: &AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        let nft_vault = &self.nft_vault;
+        let seeds = &[
+            crate::NFT_VAULT_SEED,
+            nft_vault.owner.as_ref(),
+            nft_vault.beneficiary.as_ref(),
+            &[nft_vault.bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: escrow.clone(),
+                    to: destination.clone(),
+                    authority: nft_vault.to_account_info(),
+                },
+                signer,
+            ),
+            amount,
+        )?;
+
+        token::close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: escrow.clone(),
+                destination: self.beneficiary.to_account_info(),
+                authority: nft_vault.to_account_info(),
+            },
+            signer,
+        ))
+    }
+}
@@ -0,0 +1,242 @@
+use crate::error::VaultError;
+use crate::state::{BeneficiaryIndex, GlobalStats, MintStats, OwnerRegistry, Switch, Vault};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+
+/// Claim for a vault whose beneficiary is another vault. A PDA cannot sign,
+/// so anyone may crank this; funds can only land in the beneficiary vault's
+/// own token account.
+#[derive(Accounts)]
+pub struct ClaimToVault<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::VAULT_SEED,
+            vault.load()?.creator.as_ref(),
+            vault.load()?.beneficiary.as_ref(),
+            vault.load()?.token_mint.as_ref(),
+        ],
+        bump = vault.load()?.bump,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [crate::TOKEN_VAULT_SEED, vault.key().as_ref()],
+        bump
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: the vault named as beneficiary; loaded in the handler while it is
+    /// still active, otherwise the claim falls back to its heir
+    #[account(mut, address = vault.load()?.beneficiary @ VaultError::UnauthorizedAccess)]
+    pub beneficiary_vault: UncheckedAccount<'info>,
+
+    /// Required while the beneficiary vault is active
+    #[account(
+        mut,
+        seeds = [crate::TOKEN_VAULT_SEED, beneficiary_vault.key().as_ref()],
+        bump
+    )]
+    pub beneficiary_vault_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    #[account(
+        mut,
+        seeds = [crate::OWNER_REGISTRY_SEED, vault.load()?.owner.as_ref()],
+        bump = owner_registry.bump,
+    )]
+    pub owner_registry: Account<'info, OwnerRegistry>,
+
+    #[account(
+        mut,
+        seeds = [crate::BENEFICIARY_INDEX_SEED, vault.load()?.beneficiary.as_ref()],
+        bump = beneficiary_index.bump,
+    )]
+    pub beneficiary_index: Account<'info, BeneficiaryIndex>,
+
+    #[account(mut, seeds = [crate::GLOBAL_STATS_SEED], bump = global_stats.bump)]
+    pub global_stats: Account<'info, GlobalStats>,
+
+    #[account(
+        mut,
+        seeds = [crate::MINT_STATS_SEED, vault.load()?.token_mint.as_ref()],
+        bump = mint_stats.bump,
+    )]
+    pub mint_stats: Account<'info, MintStats>,
+
+    #[account(mut, address = vault.load()?.switch @ VaultError::SwitchMismatch)]
+    pub switch: Option<Account<'info, Switch>>,
+
+    /// CHECK: owner key the claimed-out beneficiary vault was derived from;
+    /// only used to re-derive its address
+    pub heir_creator: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: beneficiary of the claimed-out beneficiary vault, checked by
+    /// re-deriving that vault's address; receives the rent
+    #[account(mut)]
+    pub heir: Option<UncheckedAccount<'info>>,
+
+    /// Heir's token account for the mint, required with `heir`
+    #[account(mut)]
+    pub heir_token_account: Option<Account<'info, TokenAccount>>,
+}
+
+impl<'info> ClaimToVault<'info> {
+    pub fn claim_to_vault(&mut self) -> Result<()> {
+        let clock = Clock::get()?;
+
+        // Copy out what we need so the vault data is not borrowed across CPIs
+        let vault = *self.vault.load()?;
+        let vault_key = self.vault.key();
+        let vault_balance = self.vault_token_account.amount;
+        let vault_info = self.vault.to_account_info();
+        let vault_lamports = vault_info.lamports();
+
+        require!(
+            vault.is_expired(self.switch.as_deref(), &clock)?,
+            VaultError::VaultNotExpired
+        );
+        require!(vault_balance > 0, VaultError::EmptyVault);
+
+        // A claimed or closed beneficiary vault must not strand the inheritance
+        let (destination, rent_destination) = if self.beneficiary_vault_is_active(&vault)? {
+            let token_account = self
+                .beneficiary_vault_token_account
+                .as_ref()
+                .ok_or(VaultError::InvalidBeneficiaryVault)?;
+            (
+                token_account.to_account_info(),
+                self.beneficiary_vault.to_account_info(),
+            )
+        } else {
+            self.heir_accounts(&vault)?
+        };
+        let inherited_by_vault = rent_destination.key() == vault.beneficiary;
+
+        let seeds = &[
+            crate::VAULT_SEED,
+            vault.creator.as_ref(),
+            vault.beneficiary.as_ref(),
+            vault.token_mint.as_ref(),
+            &[vault.bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        // Inside a vault the tokens fall under that vault's own switch from here on
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.vault_token_account.to_account_info(),
+                    to: destination,
+                    authority: vault_info.clone(),
+                },
+                signer,
+            ),
+            vault_balance,
+        )?;
+
+        // Rent follows the inheritance rather than rewarding the cranker
+        token::close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: self.vault_token_account.to_account_info(),
+                destination: rent_destination.clone(),
+                authority: vault_info.clone(),
+            },
+            signer,
+        ))?;
+
+        **vault_info.try_borrow_mut_lamports()? -= vault_lamports;
+        **rent_destination.try_borrow_mut_lamports()? += vault_lamports;
+
+        self.vault.load_mut()?.set_active(false);
+        self.vault.load_mut()?.unlink(self.switch.as_deref_mut())?;
+
+        self.owner_registry.remove(&vault_key);
+        self.beneficiary_index.remove(&vault_key);
+
+        // Same mint on both sides, so the locked amount only drops when paid out
+        self.global_stats.record_claimed();
+        self.mint_stats.retire_vault();
+        if !inherited_by_vault {
+            self.mint_stats.withdraw(vault_balance);
+        }
+
+        msg!("💀 Digital silence detected. Dead man's switch activated.");
+        msg!("Vault claimed: {}", vault_key);
+        if inherited_by_vault {
+            msg!("Passed down to vault: {}", vault.beneficiary);
+        } else {
+            msg!(
+                "Beneficiary vault gone, paid to heir: {}",
+                rent_destination.key()
+            );
+        }
+        msg!("Amount claimed: {}", vault_balance);
+        msg!("Cranked by: {}", self.caller.key());
+
+        Ok(())
+    }
+
+    fn beneficiary_vault_is_active(&self, vault: &Vault) -> Result<bool> {
+        // Claimed and closed vaults hand their lamports back, leaving no account
+        if self.beneficiary_vault.owner != &crate::ID {
+            return Ok(false);
+        }
+        let data = self.beneficiary_vault.try_borrow_data()?;
+        require!(
+            data.len() >= Vault::INIT_SPACE && data.starts_with(Vault::DISCRIMINATOR),
+            VaultError::InvalidBeneficiaryVault
+        );
+        let beneficiary_vault: &Vault =
+            bytemuck::from_bytes(&data[Vault::DISCRIMINATOR.len()..Vault::INIT_SPACE]);
+        require_keys_eq!(
+            beneficiary_vault.token_mint,
+            vault.token_mint,
+            VaultError::InvalidBeneficiaryVault
+        );
+        Ok(beneficiary_vault.is_active())
+    }
+
+    /// The beneficiary vault's own beneficiary, proven by re-deriving the
+    /// beneficiary vault address from its creator, heir and mint.
+    fn heir_accounts(&self, vault: &Vault) -> Result<(AccountInfo<'info>, AccountInfo<'info>)> {
+        let (Some(heir_creator), Some(heir), Some(heir_token_account)) =
+            (&self.heir_creator, &self.heir, &self.heir_token_account)
+        else {
+            return err!(VaultError::InvalidBeneficiaryVault);
+        };
+
+        let (expected, _) = Pubkey::find_program_address(
+            &[
+                crate::VAULT_SEED,
+                heir_creator.key().as_ref(),
+                heir.key().as_ref(),
+                vault.token_mint.as_ref(),
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            expected,
+            vault.beneficiary,
+            VaultError::InvalidBeneficiaryVault
+        );
+        require_keys_eq!(
+            heir_token_account.owner,
+            heir.key(),
+            VaultError::InvalidBeneficiaryVault
+        );
+        require_keys_eq!(
+            heir_token_account.mint,
+            vault.token_mint,
+            VaultError::InvalidBeneficiaryVault
+        );
+
+        Ok((heir_token_account.to_account_info(), heir.to_account_info()))
+    }
+}
@@ -0,0 +1,142 @@
+use crate::error::VaultError;
+use crate::state::{
+    BeneficiaryIndex, GlobalStats, HeartbeatHistory, MintStats, OwnerRegistry, PendingWithdrawal,
+    Recovery, Switch, Vault,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct CloseVault<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            crate::VAULT_SEED,
+            vault.load()?.creator.as_ref(),
+            vault.load()?.beneficiary.as_ref(),
+            vault.load()?.token_mint.as_ref(),
+        ],
+        bump = vault.load()?.bump,
+        has_one = owner @ VaultError::UnauthorizedAccess,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [crate::TOKEN_VAULT_SEED, vault.key().as_ref()],
+        bump
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [crate::OWNER_REGISTRY_SEED, owner.key().as_ref()],
+        bump = owner_registry.bump,
+    )]
+    pub owner_registry: Account<'info, OwnerRegistry>,
+
+    #[account(
+        mut,
+        seeds = [crate::BENEFICIARY_INDEX_SEED, vault.load()?.beneficiary.as_ref()],
+        bump = beneficiary_index.bump,
+    )]
+    pub beneficiary_index: Account<'info, BeneficiaryIndex>,
+
+    pub token_program: Program<'info, Token>,
+
+    #[account(mut, seeds = [crate::GLOBAL_STATS_SEED], bump = global_stats.bump)]
+    pub global_stats: Account<'info, GlobalStats>,
+
+    #[account(
+        mut,
+        seeds = [crate::MINT_STATS_SEED, vault.load()?.token_mint.as_ref()],
+        bump = mint_stats.bump,
+    )]
+    pub mint_stats: Account<'info, MintStats>,
+
+    /// Required while the vault is linked, so the switch stops counting it
+    #[account(mut, address = vault.load()?.switch @ VaultError::SwitchMismatch)]
+    pub switch: Option<Account<'info, Switch>>,
+
+    // Closed with the vault, or a vault re-created at this address would inherit them
+    #[account(
+        mut,
+        close = owner,
+        seeds = [crate::RECOVERY_SEED, vault.key().as_ref()],
+        bump = recovery.bump,
+        has_one = vault,
+    )]
+    pub recovery: Option<Account<'info, Recovery>>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [crate::WITHDRAWAL_SEED, vault.key().as_ref()],
+        bump = pending_withdrawal.bump,
+        has_one = vault,
+    )]
+    pub pending_withdrawal: Option<Account<'info, PendingWithdrawal>>,
+
+    /// Required once the vault keeps a history
+    #[account(
+        mut,
+        close = owner,
+        seeds = [crate::HISTORY_SEED, vault.key().as_ref()],
+        bump = history.load()?.bump,
+    )]
+    pub history: Option<AccountLoader<'info, HeartbeatHistory>>,
+}
+
+impl<'info> CloseVault<'info> {
+    pub fn close_vault(&mut self) -> Result<()> {
+        // Funds leave only through emergency_withdraw so withdrawal limits still apply
+        require!(
+            self.vault_token_account.amount == 0,
+            VaultError::VaultNotEmpty
+        );
+
+        let vault = *self.vault.load()?;
+        require!(
+            !vault.has_history() || self.history.is_some(),
+            VaultError::HistoryRequired
+        );
+
+        let seeds = &[
+            crate::VAULT_SEED,
+            vault.creator.as_ref(),
+            vault.beneficiary.as_ref(),
+            vault.token_mint.as_ref(),
+            &[vault.bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        token::close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: self.vault_token_account.to_account_info(),
+                destination: self.owner.to_account_info(),
+                authority: self.vault.to_account_info(),
+            },
+            signer,
+        ))?;
+
+        let vault_key = self.vault.key();
+        self.owner_registry.remove(&vault_key);
+        self.beneficiary_index.remove(&vault_key);
+        self.vault.load_mut()?.set_active(false);
+        self.vault.load_mut()?.unlink(self.switch.as_deref_mut())?;
+
+        // The token account was empty, so nothing is left to unlock
+        self.global_stats.record_closed();
+        self.mint_stats.retire_vault();
+
+        msg!("🗑️ Vault {} closed by owner.", vault_key);
+        msg!("Rent returned to: {}", self.owner.key());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use crate::error::VaultError;
+use crate::state::{Contribution, MintStats, Vault};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct Contribute<'info> {
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    #[account(
+        seeds = [
+            crate::VAULT_SEED,
+            vault.load()?.creator.as_ref(),
+            vault.load()?.beneficiary.as_ref(),
+            vault.load()?.token_mint.as_ref(),
+        ],
+        bump = vault.load()?.bump,
+        constraint = vault.load()?.is_active() @ VaultError::VaultInactive,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [crate::TOKEN_VAULT_SEED, vault.key().as_ref()],
+        bump
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = vault.load()?.token_mint,
+        token::authority = contributor,
+    )]
+    pub contributor_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        space = Contribution::INIT_SPACE,
+        seeds = [crate::CONTRIBUTION_SEED, vault.key().as_ref(), contributor.key().as_ref()],
+        bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    #[account(
+        mut,
+        seeds = [crate::MINT_STATS_SEED, vault.load()?.token_mint.as_ref()],
+        bump = mint_stats.bump,
+    )]
+    pub mint_stats: Account<'info, MintStats>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Contribute<'info> {
+    pub fn contribute(&mut self, amount: u64, bumps: &ContributeBumps) -> Result<()> {
+        require!(amount > 0, VaultError::InsufficientBalance);
+
+        let clock = Clock::get()?;
+
+        // Gifts are not proof of life: the vault's timer is left alone
+        token::transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.contributor_token_account.to_account_info(),
+                    to: self.vault_token_account.to_account_info(),
+                    authority: self.contributor.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let contribution = &mut self.contribution;
+        contribution.vault = self.vault.key();
+        contribution.contributor = self.contributor.key();
+        contribution.total_amount = contribution.total_amount.saturating_add(amount);
+        contribution.last_contributed_at = clock.unix_timestamp;
+        contribution.bump = bumps.contribution;
+
+        self.mint_stats.deposit(amount);
+
+        msg!("🎁 Contribution received for vault {}", self.vault.key());
+        msg!("Contributor: {}", self.contributor.key());
+        msg!("Amount: {}", amount);
+        msg!("Contributor total: {}", contribution.total_amount);
+
+        Ok(())
+    }
+}
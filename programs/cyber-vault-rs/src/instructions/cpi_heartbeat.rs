@@ -0,0 +1,71 @@
+use crate::error::VaultError;
+use crate::state::{record_heartbeat, CallerAllowlist, HeartbeatHistory, Vault};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::get_instruction_relative;
+
+#[derive(Accounts)]
+pub struct CpiHeartbeat<'info> {
+    #[account(
+        mut,
+        seeds = [
+            crate::VAULT_SEED,
+            vault.load()?.creator.as_ref(),
+            vault.load()?.beneficiary.as_ref(),
+            vault.load()?.token_mint.as_ref(),
+        ],
+        bump = vault.load()?.bump,
+        has_one = owner @ VaultError::UnauthorizedAccess,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [crate::CALLER_ALLOWLIST_SEED, owner.key().as_ref()],
+        bump = caller_allowlist.bump,
+        has_one = owner @ VaultError::UnauthorizedAccess,
+    )]
+    pub caller_allowlist: Account<'info, CallerAllowlist>,
+
+    /// CHECK: address-checked instructions sysvar, read to find the calling program
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [crate::HISTORY_SEED, vault.key().as_ref()],
+        bump = history.load()?.bump,
+    )]
+    pub history: Option<AccountLoader<'info, HeartbeatHistory>>,
+}
+
+impl<'info> CpiHeartbeat<'info> {
+    pub fn send_heartbeat(&mut self) -> Result<()> {
+        // The top-level instruction belongs to the program that made this CPI
+        let outer = get_instruction_relative(0, &self.instructions.to_account_info())?;
+        require!(outer.program_id != crate::ID, VaultError::NotCalledViaCpi);
+        require!(
+            self.caller_allowlist.allows(&outer.program_id),
+            VaultError::CallerNotAllowed
+        );
+
+        // Signer privileges can also come from a PDA of the caller, so insist
+        // that the owner signed the outer transaction itself
+        require!(
+            outer
+                .accounts
+                .iter()
+                .any(|meta| meta.pubkey == self.owner.key() && meta.is_signer),
+            VaultError::UnauthorizedAccess
+        );
+
+        let clock = Clock::get()?;
+        let mut vault = self.vault.load_mut()?;
+        record_heartbeat(&mut vault, self.history.as_ref(), &clock, self.owner.key())?;
+
+        msg!("💓 Heartbeat relayed by program {}", outer.program_id);
+        msg!("⏰ Dead man's switch reset. Vault remains secured.");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+use crate::error::VaultError;
+use crate::state::{AuthorityVault, HandoffAuthority, HeartbeatTimer};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(beneficiary: Pubkey, inactivity_period: i64, authority: HandoffAuthority)]
+pub struct CreateAuthorityVault<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = AuthorityVault::INIT_SPACE,
+        seeds = [crate::AUTHORITY_VAULT_SEED, target.key().as_ref(), &[authority as u8]],
+        bump
+    )]
+    pub authority_vault: Account<'info, AuthorityVault>,
+
+    /// CHECK: mint, token account, ProgramData or stake account; its program checks the authority
+    #[account(mut)]
+    pub target: UncheckedAccount<'info>,
+
+    /// CHECK: must own `target`, checked in the handler
+    pub authority_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Required for stake accounts; the stake program reads it on authorize
+    pub clock: Option<Sysvar<'info, Clock>>,
+}
+
+impl<'info> CreateAuthorityVault<'info> {
+    pub fn create_authority_vault(
+        &mut self,
+        beneficiary: Pubkey,
+        inactivity_period: i64,
+        authority: HandoffAuthority,
+        bumps: &CreateAuthorityVaultBumps,
+    ) -> Result<()> {
+        require!(
+            inactivity_period >= crate::MINIMUM_TIMEOUT,
+            VaultError::TimeoutTooShort
+        );
+        require!(beneficiary != self.owner.key(), VaultError::SelfBeneficiary);
+        require_keys_eq!(
+            self.authority_program.key(),
+            authority.program_id(),
+            VaultError::InvalidHandoffTarget
+        );
+        require_keys_eq!(
+            *self.target.owner,
+            authority.program_id(),
+            VaultError::InvalidHandoffTarget
+        );
+
+        // The owner signs this hop; the owning program rejects a wrong authority type
+        authority.transfer(
+            self.authority_program.to_account_info(),
+            self.target.to_account_info(),
+            self.owner.to_account_info(),
+            self.authority_vault.to_account_info(),
+            self.clock.as_ref().map(|clock| clock.to_account_info()),
+            &[],
+        )?;
+
+        let clock = Clock::get()?;
+        let authority_vault = &mut self.authority_vault;
+        authority_vault.owner = self.owner.key();
+        authority_vault.beneficiary = beneficiary;
+        authority_vault.target = self.target.key();
+        authority_vault.authority = authority;
+        authority_vault.timer = HeartbeatTimer::new(inactivity_period, &clock);
+        authority_vault.bump = bumps.authority_vault;
+
+        msg!("🗝️ Authority handed to vault {}", authority_vault.key());
+        msg!("Target: {}", authority_vault.target);
+        msg!("Beneficiary: {}", beneficiary);
+        msg!("Timeout: {} seconds", inactivity_period);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use crate::error::VaultError;
+use crate::state::{HeartbeatHistory, Vault};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CreateHistory<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::VAULT_SEED,
+            vault.load()?.creator.as_ref(),
+            vault.load()?.beneficiary.as_ref(),
+            vault.load()?.token_mint.as_ref(),
+        ],
+        bump = vault.load()?.bump,
+        has_one = owner @ VaultError::UnauthorizedAccess,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = HeartbeatHistory::INIT_SPACE,
+        seeds = [crate::HISTORY_SEED, vault.key().as_ref()],
+        bump
+    )]
+    pub history: AccountLoader<'info, HeartbeatHistory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateHistory<'info> {
+    pub fn create_history(&mut self, bumps: &CreateHistoryBumps) -> Result<()> {
+        let clock = Clock::get()?;
+        let mut vault = self.vault.load_mut()?;
+        require!(vault.is_active(), VaultError::VaultInactive);
+
+        let mut history = self.history.load_init()?;
+        history.vault = self.vault.key();
+        history.bump = bumps.history;
+
+        // From now on heartbeat and emergency_withdraw must write to it
+        vault.set_history(true);
+        vault.touch_by_owner(&clock);
+
+        msg!(
+            "📜 Heartbeat history enabled for vault {}",
+            self.vault.key()
+        );
+        msg!("Keeping the last {} entries", crate::HISTORY_LEN);
+
+        Ok(())
+    }
+}
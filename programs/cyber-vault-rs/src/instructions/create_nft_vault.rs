@@ -0,0 +1,52 @@
+use crate::error::VaultError;
+use crate::state::{HeartbeatTimer, NftVault};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(beneficiary: Pubkey)]
+pub struct CreateNftVault<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = NftVault::INIT_SPACE,
+        seeds = [crate::NFT_VAULT_SEED, owner.key().as_ref(), beneficiary.as_ref()],
+        bump
+    )]
+    pub nft_vault: Account<'info, NftVault>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateNftVault<'info> {
+    pub fn create_nft_vault(
+        &mut self,
+        beneficiary: Pubkey,
+        inactivity_period: i64,
+        bumps: &CreateNftVaultBumps,
+    ) -> Result<()> {
+        require!(
+            inactivity_period >= crate::MINIMUM_TIMEOUT,
+            VaultError::TimeoutTooShort
+        );
+        require!(beneficiary != self.owner.key(), VaultError::SelfBeneficiary);
+
+        let clock = Clock::get()?;
+        let nft_vault = &mut self.nft_vault;
+        nft_vault.owner = self.owner.key();
+        nft_vault.beneficiary = beneficiary;
+        nft_vault.timer = HeartbeatTimer::new(inactivity_period, &clock);
+        nft_vault.claim_cursor = 0;
+        nft_vault.bump = bumps.nft_vault;
+        nft_vault.mints = Vec::new();
+        nft_vault.deferred = Vec::new();
+
+        msg!("🖼️ NFT vault created: {}", nft_vault.key());
+        msg!("Beneficiary: {}", beneficiary);
+        msg!("Timeout: {} seconds", inactivity_period);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use crate::error::VaultError;
+use crate::state::Switch;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CreateSwitch<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = Switch::INIT_SPACE,
+        seeds = [crate::SWITCH_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub switch: Account<'info, Switch>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateSwitch<'info> {
+    pub fn create_switch(
+        &mut self,
+        inactivity_period: i64,
+        bumps: &CreateSwitchBumps,
+    ) -> Result<()> {
+        require!(
+            inactivity_period >= crate::MINIMUM_TIMEOUT,
+            VaultError::TimeoutTooShort
+        );
+
+        let clock = Clock::get()?;
+
+        self.switch.owner = self.owner.key();
+        self.switch.inactivity_period = inactivity_period;
+        self.switch.last_heartbeat = clock.unix_timestamp;
+        self.switch.linked_vaults = 0;
+        self.switch.bump = bumps.switch;
+
+        msg!("🎛️ Master switch armed for owner {}", self.owner.key());
+        msg!("Timeout: {} seconds", inactivity_period);
+
+        Ok(())
+    }
+}
@@ -1,9 +1,12 @@
-use crate::state::Vault;
+use crate::error::VaultError;
+use crate::state::{BeneficiaryIndex, GlobalStats, MintStats, OwnerRegistry, Vault};
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use anchor_lang::system_program;
+use anchor_spl::token::spl_token::native_mint;
+use anchor_spl::token::{self, Mint, SyncNative, Token, TokenAccount, Transfer};
 
 #[derive(Accounts)]
-#[instruction(beneficiary: Pubkey, inactivity_period: i64, amount: u64)]
+#[instruction(beneficiary: Pubkey, inactivity_period: i64, amount: u64, acknowledge_freeze_authority: bool)]
 pub struct CreateVault<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
@@ -15,7 +18,7 @@ pub struct CreateVault<'info> {
         seeds = [b"vault", owner.key().as_ref(), beneficiary.as_ref(), token_mint.key().as_ref()],
         bump
     )]
-    pub vault: Account<'info, Vault>,
+    pub vault: AccountLoader<'info, Vault>,
 
     #[account(
         init,
@@ -27,14 +30,55 @@ pub struct CreateVault<'info> {
     )]
     pub vault_token_account: Account<'info, TokenAccount>,
 
+    // Omit to deposit plain SOL into a wSOL vault
     #[account(mut)]
-    pub owner_token_account: Account<'info, TokenAccount>,
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
 
     pub token_mint: Account<'info, Mint>,
 
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = OwnerRegistry::INIT_SPACE,
+        seeds = [crate::OWNER_REGISTRY_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub owner_registry: Account<'info, OwnerRegistry>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = BeneficiaryIndex::INIT_SPACE,
+        seeds = [crate::BENEFICIARY_INDEX_SEED, beneficiary.as_ref()],
+        bump
+    )]
+    pub beneficiary_index: Account<'info, BeneficiaryIndex>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = GlobalStats::INIT_SPACE,
+        seeds = [crate::GLOBAL_STATS_SEED],
+        bump
+    )]
+    pub global_stats: Account<'info, GlobalStats>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = MintStats::INIT_SPACE,
+        seeds = [crate::MINT_STATS_SEED, token_mint.key().as_ref()],
+        bump
+    )]
+    pub mint_stats: Account<'info, MintStats>,
+
+    /// CHECK: mint policy singleton; until the admin creates it no allowlist applies
+    #[account(seeds = [crate::MINT_POLICY_SEED], bump)]
+    pub mint_policy: UncheckedAccount<'info>,
 }
 
 impl<'info> CreateVault<'info> {
@@ -48,27 +92,73 @@ impl<'info> CreateVault<'info> {
         let clock = Clock::get()?;
 
         // Initialize vault
-        self.vault.owner = self.owner.key();
-        self.vault.beneficiary = beneficiary;
-        self.vault.token_mint = self.token_mint.key();
-        self.vault.vault_token_account = self.vault_token_account.key();
-        self.vault.inactivity_period = inactivity_period;
-        self.vault.last_heartbeat = clock.unix_timestamp;
-        self.vault.is_active = true;
-        self.vault.bump = bumps.vault;
-
-        // Transfer tokens from owner to vault
-        let cpi_accounts = Transfer {
-            from: self.owner_token_account.to_account_info(),
-            to: self.vault_token_account.to_account_info(),
-            authority: self.owner.to_account_info(),
-        };
-        let cpi_program = self.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        token::transfer(cpi_ctx, amount)?;
+        let mut vault = self.vault.load_init()?;
+        vault.owner = self.owner.key();
+        vault.creator = self.owner.key();
+        vault.beneficiary = beneficiary;
+        vault.token_mint = self.token_mint.key();
+        vault.vault_token_account = self.vault_token_account.key();
+        vault.inactivity_period = inactivity_period;
+        vault.touch_by_owner(&clock);
+        vault.set_active(true);
+        vault.bump = bumps.vault;
+        drop(vault);
+
+        // Index the vault for "my vaults" / "vaults I inherit" lookups
+        self.owner_registry.owner = self.owner.key();
+        self.owner_registry.bump = bumps.owner_registry;
+        self.owner_registry.insert(self.vault.key());
+        self.beneficiary_index.beneficiary = beneficiary;
+        self.beneficiary_index.bump = bumps.beneficiary_index;
+        self.beneficiary_index.insert(self.vault.key());
+
+        self.global_stats.bump = bumps.global_stats;
+        self.global_stats.record_created();
+        self.mint_stats.mint = self.token_mint.key();
+        self.mint_stats.bump = bumps.mint_stats;
+        self.mint_stats.open_vault();
+        self.mint_stats.deposit(amount);
+
+        match &self.owner_token_account {
+            // Transfer tokens from owner to vault
+            Some(owner_token_account) => {
+                let cpi_accounts = Transfer {
+                    from: owner_token_account.to_account_info(),
+                    to: self.vault_token_account.to_account_info(),
+                    authority: self.owner.to_account_info(),
+                };
+                let cpi_program = self.token_program.to_account_info();
+                let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+                token::transfer(cpi_ctx, amount)?;
+            }
+            // Wrap plain SOL: fund the vault's wSOL account and sync its token balance
+            None => {
+                require_keys_eq!(
+                    self.token_mint.key(),
+                    native_mint::ID,
+                    VaultError::NotNativeMint
+                );
+                system_program::transfer(
+                    CpiContext::new(
+                        self.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: self.owner.to_account_info(),
+                            to: self.vault_token_account.to_account_info(),
+                        },
+                    ),
+                    amount,
+                )?;
+                token::sync_native(CpiContext::new(
+                    self.token_program.to_account_info(),
+                    SyncNative {
+                        account: self.vault_token_account.to_account_info(),
+                    },
+                ))?;
+            }
+        }
 
         msg!("🔒 Cyber-Vault initialized. Digital assets now protected by immutable code.");
-        msg!("Owner: {}", self.vault.owner);
+        msg!("Owner: {}", self.owner.key());
         msg!("Beneficiary: {}", beneficiary);
         msg!("Timeout: {} seconds", inactivity_period);
         msg!("Amount locked: {}", amount);
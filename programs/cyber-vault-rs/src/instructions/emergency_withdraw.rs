@@ -1,6 +1,7 @@
-use crate::state::Vault;
+use crate::state::{record_history, HeartbeatHistory, HistoryKind, MintStats, Vault};
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token::spl_token::native_mint;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
 
 #[derive(Accounts)]
 pub struct EmergencyWithdraw<'info> {
@@ -11,39 +12,66 @@ pub struct EmergencyWithdraw<'info> {
         mut,
         seeds = [
             crate::VAULT_SEED,
-            vault.owner.as_ref(),
-            vault.beneficiary.as_ref(),
-            vault.token_mint.as_ref(),
+            vault.load()?.creator.as_ref(),
+            vault.load()?.beneficiary.as_ref(),
+            vault.load()?.token_mint.as_ref(),
         ],
-        bump = vault.bump,
+        bump = vault.load()?.bump,
         has_one = owner @ crate::error::VaultError::UnauthorizedAccess,
     )]
-    pub vault: Account<'info, Vault>,
+    pub vault: AccountLoader<'info, Vault>,
 
     #[account(
         mut,
-        token::mint = vault.token_mint,
+        token::mint = vault.load()?.token_mint,
         token::authority = owner,
     )]
-    pub owner_token_account: Account<'info, TokenAccount>,
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
 
     #[account(
         mut,
-        token::mint = vault.token_mint,
+        token::mint = vault.load()?.token_mint,
         token::authority = vault,
     )]
     pub vault_token_account: Account<'info, TokenAccount>,
 
     pub token_program: Program<'info, Token>,
+
+    #[account(
+        mut,
+        seeds = [crate::MINT_STATS_SEED, vault.load()?.token_mint.as_ref()],
+        bump = mint_stats.bump,
+    )]
+    pub mint_stats: Account<'info, MintStats>,
+
+    // Unwrap path: pay out native SOL through a temporary wSOL account
+    #[account(address = vault.load()?.token_mint @ crate::error::VaultError::InvalidTokenMint)]
+    pub token_mint: Option<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = owner,
+        token::mint = token_mint,
+        token::authority = vault,
+        seeds = [crate::UNWRAP_SEED, vault.key().as_ref()],
+        bump,
+        constraint = vault.load()?.token_mint == native_mint::ID @ crate::error::VaultError::NotNativeMint,
+    )]
+    pub unwrap_account: Option<Account<'info, TokenAccount>>,
+
+    pub system_program: Option<Program<'info, System>>,
+
+    #[account(
+        mut,
+        seeds = [crate::HISTORY_SEED, vault.key().as_ref()],
+        bump = history.load()?.bump,
+    )]
+    pub history: Option<AccountLoader<'info, HeartbeatHistory>>,
 }
 
 impl<'info> EmergencyWithdraw<'info> {
     pub fn emergency_withdraw(&mut self, amount: u64) -> Result<()> {
         require!(amount > 0, crate::error::VaultError::InsufficientBalance);
-        require!(
-            self.vault.is_active,
-            crate::error::VaultError::VaultInactive
-        );
         require!(
             amount <= self.vault_token_account.amount,
             crate::error::VaultError::InsufficientBalance
@@ -51,33 +79,73 @@ impl<'info> EmergencyWithdraw<'info> {
 
         let clock = Clock::get()?;
 
+        // Update vault state; the borrow ends before the transfer CPI
+        let vault = {
+            let mut vault = self.vault.load_mut()?;
+            require!(vault.is_active(), crate::error::VaultError::VaultInactive);
+            require!(
+                !vault.is_frozen(clock.unix_timestamp),
+                crate::error::VaultError::VaultFrozen
+            );
+
+            // Amounts above the per-period limit must go through queue_withdrawal
+            vault.apply_due_policy(clock.unix_timestamp);
+            vault.record_withdrawal(amount, clock.unix_timestamp)?;
+            vault.touch_by_owner(&clock);
+            record_history(
+                &vault,
+                self.history.as_ref(),
+                clock.unix_timestamp,
+                self.owner.key(),
+                HistoryKind::EmergencyWithdraw,
+                amount,
+            )?;
+            *vault
+        };
+
         // Get vault authority before mutable borrow
         let vault_authority = self.vault.to_account_info();
 
         // Create PDA signer for the vault
         let seeds = &[
             crate::VAULT_SEED,
-            self.vault.owner.as_ref(),
-            self.vault.beneficiary.as_ref(),
-            self.vault.token_mint.as_ref(),
-            &[self.vault.bump],
+            vault.creator.as_ref(),
+            vault.beneficiary.as_ref(),
+            vault.token_mint.as_ref(),
+            &[vault.bump],
         ];
         let signer = &[&seeds[..]];
 
         // Transfer tokens back to owner
+        let destination = match (&self.unwrap_account, &self.owner_token_account) {
+            (Some(unwrap_account), _) => unwrap_account.to_account_info(),
+            (None, Some(owner_token_account)) => owner_token_account.to_account_info(),
+            (None, None) => return err!(ErrorCode::ConstraintAccountIsNone),
+        };
         let cpi_accounts = Transfer {
             from: self.vault_token_account.to_account_info(),
-            to: self.owner_token_account.to_account_info(),
-            authority: vault_authority,
+            to: destination,
+            authority: vault_authority.clone(),
         };
 
         let cpi_program = self.token_program.to_account_info();
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
 
         token::transfer(cpi_ctx, amount)?;
+        self.mint_stats.withdraw(amount);
 
-        // Update vault state
-        self.vault.last_heartbeat = clock.unix_timestamp;
+        // Closing the temporary wSOL account releases the amount and its rent as lamports
+        if let Some(unwrap_account) = &self.unwrap_account {
+            token::close_account(CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                CloseAccount {
+                    account: unwrap_account.to_account_info(),
+                    destination: self.owner.to_account_info(),
+                    authority: vault_authority,
+                },
+                signer,
+            ))?;
+        }
 
         msg!(
             "🚨 Emergency withdrawal executed. {} tokens reclaimed by owner.",
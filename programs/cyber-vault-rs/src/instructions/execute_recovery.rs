@@ -0,0 +1,109 @@
+use crate::error::VaultError;
+use crate::state::{OwnerRegistry, Recovery, Switch, Vault};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ExecuteRecovery<'info> {
+    #[account(mut)]
+    pub new_owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::VAULT_SEED,
+            vault.load()?.creator.as_ref(),
+            vault.load()?.beneficiary.as_ref(),
+            vault.load()?.token_mint.as_ref(),
+        ],
+        bump = vault.load()?.bump,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [crate::RECOVERY_SEED, vault.key().as_ref()],
+        bump = recovery.bump,
+        has_one = vault,
+    )]
+    pub recovery: Account<'info, Recovery>,
+
+    #[account(
+        mut,
+        seeds = [crate::OWNER_REGISTRY_SEED, vault.load()?.owner.as_ref()],
+        bump = old_owner_registry.bump,
+    )]
+    pub old_owner_registry: Account<'info, OwnerRegistry>,
+
+    #[account(
+        init_if_needed,
+        payer = new_owner,
+        space = OwnerRegistry::INIT_SPACE,
+        seeds = [crate::OWNER_REGISTRY_SEED, new_owner.key().as_ref()],
+        bump
+    )]
+    pub new_owner_registry: Account<'info, OwnerRegistry>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut, address = vault.load()?.switch @ VaultError::SwitchMismatch)]
+    pub switch: Option<Account<'info, Switch>>,
+}
+
+impl<'info> ExecuteRecovery<'info> {
+    pub fn execute_recovery(&mut self, bumps: &ExecuteRecoveryBumps) -> Result<()> {
+        let mut vault = self.vault.load_mut()?;
+        require!(vault.is_active(), VaultError::VaultInactive);
+
+        let pending = self
+            .recovery
+            .pending_owner
+            .ok_or(VaultError::RecoveryNotPending)?;
+        require!(
+            pending == self.new_owner.key(),
+            VaultError::UnauthorizedAccess
+        );
+
+        // Any owner signature after the rotation started voids it
+        let (last_heartbeat, _) = vault.liveness(self.switch.as_deref())?;
+        require!(
+            vault.last_heartbeat <= self.recovery.initiated_at
+                && last_heartbeat <= self.recovery.initiated_at,
+            VaultError::RecoveryCancelled
+        );
+        require!(
+            self.recovery.approval_count() >= self.recovery.threshold as u32,
+            VaultError::RecoveryThresholdNotMet
+        );
+
+        let clock = Clock::get()?;
+        require!(
+            clock.unix_timestamp - self.recovery.initiated_at >= self.recovery.timelock,
+            VaultError::RecoveryTimelockActive
+        );
+
+        let previous_owner = vault.owner;
+        vault.owner = pending;
+        vault.touch(&clock);
+        self.recovery.clear_pending();
+
+        // The lost key's switch, duress key and proposed handoff must not keep
+        // a say over the vault; it falls back to its own timer, just restarted above
+        vault.unlink(self.switch.as_deref_mut())?;
+        vault.duress_key = Pubkey::default();
+        vault.duress_lock_period = 0;
+        vault.pending_owner = Pubkey::default();
+
+        // Move the vault to the new owner's registry
+        let vault_key = self.vault.key();
+        self.old_owner_registry.remove(&vault_key);
+        self.new_owner_registry.owner = pending;
+        self.new_owner_registry.bump = bumps.new_owner_registry;
+        self.new_owner_registry.insert(vault_key);
+
+        msg!("🔑 Vault owner rotated by guardian recovery.");
+        msg!("Previous owner: {}", previous_owner);
+        msg!("New owner: {}", pending);
+
+        Ok(())
+    }
+}
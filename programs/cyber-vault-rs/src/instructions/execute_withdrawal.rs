@@ -0,0 +1,126 @@
+use crate::error::VaultError;
+use crate::state::{
+    record_history, HeartbeatHistory, HistoryKind, MintStats, PendingWithdrawal, Vault,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct ExecuteWithdrawal<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::VAULT_SEED,
+            vault.load()?.creator.as_ref(),
+            vault.load()?.beneficiary.as_ref(),
+            vault.load()?.token_mint.as_ref(),
+        ],
+        bump = vault.load()?.bump,
+        has_one = owner @ VaultError::UnauthorizedAccess,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [crate::WITHDRAWAL_SEED, vault.key().as_ref()],
+        bump = pending_withdrawal.bump,
+        has_one = vault,
+    )]
+    pub pending_withdrawal: Account<'info, PendingWithdrawal>,
+
+    #[account(
+        mut,
+        token::mint = vault.load()?.token_mint,
+        token::authority = owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = vault.load()?.token_mint,
+        token::authority = vault,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+
+    #[account(
+        mut,
+        seeds = [crate::MINT_STATS_SEED, vault.load()?.token_mint.as_ref()],
+        bump = mint_stats.bump,
+    )]
+    pub mint_stats: Account<'info, MintStats>,
+
+    #[account(
+        mut,
+        seeds = [crate::HISTORY_SEED, vault.key().as_ref()],
+        bump = history.load()?.bump,
+    )]
+    pub history: Option<AccountLoader<'info, HeartbeatHistory>>,
+}
+
+impl<'info> ExecuteWithdrawal<'info> {
+    pub fn execute_withdrawal(&mut self) -> Result<()> {
+        let vault = *self.vault.load()?;
+        require!(vault.is_active(), VaultError::VaultInactive);
+
+        let clock = Clock::get()?;
+        require!(
+            !vault.is_frozen(clock.unix_timestamp),
+            VaultError::VaultFrozen
+        );
+        require!(
+            clock.unix_timestamp >= self.pending_withdrawal.executable_at,
+            VaultError::WithdrawalDelayActive
+        );
+
+        let amount = self.pending_withdrawal.amount;
+        require!(
+            amount <= self.vault_token_account.amount,
+            VaultError::InsufficientBalance
+        );
+
+        let vault_authority = self.vault.to_account_info();
+        let seeds = &[
+            crate::VAULT_SEED,
+            vault.creator.as_ref(),
+            vault.beneficiary.as_ref(),
+            vault.token_mint.as_ref(),
+            &[vault.bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: self.vault_token_account.to_account_info(),
+            to: self.owner_token_account.to_account_info(),
+            authority: vault_authority,
+        };
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::transfer(cpi_ctx, amount)?;
+        self.mint_stats.withdraw(amount);
+
+        let mut vault = self.vault.load_mut()?;
+        vault.touch_by_owner(&clock);
+        record_history(
+            &vault,
+            self.history.as_ref(),
+            clock.unix_timestamp,
+            self.owner.key(),
+            HistoryKind::QueuedWithdrawal,
+            amount,
+        )?;
+
+        msg!(
+            "🔓 Queued withdrawal executed. {} tokens reclaimed by owner.",
+            amount
+        );
+        msg!("💓 Heartbeat updated. Digital sovereignty maintained.");
+
+        Ok(())
+    }
+}
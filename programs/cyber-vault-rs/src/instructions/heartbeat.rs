@@ -1,28 +1,53 @@
-use crate::state::Vault;
+use crate::events::DuressAlarm;
+use crate::state::{record_heartbeat, HeartbeatHistory, Vault};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 pub struct Heartbeat<'info> {
     #[account(
         mut,
-        seeds = [b"vault", vault.owner.as_ref(), vault.beneficiary.as_ref(), vault.token_mint.as_ref()],
-        bump = vault.bump
+        seeds = [b"vault", vault.load()?.creator.as_ref(), vault.load()?.beneficiary.as_ref(), vault.load()?.token_mint.as_ref()],
+        bump = vault.load()?.bump
     )]
-    pub vault: Account<'info, Vault>,
+    pub vault: AccountLoader<'info, Vault>,
 
     pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [crate::HISTORY_SEED, vault.key().as_ref()],
+        bump = history.load()?.bump,
+    )]
+    pub history: Option<AccountLoader<'info, HeartbeatHistory>>,
 }
 
 impl<'info> Heartbeat<'info> {
     pub fn send_heartbeat(&mut self) -> Result<()> {
         let clock = Clock::get()?;
+        let mut vault = self.vault.load_mut()?;
 
-        require!(
-            self.owner.key() == self.vault.owner,
-            crate::error::VaultError::UnauthorizedAccess
-        );
+        // A heartbeat from the duress key succeeds like any other, but freezes withdrawals
+        let signer = self.owner.key();
+        if vault.duress_key != Pubkey::default() && signer == vault.duress_key {
+            vault.frozen_until = vault.frozen_until.max(
+                clock
+                    .unix_timestamp
+                    .saturating_add(vault.duress_lock_period),
+            );
+            emit!(DuressAlarm {
+                vault: self.vault.key(),
+                owner: vault.owner,
+                beneficiary: vault.beneficiary,
+                frozen_until: vault.frozen_until,
+            });
+        } else {
+            require!(
+                signer == vault.owner,
+                crate::error::VaultError::UnauthorizedAccess
+            );
+        }
 
-        self.vault.last_heartbeat = clock.unix_timestamp;
+        record_heartbeat(&mut vault, self.history.as_ref(), &clock, signer)?;
 
         msg!("💓 Heartbeat detected. Digital presence confirmed.");
         msg!("⏰ Dead man's switch reset. Vault remains secured.");
@@ -0,0 +1,67 @@
+use crate::error::VaultError;
+use crate::state::{record_heartbeat, HeartbeatHistory, Vault};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct HeartbeatMany<'info> {
+    pub owner: Signer<'info>,
+    // Vaults to refresh are passed as writable `remaining_accounts`, each
+    // followed by its writable history account if it keeps one
+}
+
+impl<'info> HeartbeatMany<'info> {
+    pub fn send_heartbeats(&self, vaults: &'info [AccountInfo<'info>]) -> Result<()> {
+        require!(!vaults.is_empty(), VaultError::EmptyVaultBatch);
+
+        let clock = Clock::get()?;
+        let mut accounts = vaults.iter();
+        let mut refreshed = 0;
+
+        while let Some(info) = accounts.next() {
+            require!(info.is_writable, VaultError::InvalidVaultAccount);
+
+            // Checks program ownership and the account discriminator
+            let loader = AccountLoader::<Vault>::try_from(info)?;
+            let mut vault = loader.load_mut()?;
+
+            let expected = Pubkey::create_program_address(
+                &[
+                    crate::VAULT_SEED,
+                    vault.creator.as_ref(),
+                    vault.beneficiary.as_ref(),
+                    vault.token_mint.as_ref(),
+                    &[vault.bump],
+                ],
+                &crate::ID,
+            )
+            .map_err(|_| VaultError::InvalidVaultAccount)?;
+            require_keys_eq!(expected, info.key(), VaultError::InvalidVaultAccount);
+            require_keys_eq!(
+                vault.owner,
+                self.owner.key(),
+                VaultError::UnauthorizedAccess
+            );
+
+            let history = if vault.has_history() {
+                let history_info = accounts.next().ok_or(VaultError::HistoryRequired)?;
+                let history = AccountLoader::<HeartbeatHistory>::try_from(history_info)?;
+                require_keys_eq!(
+                    history.load()?.vault,
+                    info.key(),
+                    VaultError::HistoryRequired
+                );
+                Some(history)
+            } else {
+                None
+            };
+
+            record_heartbeat(&mut vault, history.as_ref(), &clock, self.owner.key())?;
+            refreshed += 1;
+        }
+
+        msg!("💓 Heartbeat detected. Digital presence confirmed.");
+        msg!("⏰ Dead man's switch reset on {} vaults.", refreshed);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use crate::error::VaultError;
+use crate::state::{ExpiryMode, Switch, Vault};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct LinkSwitch<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::VAULT_SEED,
+            vault.load()?.creator.as_ref(),
+            vault.load()?.beneficiary.as_ref(),
+            vault.load()?.token_mint.as_ref(),
+        ],
+        bump = vault.load()?.bump,
+        has_one = owner @ VaultError::UnauthorizedAccess,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [crate::SWITCH_SEED, owner.key().as_ref()],
+        bump = switch.bump,
+        has_one = owner @ VaultError::UnauthorizedAccess,
+    )]
+    pub switch: Account<'info, Switch>,
+}
+
+impl<'info> LinkSwitch<'info> {
+    pub fn link_switch(&mut self) -> Result<()> {
+        let mut vault = self.vault.load_mut()?;
+        require!(vault.is_active(), VaultError::VaultInactive);
+        require!(!vault.is_linked(), VaultError::SwitchAlreadyLinked);
+        require!(
+            vault.expiry_mode() == ExpiryMode::Timestamp,
+            VaultError::ExpiryModeConflict
+        );
+
+        let clock = Clock::get()?;
+
+        // Both timers restart, so linking to a switch that has gone stale
+        // cannot make the vault claimable at once
+        vault.switch = self.switch.key();
+        vault.touch_by_owner(&clock);
+        self.switch.last_heartbeat = clock.unix_timestamp;
+        self.switch.linked_vaults += 1;
+
+        msg!("🔗 Vault {} linked to master switch.", self.vault.key());
+        msg!("Linked vaults: {}", self.switch.linked_vaults);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+use crate::error::VaultError;
+use crate::state::{record_heartbeat, HeartbeatHistory, Vault};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_instruction_at_checked;
+
+/// Heartbeat carried by an SPL Memo in the same transaction, so a wallet app
+/// can add a check-in to any transaction the owner already signs. No signer
+/// is needed here; the owner's signature on the memo is the proof of life.
+#[derive(Accounts)]
+pub struct MemoHeartbeat<'info> {
+    #[account(
+        mut,
+        seeds = [
+            crate::VAULT_SEED,
+            vault.load()?.creator.as_ref(),
+            vault.load()?.beneficiary.as_ref(),
+            vault.load()?.token_mint.as_ref(),
+        ],
+        bump = vault.load()?.bump,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+
+    /// CHECK: address-checked instructions sysvar, read to find the memo
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [crate::HISTORY_SEED, vault.key().as_ref()],
+        bump = history.load()?.bump,
+    )]
+    pub history: Option<AccountLoader<'info, HeartbeatHistory>>,
+}
+
+impl<'info> MemoHeartbeat<'info> {
+    pub fn send_heartbeat(&mut self) -> Result<()> {
+        let instructions = self.instructions.to_account_info();
+        let vault_key = self.vault.key();
+        let owner = self.vault.load()?.owner;
+        let expected = format!("{}{}", crate::MEMO_HEARTBEAT_PREFIX, vault_key);
+
+        // The memo may come before or after this instruction. The Memo
+        // program fails unless every signer account it lists has signed, and
+        // the runtime has already verified the transaction's signatures.
+        let mut index = 0;
+        let found = loop {
+            let Ok(instruction) = load_instruction_at_checked(index, &instructions) else {
+                break false;
+            };
+            if instruction.program_id == crate::MEMO_PROGRAM_ID
+                && instruction.data == expected.as_bytes()
+                && instruction
+                    .accounts
+                    .iter()
+                    .any(|meta| meta.pubkey == owner && meta.is_signer)
+            {
+                break true;
+            }
+            index += 1;
+        };
+        require!(found, VaultError::MemoHeartbeatNotFound);
+
+        let clock = Clock::get()?;
+        let mut vault = self.vault.load_mut()?;
+        record_heartbeat(&mut vault, self.history.as_ref(), &clock, owner)?;
+
+        msg!("💓 Heartbeat carried by memo for vault {}", vault_key);
+        msg!("⏰ Dead man's switch reset. Vault remains secured.");
+
+        Ok(())
+    }
+}
@@ -1,9 +1,85 @@
+pub mod accept_owner;
+pub mod add_nft;
+pub mod approve_recovery;
+pub mod authority_heartbeat;
+pub mod cancel_absence;
+pub mod cancel_recovery;
 pub mod claim;
+pub mod claim_authority;
+pub mod claim_nfts;
+pub mod claim_to_vault;
+pub mod close_vault;
+pub mod contribute;
+pub mod cpi_heartbeat;
+pub mod create_authority_vault;
+pub mod create_history;
+pub mod create_nft_vault;
+pub mod create_switch;
 pub mod create_vault;
 pub mod emergency_withdraw;
+pub mod execute_recovery;
+pub mod execute_withdrawal;
 pub mod heartbeat;
+pub mod heartbeat_many;
+pub mod link_switch;
+pub mod memo_heartbeat;
+pub mod nft_heartbeat;
+pub mod propose_owner;
+pub mod pull_contribution;
+pub mod queue_withdrawal;
+pub mod reclaim_authority;
+pub mod remove_nft;
+pub mod schedule_absence;
+pub mod set_allowed_callers;
+pub mod set_deposit_schedule;
+pub mod set_duress_key;
+pub mod set_expiry_mode;
+pub mod set_guardians;
+pub mod set_mint_policy;
+pub mod set_withdraw_policy;
+pub mod switch_heartbeat;
+pub mod unlink_switch;
+pub mod veto_withdrawal;
 
+pub use accept_owner::*;
+pub use add_nft::*;
+pub use approve_recovery::*;
+pub use authority_heartbeat::*;
+pub use cancel_absence::*;
+pub use cancel_recovery::*;
 pub use claim::*;
+pub use claim_authority::*;
+pub use claim_nfts::*;
+pub use claim_to_vault::*;
+pub use close_vault::*;
+pub use contribute::*;
+pub use cpi_heartbeat::*;
+pub use create_authority_vault::*;
+pub use create_history::*;
+pub use create_nft_vault::*;
+pub use create_switch::*;
 pub use create_vault::*;
 pub use emergency_withdraw::*;
+pub use execute_recovery::*;
+pub use execute_withdrawal::*;
 pub use heartbeat::*;
+pub use heartbeat_many::*;
+pub use link_switch::*;
+pub use memo_heartbeat::*;
+pub use nft_heartbeat::*;
+pub use propose_owner::*;
+pub use pull_contribution::*;
+pub use queue_withdrawal::*;
+pub use reclaim_authority::*;
+pub use remove_nft::*;
+pub use schedule_absence::*;
+pub use set_allowed_callers::*;
+pub use set_deposit_schedule::*;
+pub use set_duress_key::*;
+pub use set_expiry_mode::*;
+pub use set_guardians::*;
+pub use set_mint_policy::*;
+pub use set_withdraw_policy::*;
+pub use switch_heartbeat::*;
+pub use unlink_switch::*;
+pub use veto_withdrawal::*;
@@ -0,0 +1,29 @@
+use crate::error::VaultError;
+use crate::state::NftVault;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct NftHeartbeat<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [crate::NFT_VAULT_SEED, nft_vault.owner.as_ref(), nft_vault.beneficiary.as_ref()],
+        bump = nft_vault.bump,
+        has_one = owner @ VaultError::UnauthorizedAccess,
+        constraint = !nft_vault.is_claiming() @ VaultError::VaultInactive,
+    )]
+    pub nft_vault: Account<'info, NftVault>,
+}
+
+impl<'info> NftHeartbeat<'info> {
+    pub fn send_heartbeat(&mut self) -> Result<()> {
+        let clock = Clock::get()?;
+        self.nft_vault.timer.touch(&clock);
+
+        msg!("💓 Heartbeat detected. Digital presence confirmed.");
+        msg!("NFT vault: {}", self.nft_vault.key());
+
+        Ok(())
+    }
+}
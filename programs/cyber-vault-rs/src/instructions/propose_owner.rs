@@ -0,0 +1,59 @@
+use crate::error::VaultError;
+use crate::state::Vault;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ProposeOwner<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::VAULT_SEED,
+            vault.load()?.creator.as_ref(),
+            vault.load()?.beneficiary.as_ref(),
+            vault.load()?.token_mint.as_ref(),
+        ],
+        bump = vault.load()?.bump,
+        has_one = owner @ VaultError::UnauthorizedAccess,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+}
+
+impl<'info> ProposeOwner<'info> {
+    pub fn propose_owner(&mut self, new_owner: Pubkey) -> Result<()> {
+        let clock = Clock::get()?;
+        let mut vault = self.vault.load_mut()?;
+        require!(vault.is_active(), VaultError::VaultInactive);
+        // Otherwise a coerced owner could simply be made to hand the vault over
+        require!(
+            !vault.is_frozen(clock.unix_timestamp),
+            VaultError::VaultFrozen
+        );
+
+        if new_owner == Pubkey::default() {
+            vault.pending_owner = Pubkey::default();
+            msg!(
+                "↩️ Ownership transfer cancelled for vault {}",
+                self.vault.key()
+            );
+        } else {
+            require!(
+                new_owner != vault.owner
+                    && new_owner != vault.beneficiary
+                    && new_owner != vault.duress_key,
+                VaultError::InvalidNewOwner
+            );
+            vault.pending_owner = new_owner;
+            msg!(
+                "📨 Ownership transfer proposed for vault {}",
+                self.vault.key()
+            );
+            msg!("Proposed owner: {}", new_owner);
+        }
+
+        vault.touch_by_owner(&clock);
+
+        Ok(())
+    }
+}
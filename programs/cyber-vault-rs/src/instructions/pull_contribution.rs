@@ -0,0 +1,109 @@
+use crate::error::VaultError;
+use crate::state::{MintStats, Vault};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+/// Permissionless crank that moves one due installment of the owner's
+/// deposit schedule, using the vault PDA's delegated allowance.
+#[derive(Accounts)]
+pub struct PullContribution<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::VAULT_SEED,
+            vault.load()?.creator.as_ref(),
+            vault.load()?.beneficiary.as_ref(),
+            vault.load()?.token_mint.as_ref(),
+        ],
+        bump = vault.load()?.bump,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [crate::TOKEN_VAULT_SEED, vault.key().as_ref()],
+        bump
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = vault.load()?.deposit_source @ VaultError::NoDepositSchedule,
+    )]
+    pub source_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [crate::MINT_STATS_SEED, vault.load()?.token_mint.as_ref()],
+        bump = mint_stats.bump,
+    )]
+    pub mint_stats: Account<'info, MintStats>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> PullContribution<'info> {
+    pub fn pull_contribution(&mut self) -> Result<()> {
+        let clock = Clock::get()?;
+        let now = clock.unix_timestamp;
+
+        // Copy out what we need so the vault data is not borrowed across CPIs
+        let vault = *self.vault.load()?;
+        let vault_key = self.vault.key();
+        let amount = vault.deposit_amount;
+
+        require!(
+            vault.is_active() && vault.has_deposit_schedule() && now <= vault.deposit_end,
+            VaultError::NoDepositSchedule
+        );
+        require!(now >= vault.next_deposit_at, VaultError::DepositNotDue);
+
+        // Fail with our own error rather than the token program's when the owner
+        // revoked the approval, lowered it or ran the account dry
+        let source = &self.source_token_account;
+        require!(
+            source.delegate == COption::Some(vault_key)
+                && source.delegated_amount >= amount
+                && source.amount >= amount,
+            VaultError::DepositMissed
+        );
+
+        let seeds = &[
+            crate::VAULT_SEED,
+            vault.creator.as_ref(),
+            vault.beneficiary.as_ref(),
+            vault.token_mint.as_ref(),
+            &[vault.bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.source_token_account.to_account_info(),
+                    to: self.vault_token_account.to_account_info(),
+                    authority: self.vault.to_account_info(),
+                },
+                signer,
+            ),
+            amount,
+        )?;
+
+        let mut vault = self.vault.load_mut()?;
+        vault.advance_deposit_schedule(now);
+        let next_deposit_at = vault.next_deposit_at;
+        drop(vault);
+
+        self.mint_stats.deposit(amount);
+
+        msg!("💰 Scheduled deposit pulled into vault {}", vault_key);
+        msg!("Amount: {}", amount);
+        msg!("Next installment due at: {}", next_deposit_at);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use crate::error::VaultError;
+use crate::state::{PendingWithdrawal, Vault};
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+#[derive(Accounts)]
+pub struct QueueWithdrawal<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::VAULT_SEED,
+            vault.load()?.creator.as_ref(),
+            vault.load()?.beneficiary.as_ref(),
+            vault.load()?.token_mint.as_ref(),
+        ],
+        bump = vault.load()?.bump,
+        has_one = owner @ VaultError::UnauthorizedAccess,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        token::mint = vault.load()?.token_mint,
+        token::authority = vault,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = PendingWithdrawal::INIT_SPACE,
+        seeds = [crate::WITHDRAWAL_SEED, vault.key().as_ref()],
+        bump
+    )]
+    pub pending_withdrawal: Account<'info, PendingWithdrawal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> QueueWithdrawal<'info> {
+    pub fn queue_withdrawal(&mut self, amount: u64, bumps: &QueueWithdrawalBumps) -> Result<()> {
+        require!(amount > 0, VaultError::InsufficientBalance);
+        let mut vault = self.vault.load_mut()?;
+        require!(vault.is_active(), VaultError::VaultInactive);
+        require!(
+            amount <= self.vault_token_account.amount,
+            VaultError::InsufficientBalance
+        );
+
+        let clock = Clock::get()?;
+        require!(
+            !vault.is_frozen(clock.unix_timestamp),
+            VaultError::VaultFrozen
+        );
+        vault.apply_due_policy(clock.unix_timestamp);
+
+        self.pending_withdrawal.vault = self.vault.key();
+        self.pending_withdrawal.amount = amount;
+        self.pending_withdrawal.requested_at = clock.unix_timestamp;
+        self.pending_withdrawal.executable_at = clock
+            .unix_timestamp
+            .saturating_add(vault.withdraw_policy.delay);
+        self.pending_withdrawal.bump = bumps.pending_withdrawal;
+
+        vault.touch_by_owner(&clock);
+
+        msg!("⏳ Withdrawal of {} tokens queued.", amount);
+        msg!(
+            "Executable at {}. Beneficiary or guardians may veto until then.",
+            self.pending_withdrawal.executable_at
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use crate::error::VaultError;
+use crate::state::AuthorityVault;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ReclaimAuthority<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            crate::AUTHORITY_VAULT_SEED,
+            authority_vault.target.as_ref(),
+            &[authority_vault.authority as u8],
+        ],
+        bump = authority_vault.bump,
+        has_one = owner @ VaultError::UnauthorizedAccess,
+        has_one = target @ VaultError::InvalidHandoffTarget,
+    )]
+    pub authority_vault: Account<'info, AuthorityVault>,
+
+    /// CHECK: the vault's target, matched by `has_one`
+    #[account(mut)]
+    pub target: UncheckedAccount<'info>,
+
+    /// CHECK: program that owns `target`
+    #[account(address = authority_vault.authority.program_id() @ VaultError::InvalidHandoffTarget)]
+    pub authority_program: UncheckedAccount<'info>,
+
+    /// Required for stake accounts; the stake program reads it on authorize
+    pub clock: Option<Sysvar<'info, Clock>>,
+}
+
+impl<'info> ReclaimAuthority<'info> {
+    pub fn reclaim_authority(&mut self) -> Result<()> {
+        let clock = Clock::get()?;
+        let authority_vault = &self.authority_vault;
+
+        // Once silent too long the authority is the beneficiary's to claim
+        require!(
+            !authority_vault.timer.is_expired(&clock),
+            VaultError::HandoffExpired
+        );
+
+        let authority = [authority_vault.authority as u8];
+        let seeds = &[
+            crate::AUTHORITY_VAULT_SEED,
+            authority_vault.target.as_ref(),
+            &authority,
+            &[authority_vault.bump],
+        ];
+        authority_vault.authority.transfer(
+            self.authority_program.to_account_info(),
+            self.target.to_account_info(),
+            authority_vault.to_account_info(),
+            self.owner.to_account_info(),
+            self.clock.as_ref().map(|clock| clock.to_account_info()),
+            &[&seeds[..]],
+        )?;
+
+        msg!(
+            "↩️ Authority over {} returned to its owner",
+            authority_vault.target
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use crate::error::VaultError;
+use crate::state::NftVault;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct RemoveNft<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [crate::NFT_VAULT_SEED, nft_vault.owner.as_ref(), nft_vault.beneficiary.as_ref()],
+        bump = nft_vault.bump,
+        has_one = owner @ VaultError::UnauthorizedAccess,
+        constraint = !nft_vault.is_claiming() @ VaultError::VaultInactive,
+    )]
+    pub nft_vault: Account<'info, NftVault>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [crate::NFT_ESCROW_SEED, nft_vault.key().as_ref(), mint.key().as_ref()],
+        bump,
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = mint)]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> RemoveNft<'info> {
+    pub fn remove_nft(&mut self) -> Result<()> {
+        let clock = Clock::get()?;
+
+        let nft_vault = &self.nft_vault;
+        let seeds = &[
+            crate::NFT_VAULT_SEED,
+            nft_vault.owner.as_ref(),
+            nft_vault.beneficiary.as_ref(),
+            &[nft_vault.bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.escrow.to_account_info(),
+                    to: self.owner_token_account.to_account_info(),
+                    authority: nft_vault.to_account_info(),
+                },
+                signer,
+            ),
+            self.escrow.amount,
+        )?;
+
+        token::close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: self.escrow.to_account_info(),
+                destination: self.owner.to_account_info(),
+                authority: nft_vault.to_account_info(),
+            },
+            signer,
+        ))?;
+
+        let nft_vault = &mut self.nft_vault;
+        nft_vault.remove(&self.mint.key());
+        nft_vault.timer.touch(&clock);
+
+        msg!(
+            "🖼️ NFT {} removed from vault {}",
+            self.mint.key(),
+            nft_vault.key()
+        );
+        msg!("NFTs held: {}", nft_vault.mints.len());
+
+        Ok(())
+    }
+}
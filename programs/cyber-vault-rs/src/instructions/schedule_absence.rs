@@ -0,0 +1,73 @@
+use crate::error::VaultError;
+use crate::state::{ExpiryMode, Vault};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ScheduleAbsence<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::VAULT_SEED,
+            vault.load()?.creator.as_ref(),
+            vault.load()?.beneficiary.as_ref(),
+            vault.load()?.token_mint.as_ref(),
+        ],
+        bump = vault.load()?.bump,
+        has_one = owner @ VaultError::UnauthorizedAccess,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+}
+
+impl<'info> ScheduleAbsence<'info> {
+    pub fn schedule_absence(&mut self, start: i64, end: i64) -> Result<()> {
+        let clock = Clock::get()?;
+        let now = clock.unix_timestamp;
+        let mut vault = self.vault.load_mut()?;
+
+        require!(vault.is_active(), VaultError::VaultInactive);
+        require!(
+            vault.expiry_mode() == ExpiryMode::Timestamp,
+            VaultError::ExpiryModeConflict
+        );
+        require!(
+            start >= now && end > start,
+            VaultError::InvalidAbsenceWindow
+        );
+        require!(
+            vault.absence_end <= now,
+            VaultError::AbsenceAlreadyScheduled
+        );
+
+        // The allowance renews a year after the first absence it covered
+        if start - vault.absence_year_start >= crate::ABSENCE_YEAR {
+            vault.absence_year_start = start;
+            vault.absence_used = 0;
+        }
+        let used = vault
+            .absence_used
+            .checked_add(end - start)
+            .ok_or(VaultError::AbsenceCapExceeded)?;
+        require!(
+            used <= crate::MAX_ABSENCE_PER_YEAR,
+            VaultError::AbsenceCapExceeded
+        );
+
+        vault.absence_start = start;
+        vault.absence_end = end;
+        vault.absence_used = used;
+
+        vault.touch_by_owner(&clock);
+
+        msg!("🏝️ Absence scheduled for vault {}", self.vault.key());
+        msg!("Window: {} to {}", start, end);
+        msg!(
+            "Allowance used: {} of {} seconds",
+            used,
+            crate::MAX_ABSENCE_PER_YEAR
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use crate::error::VaultError;
+use crate::state::CallerAllowlist;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetAllowedCallers<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = CallerAllowlist::INIT_SPACE,
+        seeds = [crate::CALLER_ALLOWLIST_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub caller_allowlist: Account<'info, CallerAllowlist>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetAllowedCallers<'info> {
+    pub fn set_allowed_callers(
+        &mut self,
+        programs: Vec<Pubkey>,
+        bumps: &SetAllowedCallersBumps,
+    ) -> Result<()> {
+        require!(
+            programs.len() <= crate::MAX_ALLOWED_CALLERS,
+            VaultError::InvalidCallerList
+        );
+        for (i, program) in programs.iter().enumerate() {
+            require!(
+                *program != crate::ID && !programs[..i].contains(program),
+                VaultError::InvalidCallerList
+            );
+        }
+
+        // An empty list turns CPI heartbeats off
+        self.caller_allowlist.owner = self.owner.key();
+        self.caller_allowlist.programs = programs;
+        self.caller_allowlist.bump = bumps.caller_allowlist;
+
+        msg!(
+            "📡 {} program(s) may now send heartbeats for {}",
+            self.caller_allowlist.programs.len(),
+            self.owner.key()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use crate::error::VaultError;
+use crate::state::Vault;
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+#[derive(Accounts)]
+pub struct SetDepositSchedule<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::VAULT_SEED,
+            vault.load()?.creator.as_ref(),
+            vault.load()?.beneficiary.as_ref(),
+            vault.load()?.token_mint.as_ref(),
+        ],
+        bump = vault.load()?.bump,
+        has_one = owner @ VaultError::UnauthorizedAccess,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+
+    // Account installments are pulled from; omit when clearing the schedule
+    #[account(
+        token::mint = vault.load()?.token_mint,
+        token::authority = owner,
+    )]
+    pub source_token_account: Option<Account<'info, TokenAccount>>,
+}
+
+impl<'info> SetDepositSchedule<'info> {
+    pub fn set_deposit_schedule(&mut self, amount: u64, interval: i64, end: i64) -> Result<()> {
+        let clock = Clock::get()?;
+        let mut vault = self.vault.load_mut()?;
+        require!(vault.is_active(), VaultError::VaultInactive);
+
+        if amount == 0 {
+            vault.clear_deposit_schedule();
+            msg!("🛑 Deposit schedule cleared for vault {}", self.vault.key());
+        } else {
+            let source = self
+                .source_token_account
+                .as_ref()
+                .ok_or(VaultError::InvalidDepositSchedule)?;
+            require!(
+                interval >= crate::MINIMUM_DEPOSIT_INTERVAL && end > clock.unix_timestamp,
+                VaultError::InvalidDepositSchedule
+            );
+
+            // The owner must also approve the vault PDA as delegate on `source`
+            vault.deposit_source = source.key();
+            vault.deposit_amount = amount;
+            vault.deposit_interval = interval;
+            vault.deposit_end = end;
+            vault.next_deposit_at = clock.unix_timestamp;
+            msg!("📅 Deposit schedule set for vault {}", self.vault.key());
+            msg!("Installment: {} every {} seconds", amount, interval);
+            msg!("Ends at: {}", end);
+        }
+
+        vault.touch_by_owner(&clock);
+
+        Ok(())
+    }
+}
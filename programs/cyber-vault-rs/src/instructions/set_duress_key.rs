@@ -0,0 +1,62 @@
+use crate::error::VaultError;
+use crate::state::Vault;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetDuressKey<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::VAULT_SEED,
+            vault.load()?.creator.as_ref(),
+            vault.load()?.beneficiary.as_ref(),
+            vault.load()?.token_mint.as_ref(),
+        ],
+        bump = vault.load()?.bump,
+        has_one = owner @ VaultError::UnauthorizedAccess,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+}
+
+impl<'info> SetDuressKey<'info> {
+    pub fn set_duress_key(&mut self, duress_key: Pubkey, lock_period: i64) -> Result<()> {
+        let clock = Clock::get()?;
+        let mut vault = self.vault.load_mut()?;
+
+        require!(vault.is_active(), VaultError::VaultInactive);
+        // Otherwise a coerced owner could simply be made to switch duress mode off
+        require!(
+            !vault.is_frozen(clock.unix_timestamp),
+            VaultError::VaultFrozen
+        );
+
+        if duress_key == Pubkey::default() {
+            vault.duress_key = Pubkey::default();
+            vault.duress_lock_period = 0;
+            msg!("🔓 Duress mode disabled for vault {}", self.vault.key());
+        } else {
+            require!(
+                duress_key != vault.owner && duress_key != vault.beneficiary,
+                VaultError::InvalidDuressKey
+            );
+            require!(
+                lock_period >= crate::MINIMUM_TIMEOUT,
+                VaultError::TimeoutTooShort
+            );
+            require!(
+                lock_period <= crate::MAX_DURESS_LOCK_PERIOD,
+                VaultError::DuressLockTooLong
+            );
+            vault.duress_key = duress_key;
+            vault.duress_lock_period = lock_period;
+            msg!("🔐 Duress mode enabled for vault {}", self.vault.key());
+            msg!("Lock period: {} seconds", lock_period);
+        }
+
+        vault.touch_by_owner(&clock);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use crate::error::VaultError;
+use crate::state::{ExpiryMode, Vault};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetExpiryMode<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::VAULT_SEED,
+            vault.load()?.creator.as_ref(),
+            vault.load()?.beneficiary.as_ref(),
+            vault.load()?.token_mint.as_ref(),
+        ],
+        bump = vault.load()?.bump,
+        has_one = owner @ VaultError::UnauthorizedAccess,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+}
+
+impl<'info> SetExpiryMode<'info> {
+    pub fn set_expiry_mode(&mut self, mode: ExpiryMode, inactivity_period: i64) -> Result<()> {
+        let clock = Clock::get()?;
+        let mut vault = self.vault.load_mut()?;
+        require!(vault.is_active(), VaultError::VaultInactive);
+
+        let minimum = match mode {
+            ExpiryMode::Timestamp => crate::MINIMUM_TIMEOUT,
+            ExpiryMode::Slot => {
+                // Both features only track wall-clock time
+                require!(
+                    !vault.is_linked() && vault.absence_end <= clock.unix_timestamp,
+                    VaultError::ExpiryModeConflict
+                );
+                crate::MINIMUM_TIMEOUT_SLOTS
+            }
+        };
+        require!(inactivity_period >= minimum, VaultError::TimeoutTooShort);
+
+        vault.set_expiry_mode(mode);
+        vault.inactivity_period = inactivity_period;
+
+        // This also starts the new timer
+        vault.touch_by_owner(&clock);
+
+        msg!("⏱️ Expiry mode updated for vault {}", self.vault.key());
+        match mode {
+            ExpiryMode::Timestamp => msg!("Timeout: {} seconds", inactivity_period),
+            ExpiryMode::Slot => msg!("Timeout: {} slots", inactivity_period),
+        }
+
+        Ok(())
+    }
+}
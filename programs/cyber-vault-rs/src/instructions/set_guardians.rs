@@ -0,0 +1,89 @@
+use crate::error::VaultError;
+use crate::state::{Recovery, Vault};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetGuardians<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::VAULT_SEED,
+            vault.load()?.creator.as_ref(),
+            vault.load()?.beneficiary.as_ref(),
+            vault.load()?.token_mint.as_ref(),
+        ],
+        bump = vault.load()?.bump,
+        has_one = owner @ VaultError::UnauthorizedAccess,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = Recovery::INIT_SPACE,
+        seeds = [crate::RECOVERY_SEED, vault.key().as_ref()],
+        bump
+    )]
+    pub recovery: Account<'info, Recovery>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetGuardians<'info> {
+    pub fn set_guardians(
+        &mut self,
+        guardians: Vec<Pubkey>,
+        threshold: u8,
+        timelock: i64,
+        bumps: &SetGuardiansBumps,
+    ) -> Result<()> {
+        let mut vault = self.vault.load_mut()?;
+        require!(vault.is_active(), VaultError::VaultInactive);
+        require!(
+            !guardians.is_empty() && guardians.len() <= crate::MAX_GUARDIANS,
+            VaultError::InvalidGuardianSet
+        );
+        for (i, guardian) in guardians.iter().enumerate() {
+            require!(
+                *guardian != self.owner.key() && !guardians[..i].contains(guardian),
+                VaultError::InvalidGuardianSet
+            );
+        }
+        require!(
+            threshold >= 1 && threshold as usize <= guardians.len(),
+            VaultError::InvalidThreshold
+        );
+        require!(
+            timelock >= crate::MINIMUM_TIMEOUT,
+            VaultError::TimeoutTooShort
+        );
+
+        let clock = Clock::get()?;
+
+        // Replacing the guardian set discards any rotation in flight
+        self.recovery.vault = self.vault.key();
+        self.recovery.guardians = guardians;
+        self.recovery.threshold = threshold;
+        self.recovery.timelock = timelock;
+        self.recovery.clear_pending();
+        self.recovery.bump = bumps.recovery;
+
+        vault.touch_by_owner(&clock);
+
+        msg!(
+            "🛡️ Recovery guardians configured for vault {}",
+            self.vault.key()
+        );
+        msg!(
+            "Threshold: {} of {}",
+            threshold,
+            self.recovery.guardians.len()
+        );
+        msg!("Timelock: {} seconds", timelock);
+
+        Ok(())
+    }
+}
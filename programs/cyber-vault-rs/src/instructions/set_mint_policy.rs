@@ -0,0 +1,60 @@
+use crate::error::VaultError;
+use crate::program::CyberVaultRs;
+use crate::state::MintPolicy;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetMintPolicy<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = MintPolicy::INIT_SPACE,
+        seeds = [crate::MINT_POLICY_SEED],
+        bump
+    )]
+    pub mint_policy: Account<'info, MintPolicy>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ VaultError::UnauthorizedAccess)]
+    pub program: Program<'info, CyberVaultRs>,
+
+    // Only the upgrade authority may change which mints vaults accept
+    #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ VaultError::UnauthorizedAccess)]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetMintPolicy<'info> {
+    pub fn set_mint_policy(
+        &mut self,
+        allowlist_enabled: bool,
+        mints: Vec<Pubkey>,
+        bumps: &SetMintPolicyBumps,
+    ) -> Result<()> {
+        require!(
+            mints.len() <= crate::MAX_ALLOWED_MINTS,
+            VaultError::InvalidMintList
+        );
+        for (i, mint) in mints.iter().enumerate() {
+            require!(!mints[..i].contains(mint), VaultError::InvalidMintList);
+        }
+
+        self.mint_policy.allowlist_enabled = allowlist_enabled;
+        self.mint_policy.mints = mints;
+        self.mint_policy.bump = bumps.mint_policy;
+
+        if allowlist_enabled {
+            msg!(
+                "🪙 Mint allowlist enabled with {} mint(s)",
+                self.mint_policy.mints.len()
+            );
+        } else {
+            msg!("🪙 Mint allowlist disabled; any mint may back a vault");
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use crate::error::VaultError;
+use crate::state::{Vault, WithdrawPolicy};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetWithdrawPolicy<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::VAULT_SEED,
+            vault.load()?.creator.as_ref(),
+            vault.load()?.beneficiary.as_ref(),
+            vault.load()?.token_mint.as_ref(),
+        ],
+        bump = vault.load()?.bump,
+        has_one = owner @ VaultError::UnauthorizedAccess,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+}
+
+impl<'info> SetWithdrawPolicy<'info> {
+    pub fn set_withdraw_policy(&mut self, limit: u64, period: i64, delay: i64) -> Result<()> {
+        let mut vault = self.vault.load_mut()?;
+        require!(vault.is_active(), VaultError::VaultInactive);
+
+        let policy = if limit == 0 {
+            WithdrawPolicy::default()
+        } else {
+            require!(
+                period > 0 && delay >= crate::MINIMUM_TIMEOUT,
+                VaultError::InvalidWithdrawPolicy
+            );
+            require!(
+                period <= crate::MAX_WITHDRAW_WINDOW && delay <= crate::MAX_WITHDRAW_WINDOW,
+                VaultError::WithdrawWindowTooLong
+            );
+            WithdrawPolicy {
+                limit,
+                period,
+                delay,
+            }
+        };
+
+        let clock = Clock::get()?;
+        vault.apply_due_policy(clock.unix_timestamp);
+
+        if policy.is_at_least_as_strict_as(&vault.withdraw_policy) {
+            // Tightening takes effect immediately and drops any staged loosening
+            vault.withdraw_policy = policy;
+            vault.clear_pending_policy();
+            vault.period_start = clock.unix_timestamp;
+            vault.period_withdrawn = 0;
+
+            msg!("🧱 Withdrawal policy applied.");
+        } else {
+            // Loosening waits out the current delay so a stolen key cannot lift the limit
+            vault.pending_policy = policy;
+            vault.policy_effective_at = clock
+                .unix_timestamp
+                .saturating_add(vault.withdraw_policy.delay);
+
+            msg!(
+                "⏳ Withdrawal policy change staged until {}",
+                vault.policy_effective_at
+            );
+        }
+
+        vault.touch_by_owner(&clock);
+
+        msg!("Limit: {} per {} seconds", limit, period);
+        msg!("Queued withdrawal delay: {} seconds", delay);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use crate::state::Switch;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SwitchHeartbeat<'info> {
+    #[account(
+        mut,
+        seeds = [crate::SWITCH_SEED, owner.key().as_ref()],
+        bump = switch.bump,
+        has_one = owner @ crate::error::VaultError::UnauthorizedAccess,
+    )]
+    pub switch: Account<'info, Switch>,
+
+    pub owner: Signer<'info>,
+}
+
+impl<'info> SwitchHeartbeat<'info> {
+    pub fn send_heartbeat(&mut self) -> Result<()> {
+        let clock = Clock::get()?;
+
+        self.switch.last_heartbeat = clock.unix_timestamp;
+
+        msg!("💓 Heartbeat detected. Digital presence confirmed.");
+        msg!(
+            "⏰ Master switch reset. {} linked vaults remain secured.",
+            self.switch.linked_vaults
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use crate::error::VaultError;
+use crate::state::{Switch, Vault};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UnlinkSwitch<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::VAULT_SEED,
+            vault.load()?.creator.as_ref(),
+            vault.load()?.beneficiary.as_ref(),
+            vault.load()?.token_mint.as_ref(),
+        ],
+        bump = vault.load()?.bump,
+        has_one = owner @ VaultError::UnauthorizedAccess,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+
+    // Not tied to the signer: after a recovery the switch may belong to the previous owner
+    #[account(
+        mut,
+        address = vault.load()?.switch @ VaultError::SwitchNotLinked,
+    )]
+    pub switch: Account<'info, Switch>,
+}
+
+impl<'info> UnlinkSwitch<'info> {
+    pub fn unlink_switch(&mut self) -> Result<()> {
+        let clock = Clock::get()?;
+
+        // The vault falls back to its own timer, restarted by this owner signature
+        let mut vault = self.vault.load_mut()?;
+        vault.unlink(Some(&mut self.switch))?;
+        vault.touch_by_owner(&clock);
+
+        msg!("✂️ Vault {} unlinked from master switch.", self.vault.key());
+        msg!("⏰ Vault timer restarted.");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use crate::error::VaultError;
+use crate::state::{PendingWithdrawal, Recovery, Vault};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct VetoWithdrawal<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::VAULT_SEED,
+            vault.load()?.creator.as_ref(),
+            vault.load()?.beneficiary.as_ref(),
+            vault.load()?.token_mint.as_ref(),
+        ],
+        bump = vault.load()?.bump,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+
+    /// CHECK: receives the rent of the vetoed request; must be the vault owner
+    #[account(mut, address = vault.load()?.owner @ VaultError::UnauthorizedAccess)]
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [crate::WITHDRAWAL_SEED, vault.key().as_ref()],
+        bump = pending_withdrawal.bump,
+        has_one = vault,
+    )]
+    pub pending_withdrawal: Option<Account<'info, PendingWithdrawal>>,
+
+    #[account(
+        seeds = [crate::RECOVERY_SEED, vault.key().as_ref()],
+        bump = recovery.bump,
+        has_one = vault,
+    )]
+    pub recovery: Option<Account<'info, Recovery>>,
+}
+
+impl<'info> VetoWithdrawal<'info> {
+    pub fn veto_withdrawal(&mut self) -> Result<()> {
+        let authority = self.authority.key();
+        let is_guardian = self
+            .recovery
+            .as_ref()
+            .is_some_and(|recovery| recovery.guardian_index(&authority).is_some());
+        require!(
+            authority == self.vault.load()?.beneficiary || is_guardian,
+            VaultError::UnauthorizedAccess
+        );
+
+        let clock = Clock::get()?;
+        let staged_policy = self.vault.load()?.policy_effective_at > clock.unix_timestamp;
+        require!(
+            self.pending_withdrawal.is_some() || staged_policy,
+            VaultError::NothingToVeto
+        );
+
+        if let Some(pending_withdrawal) = &self.pending_withdrawal {
+            msg!(
+                "🛑 Queued withdrawal of {} tokens vetoed.",
+                pending_withdrawal.amount
+            );
+            pending_withdrawal.close(self.owner.to_account_info())?;
+        }
+
+        if staged_policy {
+            self.vault.load_mut()?.clear_pending_policy();
+            msg!("🛑 Staged withdrawal policy change vetoed.");
+        }
+
+        msg!("Vetoed by: {}", authority);
+
+        Ok(())
+    }
+}
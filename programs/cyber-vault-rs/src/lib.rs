@@ -1,18 +1,49 @@
 use anchor_lang::prelude::*;
 
 mod error;
+mod events;
 mod instructions;
 mod state;
 
 use instructions::*;
+use state::{check_mint, ExpiryMode, HandoffAuthority};
 
 // Deployed program ID on devnet
 declare_id!("5QTdo3dK7pQZuYrL9ZCUWzAywpohu3gGEJBmbxqAA1gW");
 
 // Security constants
 pub const MINIMUM_TIMEOUT: i64 = 3600; // 1 hour minimum timeout
+pub const MINIMUM_TIMEOUT_SLOTS: i64 = 9000; // ~1 hour at 400ms slots
 pub const VAULT_SEED: &[u8] = b"vault";
 pub const TOKEN_VAULT_SEED: &[u8] = b"vault_token";
+pub const RECOVERY_SEED: &[u8] = b"recovery";
+pub const MAX_GUARDIANS: usize = 5;
+pub const WITHDRAWAL_SEED: &[u8] = b"withdrawal";
+pub const MAX_WITHDRAW_WINDOW: i64 = 365 * 86_400; // caps a policy's period and delay
+pub const SWITCH_SEED: &[u8] = b"switch";
+pub const OWNER_REGISTRY_SEED: &[u8] = b"owner_registry";
+pub const BENEFICIARY_INDEX_SEED: &[u8] = b"beneficiary_index";
+pub const MAX_INDEXED_VAULTS: usize = 32;
+pub const UNWRAP_SEED: &[u8] = b"unwrap";
+pub const CALLER_ALLOWLIST_SEED: &[u8] = b"caller_allowlist";
+pub const MAX_ALLOWED_CALLERS: usize = 8;
+pub const MAX_DURESS_LOCK_PERIOD: i64 = 365 * 86_400; // longest freeze a duress heartbeat sets
+pub const MAX_ABSENCE_PER_YEAR: i64 = 90 * 86_400; // 90 days
+pub const ABSENCE_YEAR: i64 = 365 * 86_400;
+pub const HISTORY_SEED: &[u8] = b"history";
+pub const HISTORY_LEN: usize = 32;
+pub const GLOBAL_STATS_SEED: &[u8] = b"global_stats";
+pub const MINT_STATS_SEED: &[u8] = b"mint_stats";
+pub const MINT_POLICY_SEED: &[u8] = b"mint_policy";
+pub const MAX_ALLOWED_MINTS: usize = 32;
+pub const CONTRIBUTION_SEED: &[u8] = b"contribution";
+pub const MINIMUM_DEPOSIT_INTERVAL: i64 = 3600; // 1 hour between installments
+pub const AUTHORITY_VAULT_SEED: &[u8] = b"authority_vault";
+pub const NFT_VAULT_SEED: &[u8] = b"nft_vault";
+pub const NFT_ESCROW_SEED: &[u8] = b"nft_escrow";
+pub const MAX_VAULT_NFTS: usize = 64;
+pub const MEMO_HEARTBEAT_PREFIX: &str = "cyber-vault:heartbeat:"; // followed by the vault address
+pub const MEMO_PROGRAM_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
 
 #[program]
 pub mod cyber_vault_rs {
@@ -23,6 +54,7 @@ pub mod cyber_vault_rs {
         beneficiary: Pubkey,
         inactivity_period: i64, // in seconds
         amount: u64,
+        acknowledge_freeze_authority: bool,
     ) -> Result<()> {
         // Validate inputs
         require!(
@@ -34,6 +66,12 @@ pub mod cyber_vault_rs {
             crate::error::VaultError::SelfBeneficiary
         );
 
+        check_mint(
+            &ctx.accounts.token_mint,
+            &ctx.accounts.mint_policy,
+            acknowledge_freeze_authority,
+        )?;
+
         ctx.accounts
             .create_vault(beneficiary, inactivity_period, amount, &ctx.bumps)
     }
@@ -42,11 +80,221 @@ pub mod cyber_vault_rs {
         ctx.accounts.send_heartbeat()
     }
 
+    pub fn heartbeat_many<'info>(
+        ctx: Context<'_, '_, 'info, 'info, HeartbeatMany<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.send_heartbeats(ctx.remaining_accounts)
+    }
+
     pub fn claim(ctx: Context<Claim>) -> Result<()> {
         ctx.accounts.claim_tokens()
     }
 
+    pub fn claim_to_vault(ctx: Context<ClaimToVault>) -> Result<()> {
+        ctx.accounts.claim_to_vault()
+    }
+
+    pub fn contribute(ctx: Context<Contribute>, amount: u64) -> Result<()> {
+        ctx.accounts.contribute(amount, &ctx.bumps)
+    }
+
+    pub fn set_deposit_schedule(
+        ctx: Context<SetDepositSchedule>,
+        amount: u64,   // per installment, 0 clears the schedule
+        interval: i64, // in seconds
+        end: i64,      // unix timestamp
+    ) -> Result<()> {
+        ctx.accounts.set_deposit_schedule(amount, interval, end)
+    }
+
+    pub fn pull_contribution(ctx: Context<PullContribution>) -> Result<()> {
+        ctx.accounts.pull_contribution()
+    }
+
+    pub fn propose_owner(ctx: Context<ProposeOwner>, new_owner: Pubkey) -> Result<()> {
+        ctx.accounts.propose_owner(new_owner)
+    }
+
+    pub fn accept_owner(ctx: Context<AcceptOwner>) -> Result<()> {
+        ctx.accounts.accept_owner(&ctx.bumps)
+    }
+
+    pub fn create_authority_vault(
+        ctx: Context<CreateAuthorityVault>,
+        beneficiary: Pubkey,
+        inactivity_period: i64, // in seconds
+        authority: HandoffAuthority,
+    ) -> Result<()> {
+        ctx.accounts
+            .create_authority_vault(beneficiary, inactivity_period, authority, &ctx.bumps)
+    }
+
+    pub fn authority_heartbeat(ctx: Context<AuthorityHeartbeat>) -> Result<()> {
+        ctx.accounts.send_heartbeat()
+    }
+
+    pub fn reclaim_authority(ctx: Context<ReclaimAuthority>) -> Result<()> {
+        ctx.accounts.reclaim_authority()
+    }
+
+    pub fn claim_authority(ctx: Context<ClaimAuthority>) -> Result<()> {
+        ctx.accounts.claim_authority()
+    }
+
+    pub fn create_nft_vault(
+        ctx: Context<CreateNftVault>,
+        beneficiary: Pubkey,
+        inactivity_period: i64,
+    ) -> Result<()> {
+        ctx.accounts
+            .create_nft_vault(beneficiary, inactivity_period, &ctx.bumps)
+    }
+
+    pub fn add_nft(ctx: Context<AddNft>, acknowledge_freeze_authority: bool) -> Result<()> {
+        check_mint(
+            &ctx.accounts.mint,
+            &ctx.accounts.mint_policy,
+            acknowledge_freeze_authority,
+        )?;
+
+        ctx.accounts.add_nft()
+    }
+
+    pub fn remove_nft(ctx: Context<RemoveNft>) -> Result<()> {
+        ctx.accounts.remove_nft()
+    }
+
+    pub fn nft_heartbeat(ctx: Context<NftHeartbeat>) -> Result<()> {
+        ctx.accounts.send_heartbeat()
+    }
+
+    pub fn claim_nfts<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimNfts<'info>>) -> Result<()> {
+        ctx.accounts.claim_nfts(ctx.remaining_accounts)
+    }
+
     pub fn emergency_withdraw(ctx: Context<EmergencyWithdraw>, amount: u64) -> Result<()> {
         ctx.accounts.emergency_withdraw(amount)
     }
+
+    pub fn close_vault(ctx: Context<CloseVault>) -> Result<()> {
+        ctx.accounts.close_vault()
+    }
+
+    pub fn set_guardians(
+        ctx: Context<SetGuardians>,
+        guardians: Vec<Pubkey>,
+        threshold: u8,
+        timelock: i64, // in seconds
+    ) -> Result<()> {
+        ctx.accounts
+            .set_guardians(guardians, threshold, timelock, &ctx.bumps)
+    }
+
+    pub fn approve_recovery(ctx: Context<ApproveRecovery>, new_owner: Pubkey) -> Result<()> {
+        ctx.accounts.approve_recovery(new_owner)
+    }
+
+    pub fn cancel_recovery(ctx: Context<CancelRecovery>) -> Result<()> {
+        ctx.accounts.cancel_recovery()
+    }
+
+    pub fn execute_recovery(ctx: Context<ExecuteRecovery>) -> Result<()> {
+        ctx.accounts.execute_recovery(&ctx.bumps)
+    }
+
+    pub fn set_withdraw_policy(
+        ctx: Context<SetWithdrawPolicy>,
+        limit: u64,
+        period: i64, // in seconds
+        delay: i64,  // in seconds
+    ) -> Result<()> {
+        ctx.accounts.set_withdraw_policy(limit, period, delay)
+    }
+
+    pub fn queue_withdrawal(ctx: Context<QueueWithdrawal>, amount: u64) -> Result<()> {
+        ctx.accounts.queue_withdrawal(amount, &ctx.bumps)
+    }
+
+    pub fn execute_withdrawal(ctx: Context<ExecuteWithdrawal>) -> Result<()> {
+        ctx.accounts.execute_withdrawal()
+    }
+
+    pub fn veto_withdrawal(ctx: Context<VetoWithdrawal>) -> Result<()> {
+        ctx.accounts.veto_withdrawal()
+    }
+
+    pub fn create_switch(
+        ctx: Context<CreateSwitch>,
+        inactivity_period: i64, // in seconds
+    ) -> Result<()> {
+        ctx.accounts.create_switch(inactivity_period, &ctx.bumps)
+    }
+
+    pub fn switch_heartbeat(ctx: Context<SwitchHeartbeat>) -> Result<()> {
+        ctx.accounts.send_heartbeat()
+    }
+
+    pub fn link_switch(ctx: Context<LinkSwitch>) -> Result<()> {
+        ctx.accounts.link_switch()
+    }
+
+    pub fn unlink_switch(ctx: Context<UnlinkSwitch>) -> Result<()> {
+        ctx.accounts.unlink_switch()
+    }
+
+    pub fn set_allowed_callers(
+        ctx: Context<SetAllowedCallers>,
+        programs: Vec<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts.set_allowed_callers(programs, &ctx.bumps)
+    }
+
+    pub fn cpi_heartbeat(ctx: Context<CpiHeartbeat>) -> Result<()> {
+        ctx.accounts.send_heartbeat()
+    }
+
+    pub fn memo_heartbeat(ctx: Context<MemoHeartbeat>) -> Result<()> {
+        ctx.accounts.send_heartbeat()
+    }
+
+    pub fn schedule_absence(
+        ctx: Context<ScheduleAbsence>,
+        start: i64, // unix timestamp
+        end: i64,   // unix timestamp
+    ) -> Result<()> {
+        ctx.accounts.schedule_absence(start, end)
+    }
+
+    pub fn cancel_absence(ctx: Context<CancelAbsence>) -> Result<()> {
+        ctx.accounts.cancel_absence()
+    }
+
+    pub fn set_duress_key(
+        ctx: Context<SetDuressKey>,
+        duress_key: Pubkey,
+        lock_period: i64, // in seconds
+    ) -> Result<()> {
+        ctx.accounts.set_duress_key(duress_key, lock_period)
+    }
+
+    pub fn set_expiry_mode(
+        ctx: Context<SetExpiryMode>,
+        mode: ExpiryMode,
+        inactivity_period: i64, // in seconds or slots, per `mode`
+    ) -> Result<()> {
+        ctx.accounts.set_expiry_mode(mode, inactivity_period)
+    }
+
+    pub fn create_history(ctx: Context<CreateHistory>) -> Result<()> {
+        ctx.accounts.create_history(&ctx.bumps)
+    }
+
+    pub fn set_mint_policy(
+        ctx: Context<SetMintPolicy>,
+        allowlist_enabled: bool,
+        mints: Vec<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts
+            .set_mint_policy(allowlist_enabled, mints, &ctx.bumps)
+    }
 }
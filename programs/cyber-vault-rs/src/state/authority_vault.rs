@@ -0,0 +1,128 @@
+use crate::error::VaultError;
+use crate::state::HeartbeatTimer;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::stake;
+use anchor_spl::token::spl_token::instruction::AuthorityType;
+use anchor_spl::token::{self, SetAuthority};
+
+/// Authority an `AuthorityVault` can hold and hand off.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum HandoffAuthority {
+    MintTokens,    // mint authority of an SPL mint
+    FreezeAccount, // freeze authority of an SPL mint
+    AccountOwner,  // owner of an SPL token account
+    CloseAccount,  // close authority of an SPL token account
+    Upgrade,       // upgrade authority stored in a program's ProgramData account
+    Stake,         // staker and withdrawer of a native stake account
+}
+
+impl HandoffAuthority {
+    /// Program that owns the account the authority is stored in.
+    pub fn program_id(self) -> Pubkey {
+        match self {
+            HandoffAuthority::Upgrade => bpf_loader_upgradeable::ID,
+            HandoffAuthority::Stake => stake::program::ID,
+            _ => token::ID,
+        }
+    }
+
+    /// Move the authority over `target` from `current` to `new_authority`.
+    /// `clock` is the Clock sysvar, which only the stake program reads.
+    pub fn transfer<'info>(
+        self,
+        program: AccountInfo<'info>,
+        target: AccountInfo<'info>,
+        current: AccountInfo<'info>,
+        new_authority: AccountInfo<'info>,
+        clock: Option<AccountInfo<'info>>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let authority_type = match self {
+            HandoffAuthority::MintTokens => AuthorityType::MintTokens,
+            HandoffAuthority::FreezeAccount => AuthorityType::FreezeAccount,
+            HandoffAuthority::AccountOwner => AuthorityType::AccountOwner,
+            HandoffAuthority::CloseAccount => AuthorityType::CloseAccount,
+            HandoffAuthority::Upgrade => {
+                // The loader's SetAuthority is variant 4 of its bincode instruction enum
+                let instruction = Instruction {
+                    program_id: bpf_loader_upgradeable::ID,
+                    accounts: vec![
+                        AccountMeta::new(target.key(), false),
+                        AccountMeta::new_readonly(current.key(), true),
+                        AccountMeta::new_readonly(new_authority.key(), false),
+                    ],
+                    data: 4u32.to_le_bytes().to_vec(),
+                };
+                invoke_signed(
+                    &instruction,
+                    &[target, current, new_authority, program],
+                    signer_seeds,
+                )?;
+                return Ok(());
+            }
+            HandoffAuthority::Stake => {
+                let clock = clock.ok_or(VaultError::InvalidHandoffTarget)?;
+
+                // Authorize is variant 1 of the stake instruction enum; the
+                // staker moves first while `current` still holds both roles
+                for stake_authorize in [0u32, 1u32] {
+                    let mut data = 1u32.to_le_bytes().to_vec();
+                    data.extend_from_slice(new_authority.key.as_ref());
+                    data.extend_from_slice(&stake_authorize.to_le_bytes());
+                    let instruction = Instruction {
+                        program_id: stake::program::ID,
+                        accounts: vec![
+                            AccountMeta::new(target.key(), false),
+                            AccountMeta::new_readonly(clock.key(), false),
+                            AccountMeta::new_readonly(current.key(), true),
+                        ],
+                        data,
+                    };
+                    invoke_signed(
+                        &instruction,
+                        &[
+                            target.clone(),
+                            clock.clone(),
+                            current.clone(),
+                            program.clone(),
+                        ],
+                        signer_seeds,
+                    )?;
+                }
+                return Ok(());
+            }
+        };
+
+        token::set_authority(
+            CpiContext::new_with_signer(
+                program,
+                SetAuthority {
+                    current_authority: current,
+                    account_or_mint: target,
+                },
+                signer_seeds,
+            ),
+            authority_type,
+            Some(new_authority.key()),
+        )
+    }
+}
+
+/// Holds one mint, token account, program or stake authority on the owner's behalf
+/// and passes it to the beneficiary once the owner goes silent.
+#[account]
+pub struct AuthorityVault {
+    pub owner: Pubkey,
+    pub beneficiary: Pubkey,
+    pub target: Pubkey, // mint, token account, ProgramData or stake account
+    pub authority: HandoffAuthority,
+    pub timer: HeartbeatTimer,
+    pub bump: u8,
+}
+
+impl AuthorityVault {
+    pub const INIT_SPACE: usize = 8 + 32 * 3 + 1 + HeartbeatTimer::INIT_SPACE + 1;
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::MAX_ALLOWED_CALLERS;
+
+/// Programs an owner trusts to send heartbeats on their behalf through CPI.
+#[account]
+pub struct CallerAllowlist {
+    pub owner: Pubkey,
+    pub programs: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl CallerAllowlist {
+    pub const INIT_SPACE: usize = 8 + 32 + (4 + 32 * MAX_ALLOWED_CALLERS) + 1;
+
+    pub fn allows(&self, program: &Pubkey) -> bool {
+        self.programs.contains(program)
+    }
+}
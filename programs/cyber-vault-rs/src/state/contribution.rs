@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+/// Running total one contributor has paid into one vault. A record only;
+/// it grants no withdrawal rights.
+#[account]
+pub struct Contribution {
+    pub vault: Pubkey,
+    pub contributor: Pubkey,
+    pub total_amount: u64,
+    pub last_contributed_at: i64,
+    pub bump: u8,
+}
+
+impl Contribution {
+    pub const INIT_SPACE: usize = 8 + 32 + 32 + 8 + 8 + 1;
+}
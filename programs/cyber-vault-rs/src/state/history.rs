@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VaultError;
+use crate::state::Vault;
+use crate::HISTORY_LEN;
+
+/// Ring buffer of the last `HISTORY_LEN` owner actions on one vault, so the
+/// beneficiary or an auditor can see the check-in pattern.
+#[account(zero_copy)]
+pub struct HeartbeatHistory {
+    pub vault: Pubkey,
+    pub next: u32, // index the next entry is written to
+    pub len: u32,  // entries written so far, capped at HISTORY_LEN
+    pub bump: u8,
+    pub _padding: [u8; 7],
+    pub entries: [HistoryEntry; HISTORY_LEN],
+}
+
+const _: () = assert!(std::mem::size_of::<HeartbeatHistory>() == 48 + 56 * HISTORY_LEN);
+
+impl HeartbeatHistory {
+    pub const INIT_SPACE: usize = 8 + std::mem::size_of::<HeartbeatHistory>();
+
+    pub fn record(&mut self, timestamp: i64, signer: Pubkey, kind: HistoryKind, amount: u64) {
+        self.entries[self.next as usize] = HistoryEntry {
+            timestamp,
+            amount,
+            signer,
+            kind: kind as u8,
+            _padding: [0; 7],
+        };
+        self.next = (self.next + 1) % HISTORY_LEN as u32;
+        self.len = (self.len + 1).min(HISTORY_LEN as u32);
+    }
+}
+
+#[zero_copy]
+pub struct HistoryEntry {
+    pub timestamp: i64,
+    pub amount: u64, // tokens withdrawn, 0 for heartbeats
+    pub signer: Pubkey,
+    pub kind: u8, // HistoryKind as u8
+    pub _padding: [u8; 7],
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum HistoryKind {
+    Heartbeat,
+    EmergencyWithdraw,
+    QueuedWithdrawal,
+}
+
+/// Append to the vault's history if it keeps one; the account is then mandatory.
+pub fn record_history(
+    vault: &Vault,
+    history: Option<&AccountLoader<HeartbeatHistory>>,
+    timestamp: i64,
+    signer: Pubkey,
+    kind: HistoryKind,
+    amount: u64,
+) -> Result<()> {
+    if !vault.has_history() {
+        return Ok(());
+    }
+    let history = history.ok_or(VaultError::HistoryRequired)?;
+    history.load_mut()?.record(timestamp, signer, kind, amount);
+    Ok(())
+}
+
+/// Proof of life from any heartbeat path. Going through one place keeps the
+/// history complete no matter how the owner checked in. A linked vault only
+/// follows its switch, so a heartbeat there would not count and is rejected.
+pub fn record_heartbeat(
+    vault: &mut Vault,
+    history: Option<&AccountLoader<HeartbeatHistory>>,
+    clock: &Clock,
+    signer: Pubkey,
+) -> Result<()> {
+    require!(!vault.is_linked(), VaultError::SwitchAlreadyLinked);
+    vault.touch(clock);
+    record_history(
+        vault,
+        history,
+        clock.unix_timestamp,
+        signer,
+        HistoryKind::Heartbeat,
+        0,
+    )
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::error::VaultError;
+use crate::MAX_ALLOWED_MINTS;
+
+/// Program-wide mint policy, managed by the program's upgrade authority.
+#[account]
+pub struct MintPolicy {
+    pub allowlist_enabled: bool,
+    pub mints: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl MintPolicy {
+    pub const INIT_SPACE: usize = 8 + 1 + (4 + 32 * MAX_ALLOWED_MINTS) + 1;
+
+    pub fn allows(&self, mint: &Pubkey) -> bool {
+        !self.allowlist_enabled || self.mints.contains(mint)
+    }
+}
+
+/// Gate for every instruction that takes custody of a mint. `mint_policy` is
+/// the policy PDA, which may not exist yet.
+pub fn check_mint(
+    mint: &Account<Mint>,
+    mint_policy: &AccountInfo,
+    acknowledge_freeze_authority: bool,
+) -> Result<()> {
+    // A freeze authority can lock the escrow token account and strand the inheritance
+    require!(
+        mint.freeze_authority.is_none() || acknowledge_freeze_authority,
+        VaultError::MintHasFreezeAuthority
+    );
+
+    if !mint_policy.data_is_empty() {
+        // Only this program can write to its own PDA, so the data is the stored policy
+        let data = mint_policy.try_borrow_data()?;
+        let policy = MintPolicy::try_deserialize(&mut &data[..])?;
+        require!(policy.allows(&mint.key()), VaultError::MintNotAllowed);
+    }
+
+    Ok(())
+}
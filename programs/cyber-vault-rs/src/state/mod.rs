@@ -1,3 +1,27 @@
+pub mod authority_vault;
+pub mod caller_allowlist;
+pub mod contribution;
+pub mod history;
+pub mod mint_policy;
+pub mod nft_vault;
+pub mod pending_withdrawal;
+pub mod recovery;
+pub mod registry;
+pub mod stats;
+pub mod switch;
+pub mod timer;
 pub mod vault;
 
+pub use authority_vault::*;
+pub use caller_allowlist::*;
+pub use contribution::*;
+pub use history::*;
+pub use mint_policy::*;
+pub use nft_vault::*;
+pub use pending_withdrawal::*;
+pub use recovery::*;
+pub use registry::*;
+pub use stats::*;
+pub use switch::*;
+pub use timer::*;
 pub use vault::*;
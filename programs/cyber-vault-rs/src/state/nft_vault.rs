@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VaultError;
+use crate::state::HeartbeatTimer;
+use crate::MAX_VAULT_NFTS;
+
+/// Holds a collection of single-supply mints, each in its own escrow token
+/// account, and passes them to the beneficiary once the owner goes silent.
+/// Fixed-size fields come first so clients can read the claim progress at a
+/// known offset.
+#[account]
+pub struct NftVault {
+    pub owner: Pubkey,
+    pub beneficiary: Pubkey,
+    pub timer: HeartbeatTimer,
+    pub claim_cursor: u32, // mints[..claim_cursor] have been paid to the beneficiary
+    pub bump: u8,
+    pub mints: Vec<Pubkey>,    // escrowed NFTs, claimed in this order
+    pub deferred: Vec<Pubkey>, // passed by the cursor while their escrow was frozen
+}
+
+impl NftVault {
+    pub const INIT_SPACE: usize = 8
+        + 32
+        + 32
+        + HeartbeatTimer::INIT_SPACE
+        + 4
+        + 1
+        + (4 + 32 * MAX_VAULT_NFTS)
+        + (4 + 32 * MAX_VAULT_NFTS);
+
+    /// The first claim batch locks the owner out until the vault is emptied.
+    pub fn is_claiming(&self) -> bool {
+        self.claim_cursor > 0
+    }
+
+    /// NFTs not yet paid out.
+    pub fn unclaimed(&self) -> &[Pubkey] {
+        &self.mints[self.claim_cursor as usize..]
+    }
+
+    /// Nothing left in escrow, so the vault can close.
+    pub fn is_settled(&self) -> bool {
+        self.unclaimed().is_empty() && self.deferred.is_empty()
+    }
+
+    pub fn insert(&mut self, mint: Pubkey) -> Result<()> {
+        require!(self.mints.len() < MAX_VAULT_NFTS, VaultError::NftVaultFull);
+        self.mints.push(mint);
+        Ok(())
+    }
+
+    /// Order only matters once a claim starts, so removal can swap.
+    pub fn remove(&mut self, mint: &Pubkey) {
+        if let Some(index) = self.mints.iter().position(|m| m == mint) {
+            self.mints.swap_remove(index);
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct PendingWithdrawal {
+    pub vault: Pubkey,
+    pub amount: u64,
+    pub requested_at: i64,
+    pub executable_at: i64,
+    pub bump: u8,
+}
+
+impl PendingWithdrawal {
+    pub const INIT_SPACE: usize = 8 + 32 + 8 + 8 + 8 + 1;
+}
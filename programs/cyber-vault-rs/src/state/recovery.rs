@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::MAX_GUARDIANS;
+
+#[account]
+pub struct Recovery {
+    pub vault: Pubkey,
+    pub guardians: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timelock: i64,
+    pub pending_owner: Option<Pubkey>,
+    pub approvals: u8, // bitmap indexed by guardian position
+    pub initiated_at: i64,
+    pub bump: u8,
+}
+
+impl Recovery {
+    pub const INIT_SPACE: usize = 8 + 32 + (4 + 32 * MAX_GUARDIANS) + 1 + 8 + (1 + 32) + 1 + 8 + 1;
+
+    pub fn guardian_index(&self, key: &Pubkey) -> Option<usize> {
+        self.guardians.iter().position(|g| g == key)
+    }
+
+    pub fn approval_count(&self) -> u32 {
+        self.approvals.count_ones()
+    }
+
+    pub fn clear_pending(&mut self) {
+        self.pending_owner = None;
+        self.approvals = 0;
+        self.initiated_at = 0;
+    }
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::MAX_INDEXED_VAULTS;
+
+/// Vaults owned by one key, so clients can list them with a single fetch.
+#[account]
+pub struct OwnerRegistry {
+    pub owner: Pubkey,
+    pub vaults: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl OwnerRegistry {
+    pub const INIT_SPACE: usize = 8 + 32 + (4 + 32 * MAX_INDEXED_VAULTS) + 1;
+
+    pub fn insert(&mut self, vault: Pubkey) {
+        insert_vault(&mut self.vaults, vault)
+    }
+
+    pub fn remove(&mut self, vault: &Pubkey) {
+        remove_vault(&mut self.vaults, vault)
+    }
+}
+
+/// Vaults naming one key as beneficiary.
+#[account]
+pub struct BeneficiaryIndex {
+    pub beneficiary: Pubkey,
+    pub vaults: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl BeneficiaryIndex {
+    pub const INIT_SPACE: usize = 8 + 32 + (4 + 32 * MAX_INDEXED_VAULTS) + 1;
+
+    pub fn insert(&mut self, vault: Pubkey) {
+        insert_vault(&mut self.vaults, vault)
+    }
+
+    pub fn remove(&mut self, vault: &Pubkey) {
+        remove_vault(&mut self.vaults, vault)
+    }
+}
+
+/// Indexing is best effort: a full list must never block the instruction
+/// that tried to extend it, or anyone could lock a key out of new vaults by
+/// filling its index with dust vaults.
+fn insert_vault(vaults: &mut Vec<Pubkey>, vault: Pubkey) {
+    if vaults.contains(&vault) {
+        return;
+    }
+    if vaults.len() >= MAX_INDEXED_VAULTS {
+        msg!("Vault index full, {} not listed", vault);
+        return;
+    }
+    vaults.push(vault);
+}
+
+fn remove_vault(vaults: &mut Vec<Pubkey>, vault: &Pubkey) {
+    if let Some(index) = vaults.iter().position(|v| v == vault) {
+        vaults.swap_remove(index);
+    }
+}
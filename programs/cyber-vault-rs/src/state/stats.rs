@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+/// Protocol-wide vault counters. `active_vaults` is created minus claimed minus closed.
+#[account]
+pub struct GlobalStats {
+    pub vaults_created: u64,
+    pub active_vaults: u64,
+    pub vaults_claimed: u64,
+    pub vaults_closed: u64,
+    pub bump: u8,
+}
+
+impl GlobalStats {
+    pub const INIT_SPACE: usize = 8 + 8 * 4 + 1;
+
+    pub fn record_created(&mut self) {
+        self.vaults_created = self.vaults_created.saturating_add(1);
+        self.active_vaults = self.active_vaults.saturating_add(1);
+    }
+
+    pub fn record_claimed(&mut self) {
+        self.vaults_claimed = self.vaults_claimed.saturating_add(1);
+        self.active_vaults = self.active_vaults.saturating_sub(1);
+    }
+
+    pub fn record_closed(&mut self) {
+        self.vaults_closed = self.vaults_closed.saturating_add(1);
+        self.active_vaults = self.active_vaults.saturating_sub(1);
+    }
+}
+
+/// Value locked in vaults of one mint. Tokens sent straight to a vault token
+/// account bypass the program and are not counted, so counters saturate
+/// rather than fail: stats must never block a claim or withdrawal.
+#[account]
+pub struct MintStats {
+    pub mint: Pubkey,
+    pub locked_amount: u64,
+    pub active_vaults: u64,
+    pub bump: u8,
+}
+
+impl MintStats {
+    pub const INIT_SPACE: usize = 8 + 32 + 8 + 8 + 1;
+
+    pub fn deposit(&mut self, amount: u64) {
+        self.locked_amount = self.locked_amount.saturating_add(amount);
+    }
+
+    pub fn withdraw(&mut self, amount: u64) {
+        self.locked_amount = self.locked_amount.saturating_sub(amount);
+    }
+
+    pub fn open_vault(&mut self) {
+        self.active_vaults = self.active_vaults.saturating_add(1);
+    }
+
+    pub fn retire_vault(&mut self) {
+        self.active_vaults = self.active_vaults.saturating_sub(1);
+    }
+}
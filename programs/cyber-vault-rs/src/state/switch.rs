@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct Switch {
+    pub owner: Pubkey,
+    pub inactivity_period: i64,
+    pub last_heartbeat: i64,
+    pub linked_vaults: u32,
+    pub bump: u8,
+}
+
+impl Switch {
+    pub const INIT_SPACE: usize = 8 + 32 + 8 + 8 + 4 + 1;
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+/// Plain timestamp timer shared by the NFT and authority vaults. Unlike
+/// `Vault` it has no absence window, slot mode or master switch: those
+/// vaults expire on wall-clock silence alone.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct HeartbeatTimer {
+    pub inactivity_period: i64,
+    pub last_heartbeat: i64,
+}
+
+impl HeartbeatTimer {
+    pub const INIT_SPACE: usize = 8 + 8;
+
+    pub fn new(inactivity_period: i64, clock: &Clock) -> Self {
+        Self {
+            inactivity_period,
+            last_heartbeat: clock.unix_timestamp,
+        }
+    }
+
+    /// Record proof of life.
+    pub fn touch(&mut self, clock: &Clock) {
+        self.last_heartbeat = clock.unix_timestamp;
+    }
+
+    pub fn is_expired(&self, clock: &Clock) -> bool {
+        clock.unix_timestamp - self.last_heartbeat >= self.inactivity_period
+    }
+}
@@ -1,17 +1,228 @@
 use anchor_lang::prelude::*;
 
-#[account]
+use crate::state::Switch;
+
+/// Fixed `repr(C)` layout read in place, so instructions do not pay to
+/// deserialize the whole vault. Fields are ordered by alignment; new fields
+/// go before `_padding` and must keep the size a multiple of 8.
+#[account(zero_copy)]
 pub struct Vault {
     pub owner: Pubkey,
     pub beneficiary: Pubkey,
     pub token_mint: Pubkey,
     pub vault_token_account: Pubkey,
+    pub creator: Pubkey, // owner key the PDA was derived from; `owner` may rotate
+    pub switch: Pubkey,  // shared master switch, Pubkey::default() when unlinked
     pub inactivity_period: i64,
     pub last_heartbeat: i64,
-    pub is_active: bool,
+    pub policy_effective_at: i64, // 0 when nothing is staged
+    pub period_start: i64,
+    pub period_withdrawn: u64,
+    pub withdraw_policy: WithdrawPolicy,
+    pub pending_policy: WithdrawPolicy, // looser policy waiting out the current delay
+    pub is_active: u8,
     pub bump: u8,
+    pub _padding: [u8; 6],
+    pub absence_start: i64, // scheduled absence window, both 0 when none
+    pub absence_end: i64,
+    pub absence_year_start: i64, // start of the yearly absence allowance
+    pub absence_used: i64,       // seconds of absence scheduled this year
+    pub duress_key: Pubkey,      // Pubkey::default() when duress mode is off
+    pub duress_lock_period: i64, // seconds a duress heartbeat freezes withdrawals
+    pub frozen_until: i64,
+    pub last_heartbeat_slot: u64,
+    pub expiry_mode: u8, // ExpiryMode as u8
+    pub history_enabled: u8,
+    pub _padding2: [u8; 6],
+    pub deposit_source: Pubkey, // owner token account delegating to the vault
+    pub deposit_amount: u64,    // per installment, 0 when no schedule
+    pub deposit_interval: i64,
+    pub deposit_end: i64, // no installment is pulled after this timestamp
+    pub next_deposit_at: i64,
+    pub pending_owner: Pubkey, // proposed by the owner, Pubkey::default() when none
 }
 
+const _: () = assert!(std::mem::size_of::<Vault>() == 480);
+
 impl Vault {
-    pub const INIT_SPACE: usize = 8 + 32 + 32 + 32 + 32 + 8 + 8 + 1 + 1;
+    pub const INIT_SPACE: usize = 8 + std::mem::size_of::<Vault>();
+
+    pub fn is_active(&self) -> bool {
+        self.is_active != 0
+    }
+
+    pub fn set_active(&mut self, active: bool) {
+        self.is_active = active as u8;
+    }
+
+    pub fn has_history(&self) -> bool {
+        self.history_enabled != 0
+    }
+
+    pub fn set_history(&mut self, enabled: bool) {
+        self.history_enabled = enabled as u8;
+    }
+
+    pub fn expiry_mode(&self) -> ExpiryMode {
+        if self.expiry_mode == ExpiryMode::Slot as u8 {
+            ExpiryMode::Slot
+        } else {
+            ExpiryMode::Timestamp
+        }
+    }
+
+    pub fn set_expiry_mode(&mut self, mode: ExpiryMode) {
+        self.expiry_mode = mode as u8;
+    }
+
+    /// Record proof of life on both timers.
+    pub fn touch(&mut self, clock: &Clock) {
+        self.last_heartbeat = clock.unix_timestamp;
+        self.last_heartbeat_slot = clock.slot;
+    }
+
+    /// Record an instruction signed by the owner. Any owner signature is proof
+    /// of life, so every owner-signed instruction on a vault calls this rather
+    /// than `touch`. The exceptions are `close_vault`, which ends the vault,
+    /// and `create_switch` and `set_allowed_callers`, which write no vault.
+    /// Heartbeats go through `record_heartbeat`, and NFT and authority vaults
+    /// touch their own `HeartbeatTimer`.
+    pub fn touch_by_owner(&mut self, clock: &Clock) {
+        self.touch(clock);
+    }
+
+    /// True once the governing timer has run out. Linked vaults always follow
+    /// the switch's timestamp timer.
+    pub fn is_expired(&self, switch: Option<&Switch>, clock: &Clock) -> Result<bool> {
+        if !self.is_linked() && self.expiry_mode() == ExpiryMode::Slot {
+            let elapsed = clock.slot.saturating_sub(self.last_heartbeat_slot);
+            return Ok(elapsed >= self.inactivity_period as u64);
+        }
+        let (last_heartbeat, inactivity_period) = self.liveness(switch)?;
+        Ok(self.silence_since(last_heartbeat, clock.unix_timestamp) >= inactivity_period)
+    }
+
+    pub fn is_frozen(&self, now: i64) -> bool {
+        now < self.frozen_until
+    }
+
+    pub fn is_linked(&self) -> bool {
+        self.switch != Pubkey::default()
+    }
+
+    /// Detach from the master switch, releasing its link count. A linked vault
+    /// must be given its switch so the count stays accurate.
+    pub fn unlink(&mut self, switch: Option<&mut Switch>) -> Result<()> {
+        if self.is_linked() {
+            let switch = switch.ok_or(crate::error::VaultError::SwitchRequired)?;
+            switch.linked_vaults = switch.linked_vaults.saturating_sub(1);
+            self.switch = Pubkey::default();
+        }
+        Ok(())
+    }
+
+    /// Resolve the timer that governs expiry: the linked switch if any, else the vault's own.
+    pub fn liveness(&self, switch: Option<&Switch>) -> Result<(i64, i64)> {
+        if !self.is_linked() {
+            return Ok((self.last_heartbeat, self.inactivity_period));
+        }
+        let switch = switch.ok_or(crate::error::VaultError::SwitchRequired)?;
+        Ok((switch.last_heartbeat, switch.inactivity_period))
+    }
+
+    /// Seconds of silence between `from` and `now`, not counting the absence window.
+    pub fn silence_since(&self, from: i64, now: i64) -> i64 {
+        let paused = (self.absence_end.min(now) - self.absence_start.max(from)).max(0);
+        now - from - paused
+    }
+
+    /// Promote a staged policy once its delay has run out.
+    pub fn apply_due_policy(&mut self, now: i64) {
+        if self.policy_effective_at != 0 && now >= self.policy_effective_at {
+            self.withdraw_policy = self.pending_policy;
+            self.clear_pending_policy();
+            self.period_start = now;
+            self.period_withdrawn = 0;
+        }
+    }
+
+    pub fn clear_pending_policy(&mut self) {
+        self.pending_policy = WithdrawPolicy::default();
+        self.policy_effective_at = 0;
+    }
+
+    pub fn has_deposit_schedule(&self) -> bool {
+        self.deposit_amount != 0
+    }
+
+    pub fn clear_deposit_schedule(&mut self) {
+        self.deposit_source = Pubkey::default();
+        self.deposit_amount = 0;
+        self.deposit_interval = 0;
+        self.deposit_end = 0;
+        self.next_deposit_at = 0;
+    }
+
+    /// Move the schedule past `now`. Windows the crank missed are skipped,
+    /// not pulled later in a burst.
+    pub fn advance_deposit_schedule(&mut self, now: i64) {
+        let missed = (now - self.next_deposit_at) / self.deposit_interval;
+        self.next_deposit_at += (missed + 1) * self.deposit_interval;
+    }
+
+    /// Count an instant withdrawal against the current period's limit.
+    pub fn record_withdrawal(&mut self, amount: u64, now: i64) -> Result<()> {
+        if !self.withdraw_policy.is_enabled() {
+            return Ok(());
+        }
+
+        if now - self.period_start >= self.withdraw_policy.period {
+            self.period_start = now;
+            self.period_withdrawn = 0;
+        }
+
+        let withdrawn = self
+            .period_withdrawn
+            .checked_add(amount)
+            .ok_or(crate::error::VaultError::WithdrawLimitExceeded)?;
+        require!(
+            withdrawn <= self.withdraw_policy.limit,
+            crate::error::VaultError::WithdrawLimitExceeded
+        );
+        self.period_withdrawn = withdrawn;
+
+        Ok(())
+    }
+}
+
+/// How `inactivity_period` is measured.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ExpiryMode {
+    Timestamp, // seconds of `Clock::unix_timestamp`
+    Slot,      // slots, immune to validator timestamp skew
+}
+
+#[zero_copy]
+#[derive(Default, PartialEq, Eq)]
+pub struct WithdrawPolicy {
+    pub limit: u64,  // max instant withdrawal per period, 0 = unlimited
+    pub period: i64, // seconds
+    pub delay: i64,  // seconds a queued withdrawal waits before execution
+}
+
+impl WithdrawPolicy {
+    pub fn is_enabled(&self) -> bool {
+        self.limit > 0
+    }
+
+    /// True when switching from `current` to `self` gives a thief no extra room.
+    pub fn is_at_least_as_strict_as(&self, current: &WithdrawPolicy) -> bool {
+        if !current.is_enabled() {
+            return true;
+        }
+        self.is_enabled()
+            && self.limit <= current.limit
+            && self.period >= current.period
+            && self.delay >= current.delay
+    }
 }
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use cyber_vault_rs::cpi::accounts::CpiHeartbeat;
+use cyber_vault_rs::program::CyberVaultRs;
+
+// Example consumer; the owner adds this ID with `set_allowed_callers`
+declare_id!("9KRpmbS6pwqC4845JHqt4ZgaTW1nQQj6hGAwYdF9V1Hw");
+
+/// Stand-in for any everyday action in another program. Each call also
+/// counts as proof of life for the caller's Cyber-Vault.
+#[program]
+pub mod heartbeat_consumer {
+    use super::*;
+
+    pub fn record_activity(ctx: Context<RecordActivity>) -> Result<()> {
+        msg!("📝 Activity recorded for {}", ctx.accounts.owner.key());
+
+        cyber_vault_rs::cpi::cpi_heartbeat(CpiContext::new(
+            ctx.accounts.cyber_vault_program.to_account_info(),
+            CpiHeartbeat {
+                vault: ctx.accounts.vault.to_account_info(),
+                owner: ctx.accounts.owner.to_account_info(),
+                caller_allowlist: ctx.accounts.caller_allowlist.to_account_info(),
+                instructions: ctx.accounts.instructions.to_account_info(),
+                history: ctx
+                    .accounts
+                    .history
+                    .as_ref()
+                    .map(|history| history.to_account_info()),
+            },
+        ))
+    }
+}
+
+#[derive(Accounts)]
+pub struct RecordActivity<'info> {
+    pub owner: Signer<'info>,
+
+    /// CHECK: validated by cyber-vault-rs
+    #[account(mut)]
+    pub vault: UncheckedAccount<'info>,
+
+    /// CHECK: validated by cyber-vault-rs
+    pub caller_allowlist: UncheckedAccount<'info>,
+
+    /// CHECK: validated by cyber-vault-rs
+    pub instructions: UncheckedAccount<'info>,
+
+    pub cyber_vault_program: Program<'info, CyberVaultRs>,
+
+    /// CHECK: validated by cyber-vault-rs; required once the vault keeps a history
+    #[account(mut)]
+    pub history: Option<UncheckedAccount<'info>>,
+}
@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+// Test helper; a real treasury would use an audited multisig
+declare_id!("HVdu2tsAM2kvMNUhnRdp9b1tioHaHz6hgYu323L7spwk");
+
+pub const SIGNER_SEED: &[u8] = b"signer";
+pub const MAX_MEMBERS: usize = 10;
+
+/// M-of-N multisig whose signer PDA can own a Cyber-Vault. Members co-sign a
+/// single `execute` transaction, which relays any instruction with the PDA
+/// signing. The PDA holds no data, so it can also pay rent.
+#[program]
+pub mod minimal_multisig {
+    use super::*;
+
+    pub fn create_multisig(
+        ctx: Context<CreateMultisig>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        require!(
+            !members.is_empty() && members.len() <= MAX_MEMBERS,
+            MultisigError::InvalidMembers
+        );
+        for (i, member) in members.iter().enumerate() {
+            require!(
+                !members[..i].contains(member),
+                MultisigError::InvalidMembers
+            );
+        }
+        require!(
+            threshold >= 1 && threshold as usize <= members.len(),
+            MultisigError::InvalidMembers
+        );
+
+        let multisig = &mut ctx.accounts.multisig;
+        multisig.members = members;
+        multisig.threshold = threshold;
+        multisig.signer_bump = ctx.bumps.signer;
+
+        msg!("👥 Multisig created: {}", multisig.key());
+        msg!("Signer: {}", ctx.accounts.signer.key());
+
+        Ok(())
+    }
+
+    /// The first `approvals` remaining accounts are approving members; the
+    /// rest are the accounts of the relayed instruction, in order.
+    pub fn execute<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Execute<'info>>,
+        approvals: u8,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        let approvals = approvals as usize;
+        require!(
+            approvals <= ctx.remaining_accounts.len(),
+            MultisigError::NotEnoughApprovals
+        );
+        let (approvers, relayed) = ctx.remaining_accounts.split_at(approvals);
+
+        let multisig = &ctx.accounts.multisig;
+        for (i, approver) in approvers.iter().enumerate() {
+            require!(
+                approver.is_signer
+                    && multisig.members.contains(approver.key)
+                    && !approvers[..i].iter().any(|a| a.key == approver.key),
+                MultisigError::NotEnoughApprovals
+            );
+        }
+        require!(
+            approvals >= multisig.threshold as usize,
+            MultisigError::NotEnoughApprovals
+        );
+
+        let signer_key = ctx.accounts.signer.key();
+        let instruction = Instruction {
+            program_id: ctx.accounts.target_program.key(),
+            accounts: relayed
+                .iter()
+                .map(|account| AccountMeta {
+                    pubkey: *account.key,
+                    is_signer: account.is_signer || *account.key == signer_key,
+                    is_writable: account.is_writable,
+                })
+                .collect(),
+            data,
+        };
+
+        let mut account_infos = relayed.to_vec();
+        account_infos.push(ctx.accounts.signer.to_account_info());
+        account_infos.push(ctx.accounts.target_program.to_account_info());
+
+        let multisig_key = multisig.key();
+        let seeds = &[SIGNER_SEED, multisig_key.as_ref(), &[multisig.signer_bump]];
+        invoke_signed(&instruction, &account_infos, &[&seeds[..]])?;
+
+        msg!(
+            "✍️ Multisig {} executed with {} approvals",
+            multisig_key,
+            approvals
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(members: Vec<Pubkey>)]
+pub struct CreateMultisig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(init, payer = payer, space = Multisig::space(members.len()))]
+    pub multisig: Account<'info, Multisig>,
+
+    /// CHECK: data-less PDA that signs for the multisig
+    #[account(seeds = [SIGNER_SEED, multisig.key().as_ref()], bump)]
+    pub signer: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Execute<'info> {
+    pub multisig: Account<'info, Multisig>,
+
+    /// CHECK: data-less PDA that signs for the multisig
+    #[account(
+        mut,
+        seeds = [SIGNER_SEED, multisig.key().as_ref()],
+        bump = multisig.signer_bump,
+    )]
+    pub signer: UncheckedAccount<'info>,
+
+    /// CHECK: any program; the multisig vouches for the call, not the callee
+    #[account(executable)]
+    pub target_program: UncheckedAccount<'info>,
+}
+
+#[account]
+pub struct Multisig {
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub signer_bump: u8,
+}
+
+impl Multisig {
+    pub fn space(members: usize) -> usize {
+        8 + (4 + 32 * members) + 1 + 1
+    }
+}
+
+#[error_code]
+pub enum MultisigError {
+    #[msg("Members must be 1 to 10 distinct keys with a threshold between 1 and their count.")]
+    InvalidMembers,
+    #[msg("Not enough distinct member signatures.")]
+    NotEnoughApprovals,
+}
@@ -0,0 +1,435 @@
+use litesvm::LiteSVM;
+use litesvm_token::{spl_token, CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+
+const INACTIVITY_PERIOD: i64 = 3600;
+const DAY: i64 = 86_400;
+
+// VaultError codes
+const ABSENCE_NOT_SCHEDULED: u32 = 6056;
+
+/// Test fixture for scheduled absences
+struct AbsenceTestFixture {
+    svm: LiteSVM,
+    program_id: Pubkey,
+    owner: Keypair,
+    beneficiary: Keypair,
+    mint: Pubkey,
+    vault_pda: Pubkey,
+    vault_token_pda: Pubkey,
+    owner_registry_pda: Pubkey,
+    beneficiary_index_pda: Pubkey,
+    global_stats_pda: Pubkey,
+    mint_stats_pda: Pubkey,
+}
+
+impl AbsenceTestFixture {
+    fn new() -> Self {
+        let mut svm = LiteSVM::new();
+
+        // Load the compiled program
+        let program_keypair =
+            read_keypair_file("target/deploy/cyber_vault_rs-keypair.json").unwrap();
+        let program_id = program_keypair.pubkey();
+        let program_bytes = include_bytes!("../target/deploy/cyber_vault_rs.so");
+        svm.add_program(program_id, program_bytes);
+
+        let owner = Keypair::new();
+        let beneficiary = Keypair::new();
+        svm.airdrop(&owner.pubkey(), 10_000_000_000).unwrap();
+        svm.airdrop(&beneficiary.pubkey(), 10_000_000_000).unwrap();
+
+        let mint = CreateMint::new(&mut svm, &owner)
+            .authority(&owner.pubkey())
+            .decimals(6)
+            .send()
+            .unwrap();
+        let owner_ata = CreateAssociatedTokenAccount::new(&mut svm, &owner, &mint)
+            .owner(&owner.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &owner, &mint, &owner_ata, 1_000_000)
+            .send()
+            .unwrap();
+
+        let (vault_pda, _) = Pubkey::find_program_address(
+            &[
+                b"vault",
+                owner.pubkey().as_ref(),
+                beneficiary.pubkey().as_ref(),
+                mint.as_ref(),
+            ],
+            &program_id,
+        );
+        let (vault_token_pda, _) =
+            Pubkey::find_program_address(&[b"vault_token", vault_pda.as_ref()], &program_id);
+        let (owner_registry_pda, _) = Pubkey::find_program_address(
+            &[b"owner_registry", owner.pubkey().as_ref()],
+            &program_id,
+        );
+        let (beneficiary_index_pda, _) = Pubkey::find_program_address(
+            &[b"beneficiary_index", beneficiary.pubkey().as_ref()],
+            &program_id,
+        );
+        let (global_stats_pda, _) = Pubkey::find_program_address(&[b"global_stats"], &program_id);
+        let (mint_stats_pda, _) =
+            Pubkey::find_program_address(&[b"mint_stats", mint.as_ref()], &program_id);
+
+        let mut fixture = Self {
+            svm,
+            program_id,
+            owner,
+            beneficiary,
+            mint,
+            vault_pda,
+            vault_token_pda,
+            owner_registry_pda,
+            beneficiary_index_pda,
+            global_stats_pda,
+            mint_stats_pda,
+        };
+
+        // create_vault discriminator: [29, 237, 247, 208, 193, 82, 54, 135]
+        let mut instruction_data = vec![29, 237, 247, 208, 193, 82, 54, 135];
+        instruction_data.extend_from_slice(&fixture.beneficiary.pubkey().to_bytes());
+        instruction_data.extend_from_slice(&INACTIVITY_PERIOD.to_le_bytes());
+        instruction_data.extend_from_slice(&1_000_000u64.to_le_bytes());
+        instruction_data.push(0); // acknowledge_freeze_authority
+        let (mint_policy_pda, _) = Pubkey::find_program_address(&[b"mint_policy"], &program_id);
+        let instruction = Instruction {
+            program_id,
+            accounts: vec![
+                AccountMeta::new(fixture.owner.pubkey(), true),
+                AccountMeta::new(vault_pda, false),
+                AccountMeta::new(vault_token_pda, false),
+                AccountMeta::new(owner_ata, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
+                AccountMeta::new(owner_registry_pda, false),
+                AccountMeta::new(beneficiary_index_pda, false),
+                AccountMeta::new(global_stats_pda, false),
+                AccountMeta::new(mint_stats_pda, false),
+                AccountMeta::new_readonly(mint_policy_pda, false),
+            ],
+            data: instruction_data,
+        };
+        let owner = fixture.owner.insecure_clone();
+        fixture.send(instruction, &owner).unwrap();
+
+        fixture
+    }
+
+    fn send(&mut self, instruction: Instruction, signer: &Keypair) -> Result<(), String> {
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        self.svm
+            .send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn schedule_absence(&mut self, signer: &Keypair, start: i64, end: i64) -> Result<(), String> {
+        // schedule_absence discriminator: [62, 170, 61, 17, 196, 83, 148, 227]
+        let mut instruction_data = vec![62, 170, 61, 17, 196, 83, 148, 227];
+        instruction_data.extend_from_slice(&start.to_le_bytes());
+        instruction_data.extend_from_slice(&end.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(signer.pubkey(), true),
+                AccountMeta::new(self.vault_pda, false),
+            ],
+            data: instruction_data,
+        };
+
+        self.send(instruction, signer)
+    }
+
+    fn cancel_absence(&mut self) -> Result<(), String> {
+        let owner = self.owner.insecure_clone();
+
+        // cancel_absence discriminator: [140, 19, 4, 167, 11, 211, 234, 71]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(owner.pubkey(), true),
+                AccountMeta::new(self.vault_pda, false),
+            ],
+            data: vec![140, 19, 4, 167, 11, 211, 234, 71],
+        };
+
+        self.send(instruction, &owner)
+    }
+
+    fn claim(&mut self) -> Result<(), String> {
+        let beneficiary = self.beneficiary.insecure_clone();
+        let beneficiary_ata = get_associated_token_address(&beneficiary.pubkey(), &self.mint);
+
+        // claim discriminator: [62, 198, 214, 193, 213, 159, 108, 210]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.vault_pda, false),
+                AccountMeta::new(self.vault_token_pda, false),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new(beneficiary_ata, false),
+                AccountMeta::new(beneficiary.pubkey(), true),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new(self.owner_registry_pda, false),
+                AccountMeta::new(self.beneficiary_index_pda, false),
+                AccountMeta::new(self.global_stats_pda, false),
+                AccountMeta::new(self.mint_stats_pda, false),
+            ],
+            data: vec![62, 198, 214, 193, 213, 159, 108, 210],
+        };
+
+        self.send(instruction, &beneficiary)
+    }
+
+    /// Creates an empty NFT vault for the same owner and beneficiary
+    fn create_nft_vault(&mut self) -> Pubkey {
+        let owner = self.owner.insecure_clone();
+        let (nft_vault, _) = Pubkey::find_program_address(
+            &[
+                b"nft_vault",
+                owner.pubkey().as_ref(),
+                self.beneficiary.pubkey().as_ref(),
+            ],
+            &self.program_id,
+        );
+
+        // create_nft_vault discriminator: [150, 78, 66, 49, 224, 254, 62, 147]
+        let mut instruction_data = vec![150, 78, 66, 49, 224, 254, 62, 147];
+        instruction_data.extend_from_slice(self.beneficiary.pubkey().as_ref());
+        instruction_data.extend_from_slice(&INACTIVITY_PERIOD.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(owner.pubkey(), true),
+                AccountMeta::new(nft_vault, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: instruction_data,
+        };
+
+        self.send(instruction, &owner).unwrap();
+        nft_vault
+    }
+
+    /// Claims an empty NFT vault, which closes it once expired
+    fn claim_nfts(&mut self, nft_vault: &Pubkey) -> Result<(), String> {
+        let beneficiary = self.beneficiary.insecure_clone();
+
+        // claim_nfts discriminator: [185, 98, 203, 203, 168, 52, 91, 162]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(beneficiary.pubkey(), true),
+                AccountMeta::new(*nft_vault, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+            ],
+            data: vec![185, 98, 203, 203, 168, 52, 91, 162],
+        };
+
+        self.send(instruction, &beneficiary)
+    }
+
+    fn now(&self) -> i64 {
+        self.svm.get_sysvar::<Clock>().unix_timestamp
+    }
+
+    fn set_time(&mut self, unix_timestamp: i64) {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp = unix_timestamp;
+        self.svm.set_sysvar(&clock);
+    }
+}
+
+#[test]
+fn test_claim_waits_out_absence_window() {
+    println!("🧪 Testing that claims respect a scheduled absence");
+
+    let mut fixture = AbsenceTestFixture::new();
+    let owner = fixture.owner.insecure_clone();
+    let t0 = fixture.now();
+    let start = t0 + 1000;
+    let end = start + 30 * DAY;
+    fixture.schedule_absence(&owner, start, end).unwrap();
+
+    // Well past the normal timeout, but inside the window
+    fixture.set_time(t0 + 10 * DAY);
+    assert!(
+        fixture.claim().is_err(),
+        "Claim during an absence should fail"
+    );
+
+    // 1000s of silence before the window, the rest must follow it
+    fixture.set_time(end + INACTIVITY_PERIOD - 1000 - 1);
+    assert!(
+        fixture.claim().is_err(),
+        "Claim before the remaining period runs out should fail"
+    );
+    println!("✅ Expiry suspended during the absence");
+
+    fixture.set_time(end + INACTIVITY_PERIOD - 1000);
+    fixture.claim().unwrap();
+    println!("✅ Normal period resumed after the absence");
+}
+
+#[test]
+fn test_schedule_absence_validation() {
+    println!("🧪 Testing absence window validation");
+
+    let mut fixture = AbsenceTestFixture::new();
+    let owner = fixture.owner.insecure_clone();
+    let now = fixture.now();
+
+    assert!(fixture
+        .schedule_absence(&owner, now - 1, now + DAY)
+        .is_err());
+    assert!(fixture
+        .schedule_absence(&owner, now + DAY, now + DAY)
+        .is_err());
+    assert!(fixture
+        .schedule_absence(&owner, now, now + 91 * DAY)
+        .is_err());
+    println!("✅ Past, empty and oversized windows rejected");
+
+    fixture
+        .schedule_absence(&owner, now + DAY, now + 2 * DAY)
+        .unwrap();
+    assert!(
+        fixture
+            .schedule_absence(&owner, now + 3 * DAY, now + 4 * DAY)
+            .is_err(),
+        "A second absence before the first ends should fail"
+    );
+    println!("✅ Overlapping schedule rejected");
+
+    let stranger = Keypair::new();
+    fixture
+        .svm
+        .airdrop(&stranger.pubkey(), 1_000_000_000)
+        .unwrap();
+    fixture.set_time(now + 3 * DAY);
+    assert!(fixture
+        .schedule_absence(&stranger, now + 3 * DAY, now + 4 * DAY)
+        .is_err());
+    println!("✅ Non-owner rejected");
+}
+
+#[test]
+fn test_yearly_absence_allowance() {
+    println!("🧪 Testing the yearly absence cap");
+
+    let mut fixture = AbsenceTestFixture::new();
+    let owner = fixture.owner.insecure_clone();
+    let t0 = fixture.now();
+
+    fixture.schedule_absence(&owner, t0, t0 + 60 * DAY).unwrap();
+    fixture.set_time(t0 + 100 * DAY);
+    let now = fixture.now();
+    assert!(
+        fixture
+            .schedule_absence(&owner, now, now + 31 * DAY)
+            .is_err(),
+        "Exceeding 90 days in a year should fail"
+    );
+    fixture
+        .schedule_absence(&owner, now, now + 30 * DAY)
+        .unwrap();
+    println!("✅ Allowance capped at 90 days");
+
+    // A year after the first absence the allowance renews
+    fixture.set_time(t0 + 365 * DAY);
+    let now = fixture.now();
+    fixture
+        .schedule_absence(&owner, now, now + 90 * DAY)
+        .unwrap();
+    println!("✅ Allowance renewed after a year");
+}
+
+#[test]
+fn test_cancel_absence_refunds_unused_allowance() {
+    println!("🧪 Testing cancellation of a scheduled absence");
+
+    let mut fixture = AbsenceTestFixture::new();
+    let owner = fixture.owner.insecure_clone();
+    let t0 = fixture.now();
+
+    let err = fixture.cancel_absence().unwrap_err();
+    assert!(err.contains(&format!("Custom({})", ABSENCE_NOT_SCHEDULED)));
+
+    // Cancelled before it starts: the whole window goes back to the allowance
+    fixture
+        .schedule_absence(&owner, t0 + DAY, t0 + 61 * DAY)
+        .unwrap();
+    fixture.cancel_absence().unwrap();
+    fixture
+        .schedule_absence(&owner, t0 + 2 * DAY, t0 + 92 * DAY)
+        .unwrap();
+    println!("✅ Pending window replaced with a full 90-day one");
+
+    // Cancelled 10 days in: the unused 80 days are refunded
+    fixture.set_time(t0 + 12 * DAY);
+    fixture.cancel_absence().unwrap();
+    let now = fixture.now();
+    assert!(
+        fixture
+            .schedule_absence(&owner, now + DAY, now + 82 * DAY)
+            .is_err(),
+        "Only the unused part of the window should be refunded"
+    );
+    println!("✅ Days already spent away stay counted");
+
+    // The window is over, so the normal period runs from the cancellation
+    fixture.set_time(now + INACTIVITY_PERIOD - 1);
+    assert!(fixture.claim().is_err());
+    fixture.set_time(now + INACTIVITY_PERIOD);
+    fixture.claim().unwrap();
+    println!("✅ Expiry resumed right after the cancellation");
+}
+
+#[test]
+fn test_absence_does_not_pause_nft_vault() {
+    println!("🧪 Testing that an absence only covers the token vault");
+
+    let mut fixture = AbsenceTestFixture::new();
+    let owner = fixture.owner.insecure_clone();
+    let nft_vault = fixture.create_nft_vault();
+    let t0 = fixture.now();
+    fixture
+        .schedule_absence(&owner, t0 + 1, t0 + 30 * DAY)
+        .unwrap();
+
+    fixture.set_time(t0 + 10 * DAY);
+    assert!(
+        fixture.claim().is_err(),
+        "Token vault should be paused by the absence"
+    );
+    println!("✅ Token vault paused");
+
+    // NFT and authority vaults run a plain wall-clock timer
+    fixture.claim_nfts(&nft_vault).unwrap();
+    assert!(fixture.svm.get_account(&nft_vault).is_none());
+    println!("✅ NFT vault expired on its own timer");
+}
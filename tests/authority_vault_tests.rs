@@ -0,0 +1,337 @@
+use litesvm::LiteSVM;
+use litesvm_token::{get_spl_account, spl_token, CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    account::Account,
+    bpf_loader_upgradeable,
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+
+// VaultError codes
+const VAULT_NOT_EXPIRED: u32 = 6001;
+const INVALID_HANDOFF_TARGET: u32 = 6050;
+const HANDOFF_EXPIRED: u32 = 6051;
+
+// HandoffAuthority variants
+const MINT_TOKENS: u8 = 0;
+const ACCOUNT_OWNER: u8 = 2;
+const UPGRADE: u8 = 4;
+
+/// Test fixture for vaults holding mint, token account and upgrade authorities
+struct AuthorityVaultTestFixture {
+    svm: LiteSVM,
+    program_id: Pubkey,
+    owner: Keypair,
+    beneficiary: Keypair,
+    mint: Pubkey,
+}
+
+impl AuthorityVaultTestFixture {
+    fn new() -> Self {
+        let mut svm = LiteSVM::new();
+
+        // Load the compiled program
+        let program_keypair =
+            read_keypair_file("target/deploy/cyber_vault_rs-keypair.json").unwrap();
+        let program_id = program_keypair.pubkey();
+        let program_bytes = include_bytes!("../target/deploy/cyber_vault_rs.so");
+        svm.add_program(program_id, program_bytes);
+
+        let owner = Keypair::new();
+        let beneficiary = Keypair::new();
+        svm.airdrop(&owner.pubkey(), 10_000_000_000).unwrap();
+        svm.airdrop(&beneficiary.pubkey(), 10_000_000_000).unwrap();
+
+        let mint = CreateMint::new(&mut svm, &owner)
+            .authority(&owner.pubkey())
+            .decimals(6)
+            .send()
+            .unwrap();
+
+        Self {
+            svm,
+            program_id,
+            owner,
+            beneficiary,
+            mint,
+        }
+    }
+
+    fn authority_vault_pda(&self, target: &Pubkey, authority: u8) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"authority_vault", target.as_ref(), &[authority]],
+            &self.program_id,
+        )
+        .0
+    }
+
+    fn authority_program(authority: u8) -> Pubkey {
+        if authority == UPGRADE {
+            bpf_loader_upgradeable::id()
+        } else {
+            spl_token::id()
+        }
+    }
+
+    fn send(&mut self, instruction: Instruction, signer: &Keypair) -> Result<(), String> {
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        self.svm
+            .send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn create_authority_vault(
+        &mut self,
+        target: &Pubkey,
+        authority: u8,
+        authority_program: &Pubkey,
+    ) -> Result<(), String> {
+        let owner = self.owner.insecure_clone();
+
+        // create_authority_vault discriminator: [173, 158, 69, 243, 172, 21, 227, 171]
+        let mut instruction_data = vec![173, 158, 69, 243, 172, 21, 227, 171];
+        instruction_data.extend_from_slice(self.beneficiary.pubkey().as_ref());
+        instruction_data.extend_from_slice(&3600i64.to_le_bytes());
+        instruction_data.push(authority);
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(owner.pubkey(), true),
+                AccountMeta::new(self.authority_vault_pda(target, authority), false),
+                AccountMeta::new(*target, false),
+                AccountMeta::new_readonly(*authority_program, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: instruction_data,
+        };
+
+        self.send(instruction, &owner)
+    }
+
+    fn authority_heartbeat(&mut self, target: &Pubkey, authority: u8) {
+        let owner = self.owner.insecure_clone();
+
+        // authority_heartbeat discriminator: [248, 249, 95, 60, 189, 163, 109, 99]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(owner.pubkey(), true),
+                AccountMeta::new(self.authority_vault_pda(target, authority), false),
+            ],
+            data: vec![248, 249, 95, 60, 189, 163, 109, 99],
+        };
+
+        self.send(instruction, &owner).unwrap();
+    }
+
+    /// Sends reclaim_authority as the owner or claim_authority as the beneficiary
+    fn hand_back(
+        &mut self,
+        target: &Pubkey,
+        authority: u8,
+        to_beneficiary: bool,
+    ) -> Result<(), String> {
+        let signer = if to_beneficiary {
+            self.beneficiary.insecure_clone()
+        } else {
+            self.owner.insecure_clone()
+        };
+        let data = if to_beneficiary {
+            // claim_authority discriminator: [222, 132, 185, 123, 127, 107, 6, 31]
+            vec![222, 132, 185, 123, 127, 107, 6, 31]
+        } else {
+            // reclaim_authority discriminator: [156, 147, 67, 113, 251, 229, 162, 166]
+            vec![156, 147, 67, 113, 251, 229, 162, 166]
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(signer.pubkey(), true),
+                AccountMeta::new(self.authority_vault_pda(target, authority), false),
+                AccountMeta::new(*target, false),
+                AccountMeta::new_readonly(Self::authority_program(authority), false),
+            ],
+            data,
+        };
+
+        self.send(instruction, &signer)
+    }
+
+    fn advance_clock(&mut self, seconds: i64) {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp += seconds;
+        self.svm.set_sysvar(&clock);
+    }
+
+    fn mint_authority(&self) -> Option<Pubkey> {
+        get_spl_account::<spl_token::state::Mint>(&self.svm, &self.mint)
+            .unwrap()
+            .mint_authority
+            .into()
+    }
+
+    fn token_account_owner(&self, account: &Pubkey) -> Pubkey {
+        get_spl_account::<spl_token::state::Account>(&self.svm, account)
+            .unwrap()
+            .owner
+    }
+}
+
+fn assert_vault_error(result: Result<(), String>, code: u32) {
+    let err = result.expect_err("instruction should have been rejected");
+    assert!(
+        err.contains(&format!("Custom({})", code)),
+        "expected error {}, got {}",
+        code,
+        err
+    );
+}
+
+#[test]
+fn test_mint_authority_passes_to_beneficiary() {
+    println!("🧪 Testing mint authority handoff");
+
+    let mut fixture = AuthorityVaultTestFixture::new();
+    let mint = fixture.mint;
+
+    assert_vault_error(
+        fixture.create_authority_vault(&Pubkey::new_unique(), MINT_TOKENS, &spl_token::id()),
+        INVALID_HANDOFF_TARGET,
+    );
+    println!("✅ Target not owned by the token program rejected");
+
+    fixture
+        .create_authority_vault(&mint, MINT_TOKENS, &spl_token::id())
+        .unwrap();
+    let authority_vault = fixture.authority_vault_pda(&mint, MINT_TOKENS);
+    assert_eq!(fixture.mint_authority(), Some(authority_vault));
+    println!("✅ Mint authority held by the vault PDA");
+
+    assert_vault_error(
+        fixture.hand_back(&mint, MINT_TOKENS, true),
+        VAULT_NOT_EXPIRED,
+    );
+    fixture.advance_clock(3600);
+    fixture.hand_back(&mint, MINT_TOKENS, true).unwrap();
+
+    assert_eq!(fixture.mint_authority(), Some(fixture.beneficiary.pubkey()));
+    assert!(fixture.svm.get_account(&authority_vault).is_none());
+    println!("✅ Beneficiary became the mint authority after the owner went silent");
+
+    let beneficiary = fixture.beneficiary.insecure_clone();
+    let beneficiary_ata = CreateAssociatedTokenAccount::new(&mut fixture.svm, &beneficiary, &mint)
+        .owner(&beneficiary.pubkey())
+        .send()
+        .unwrap();
+    MintTo::new(
+        &mut fixture.svm,
+        &beneficiary,
+        &mint,
+        &beneficiary_ata,
+        1_000,
+    )
+    .send()
+    .unwrap();
+    println!("✅ Beneficiary can mint");
+}
+
+#[test]
+fn test_owner_reclaims_only_while_alive() {
+    println!("🧪 Testing heartbeat-gated reclaim of a token account owner");
+
+    let mut fixture = AuthorityVaultTestFixture::new();
+    let owner = fixture.owner.insecure_clone();
+    let mint = fixture.mint;
+    let owner_ata = CreateAssociatedTokenAccount::new(&mut fixture.svm, &owner, &mint)
+        .owner(&owner.pubkey())
+        .send()
+        .unwrap();
+
+    fixture
+        .create_authority_vault(&owner_ata, ACCOUNT_OWNER, &spl_token::id())
+        .unwrap();
+    let authority_vault = fixture.authority_vault_pda(&owner_ata, ACCOUNT_OWNER);
+    assert_eq!(fixture.token_account_owner(&owner_ata), authority_vault);
+
+    // A heartbeat halfway keeps the owner in control past the original deadline
+    fixture.advance_clock(1800);
+    fixture.authority_heartbeat(&owner_ata, ACCOUNT_OWNER);
+    fixture.advance_clock(1800);
+    fixture.hand_back(&owner_ata, ACCOUNT_OWNER, false).unwrap();
+    assert_eq!(fixture.token_account_owner(&owner_ata), owner.pubkey());
+    println!("✅ Owner reclaimed the account while alive");
+
+    fixture
+        .create_authority_vault(&owner_ata, ACCOUNT_OWNER, &spl_token::id())
+        .unwrap();
+    fixture.advance_clock(3600);
+    assert_vault_error(
+        fixture.hand_back(&owner_ata, ACCOUNT_OWNER, false),
+        HANDOFF_EXPIRED,
+    );
+    fixture.hand_back(&owner_ata, ACCOUNT_OWNER, true).unwrap();
+    assert_eq!(
+        fixture.token_account_owner(&owner_ata),
+        fixture.beneficiary.pubkey()
+    );
+    println!("✅ After expiry only the beneficiary can take the account");
+}
+
+#[test]
+fn test_upgrade_authority_passes_to_beneficiary() {
+    println!("🧪 Testing program upgrade authority handoff");
+
+    let mut fixture = AuthorityVaultTestFixture::new();
+    let owner = fixture.owner.pubkey();
+
+    // ProgramData state: tag 3, deployment slot, Some(upgrade authority)
+    let program_data = Pubkey::new_unique();
+    let mut data = 3u32.to_le_bytes().to_vec();
+    data.extend_from_slice(&0u64.to_le_bytes());
+    data.push(1);
+    data.extend_from_slice(owner.as_ref());
+    fixture
+        .svm
+        .set_account(
+            program_data,
+            Account {
+                lamports: 1_000_000_000,
+                data,
+                owner: bpf_loader_upgradeable::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+    assert_vault_error(
+        fixture.create_authority_vault(&program_data, UPGRADE, &spl_token::id()),
+        INVALID_HANDOFF_TARGET,
+    );
+    fixture
+        .create_authority_vault(&program_data, UPGRADE, &bpf_loader_upgradeable::id())
+        .unwrap();
+    let authority_vault = fixture.authority_vault_pda(&program_data, UPGRADE);
+    let data = fixture.svm.get_account(&program_data).unwrap().data;
+    assert_eq!(&data[13..45], authority_vault.as_ref());
+    println!("✅ Upgrade authority held by the vault PDA");
+
+    fixture.advance_clock(3600);
+    fixture.hand_back(&program_data, UPGRADE, true).unwrap();
+    let data = fixture.svm.get_account(&program_data).unwrap().data;
+    assert_eq!(&data[13..45], fixture.beneficiary.pubkey().as_ref());
+    println!("✅ Beneficiary became the upgrade authority");
+}
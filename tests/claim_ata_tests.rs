@@ -0,0 +1,294 @@
+use litesvm::LiteSVM;
+use litesvm_token::{get_spl_account, spl_token, CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+
+const DEPOSIT: u64 = 1_000_000;
+
+/// Test fixture for claims into the beneficiary's associated token account
+struct ClaimAtaTestFixture {
+    svm: LiteSVM,
+    program_id: Pubkey,
+    owner: Keypair,
+    beneficiary: Keypair,
+    mint: Pubkey,
+    vault_pda: Pubkey,
+    vault_token_pda: Pubkey,
+    owner_registry_pda: Pubkey,
+    beneficiary_index_pda: Pubkey,
+    global_stats_pda: Pubkey,
+    mint_stats_pda: Pubkey,
+}
+
+impl ClaimAtaTestFixture {
+    fn new() -> Self {
+        let mut svm = LiteSVM::new();
+
+        // Load the compiled program
+        let program_keypair =
+            read_keypair_file("target/deploy/cyber_vault_rs-keypair.json").unwrap();
+        let program_id = program_keypair.pubkey();
+        let program_bytes = include_bytes!("../target/deploy/cyber_vault_rs.so");
+        svm.add_program(program_id, program_bytes);
+
+        let owner = Keypair::new();
+        let beneficiary = Keypair::new();
+        svm.airdrop(&owner.pubkey(), 10_000_000_000).unwrap();
+        svm.airdrop(&beneficiary.pubkey(), 10_000_000_000).unwrap();
+
+        let mint = CreateMint::new(&mut svm, &owner)
+            .authority(&owner.pubkey())
+            .decimals(6)
+            .send()
+            .unwrap();
+
+        let owner_ata = CreateAssociatedTokenAccount::new(&mut svm, &owner, &mint)
+            .owner(&owner.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &owner, &mint, &owner_ata, DEPOSIT)
+            .send()
+            .unwrap();
+
+        let (vault_pda, _) = Pubkey::find_program_address(
+            &[
+                b"vault",
+                owner.pubkey().as_ref(),
+                beneficiary.pubkey().as_ref(),
+                mint.as_ref(),
+            ],
+            &program_id,
+        );
+        let (vault_token_pda, _) =
+            Pubkey::find_program_address(&[b"vault_token", vault_pda.as_ref()], &program_id);
+        let (owner_registry_pda, _) = Pubkey::find_program_address(
+            &[b"owner_registry", owner.pubkey().as_ref()],
+            &program_id,
+        );
+        let (beneficiary_index_pda, _) = Pubkey::find_program_address(
+            &[b"beneficiary_index", beneficiary.pubkey().as_ref()],
+            &program_id,
+        );
+        let (global_stats_pda, _) = Pubkey::find_program_address(&[b"global_stats"], &program_id);
+        let (mint_stats_pda, _) =
+            Pubkey::find_program_address(&[b"mint_stats", mint.as_ref()], &program_id);
+
+        let mut fixture = Self {
+            svm,
+            program_id,
+            owner,
+            beneficiary,
+            mint,
+            vault_pda,
+            vault_token_pda,
+            owner_registry_pda,
+            beneficiary_index_pda,
+            global_stats_pda,
+            mint_stats_pda,
+        };
+        fixture.create_vault(&owner_ata);
+        fixture
+    }
+
+    fn create_vault(&mut self, owner_ata: &Pubkey) {
+        let inactivity_period: i64 = 3600;
+
+        // create_vault discriminator: [29, 237, 247, 208, 193, 82, 54, 135]
+        let mut instruction_data = vec![29, 237, 247, 208, 193, 82, 54, 135];
+        instruction_data.extend_from_slice(&self.beneficiary.pubkey().to_bytes());
+        instruction_data.extend_from_slice(&inactivity_period.to_le_bytes());
+        instruction_data.extend_from_slice(&DEPOSIT.to_le_bytes());
+        instruction_data.push(0); // acknowledge_freeze_authority
+        let (mint_policy_pda, _) =
+            Pubkey::find_program_address(&[b"mint_policy"], &self.program_id);
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.owner.pubkey(), true),
+                AccountMeta::new(self.vault_pda, false),
+                AccountMeta::new(self.vault_token_pda, false),
+                AccountMeta::new(*owner_ata, false),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
+                AccountMeta::new(self.owner_registry_pda, false),
+                AccountMeta::new(self.beneficiary_index_pda, false),
+                AccountMeta::new(self.global_stats_pda, false),
+                AccountMeta::new(self.mint_stats_pda, false),
+                AccountMeta::new_readonly(mint_policy_pda, false),
+            ],
+            data: instruction_data,
+        };
+
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner).unwrap();
+
+        // Let the vault expire so only the token account checks can fail
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp += inactivity_period;
+        self.svm.set_sysvar(&clock);
+    }
+
+    fn send(&mut self, instruction: Instruction, signer: &Keypair) -> Result<(), String> {
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        self.svm
+            .send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn claim(
+        &mut self,
+        signer: &Keypair,
+        token_mint: &Pubkey,
+        destination: &Pubkey,
+    ) -> Result<(), String> {
+        // claim discriminator: [62, 198, 214, 193, 213, 159, 108, 210]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.vault_pda, false),
+                AccountMeta::new(self.vault_token_pda, false),
+                AccountMeta::new_readonly(*token_mint, false),
+                AccountMeta::new(*destination, false),
+                AccountMeta::new(signer.pubkey(), true),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new(self.owner_registry_pda, false),
+                AccountMeta::new(self.beneficiary_index_pda, false),
+                AccountMeta::new(self.global_stats_pda, false),
+                AccountMeta::new(self.mint_stats_pda, false),
+            ],
+            data: vec![62, 198, 214, 193, 213, 159, 108, 210],
+        };
+
+        self.send(instruction, signer)
+    }
+
+    fn token_balance(&self, account: &Pubkey) -> u64 {
+        get_spl_account::<spl_token::state::Account>(&self.svm, account)
+            .unwrap()
+            .amount
+    }
+}
+
+#[test]
+fn test_claim_creates_missing_associated_token_account() {
+    println!("🧪 Testing claim when the beneficiary has no token account yet");
+
+    let mut fixture = ClaimAtaTestFixture::new();
+    let beneficiary = fixture.beneficiary.insecure_clone();
+    let mint = fixture.mint;
+    let ata = get_associated_token_address(&beneficiary.pubkey(), &mint);
+    assert!(fixture.svm.get_account(&ata).is_none());
+
+    fixture.claim(&beneficiary, &mint, &ata).unwrap();
+
+    assert_eq!(fixture.token_balance(&ata), DEPOSIT);
+    println!("✅ Associated token account created and funded by the claim");
+}
+
+#[test]
+fn test_claim_into_existing_associated_token_account() {
+    println!("🧪 Testing claim into an existing associated token account");
+
+    let mut fixture = ClaimAtaTestFixture::new();
+    let beneficiary = fixture.beneficiary.insecure_clone();
+    let mint = fixture.mint;
+    let ata = CreateAssociatedTokenAccount::new(&mut fixture.svm, &beneficiary, &mint)
+        .owner(&beneficiary.pubkey())
+        .send()
+        .unwrap();
+
+    fixture.claim(&beneficiary, &mint, &ata).unwrap();
+
+    assert_eq!(fixture.token_balance(&ata), DEPOSIT);
+    println!("✅ Existing associated token account reused");
+}
+
+#[test]
+fn test_claim_rejects_token_account_of_another_owner() {
+    println!("🧪 Testing claim into someone else's token account");
+
+    let mut fixture = ClaimAtaTestFixture::new();
+    let beneficiary = fixture.beneficiary.insecure_clone();
+    let owner = fixture.owner.insecure_clone();
+    let mint = fixture.mint;
+    let foreign_ata = CreateAssociatedTokenAccount::new(&mut fixture.svm, &owner, &mint)
+        .owner(&owner.pubkey())
+        .send()
+        .unwrap();
+
+    assert!(
+        fixture.claim(&beneficiary, &mint, &foreign_ata).is_err(),
+        "Claim into another owner's token account should fail"
+    );
+    println!("✅ Foreign token account rejected");
+}
+
+#[test]
+fn test_claim_rejects_mismatched_mint() {
+    println!("🧪 Testing claim with a token account for a different mint");
+
+    let mut fixture = ClaimAtaTestFixture::new();
+    let beneficiary = fixture.beneficiary.insecure_clone();
+    let owner = fixture.owner.insecure_clone();
+    let mint = fixture.mint;
+    let other_mint = CreateMint::new(&mut fixture.svm, &owner)
+        .authority(&owner.pubkey())
+        .decimals(6)
+        .send()
+        .unwrap();
+    let other_ata = CreateAssociatedTokenAccount::new(&mut fixture.svm, &beneficiary, &other_mint)
+        .owner(&beneficiary.pubkey())
+        .send()
+        .unwrap();
+
+    assert!(
+        fixture.claim(&beneficiary, &mint, &other_ata).is_err(),
+        "Claim into a token account of another mint should fail"
+    );
+    assert!(
+        fixture
+            .claim(&beneficiary, &other_mint, &other_ata)
+            .is_err(),
+        "Claim naming a mint other than the vault's should fail"
+    );
+    println!("✅ Mismatched mint rejected");
+}
+
+#[test]
+fn test_claim_rejects_non_beneficiary_signer() {
+    println!("🧪 Testing claim signed by someone other than the beneficiary");
+
+    let mut fixture = ClaimAtaTestFixture::new();
+    let stranger = Keypair::new();
+    fixture
+        .svm
+        .airdrop(&stranger.pubkey(), 1_000_000_000)
+        .unwrap();
+    let mint = fixture.mint;
+    let stranger_ata = get_associated_token_address(&stranger.pubkey(), &mint);
+
+    assert!(
+        fixture.claim(&stranger, &mint, &stranger_ata).is_err(),
+        "Claim by a non-beneficiary should fail"
+    );
+    println!("✅ Non-beneficiary claim rejected");
+}
@@ -0,0 +1,824 @@
+use litesvm::LiteSVM;
+use litesvm_token::{
+    get_spl_account, spl_token, Approve, CreateAssociatedTokenAccount, CreateMint, MintTo,
+};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+
+/// Compute unit ceiling per measured instruction, mirrored in SMART_CONTRACT.md.
+/// Raise one only together with the table there.
+const BUDGETS: &[(&str, u64)] = &[
+    ("create_vault", 80_000),
+    ("create_vault (wSOL)", 90_000),
+    ("heartbeat", 10_000),
+    ("heartbeat_many (1 vault)", 15_000),
+    ("emergency_withdraw", 30_000),
+    ("emergency_withdraw (unwrap)", 50_000),
+    ("emergency_withdraw (history)", 35_000),
+    ("set_withdraw_policy", 15_000),
+    ("set_guardians", 30_000),
+    ("create_switch", 20_000),
+    ("link_switch", 15_000),
+    ("switch_heartbeat", 10_000),
+    ("unlink_switch", 15_000),
+    ("queue_withdrawal", 25_000),
+    ("execute_withdrawal", 30_000),
+    ("veto_withdrawal", 20_000),
+    ("claim", 50_000),
+    ("set_duress_key", 10_000),
+    ("schedule_absence", 10_000),
+    ("cancel_absence", 10_000),
+    ("set_expiry_mode", 10_000),
+    ("create_history", 25_000),
+    ("heartbeat (history)", 15_000),
+    ("contribute", 40_000),
+    ("set_deposit_schedule", 15_000),
+    ("pull_contribution", 30_000),
+    ("close_vault", 40_000),
+    ("approve_recovery", 15_000),
+    ("cancel_recovery", 10_000),
+    ("execute_recovery", 40_000),
+    ("propose_owner", 10_000),
+    ("accept_owner", 40_000),
+];
+
+/// Test fixture that records compute units per instruction
+struct ComputeUnitsFixture {
+    svm: LiteSVM,
+    program_id: Pubkey,
+    owner: Keypair,
+    beneficiary: Keypair,
+    mint: Pubkey,
+    owner_ata: Pubkey,
+    beneficiary_ata: Pubkey,
+    vault_pda: Pubkey,
+    vault_token_pda: Pubkey,
+    owner_registry_pda: Pubkey,
+    beneficiary_index_pda: Pubkey,
+    global_stats_pda: Pubkey,
+    mint_stats_pda: Pubkey,
+    switch_pda: Pubkey,
+    recovery_pda: Pubkey,
+    withdrawal_pda: Pubkey,
+    unwrap_pda: Pubkey,
+    report: Vec<(&'static str, u64)>,
+}
+
+impl ComputeUnitsFixture {
+    fn new() -> Self {
+        let (mut svm, program_id, owner, beneficiary) = Self::setup();
+
+        let mint = CreateMint::new(&mut svm, &owner)
+            .authority(&owner.pubkey())
+            .decimals(6)
+            .send()
+            .unwrap();
+
+        let owner_ata = CreateAssociatedTokenAccount::new(&mut svm, &owner, &mint)
+            .owner(&owner.pubkey())
+            .send()
+            .unwrap();
+        let beneficiary_ata = CreateAssociatedTokenAccount::new(&mut svm, &beneficiary, &mint)
+            .owner(&beneficiary.pubkey())
+            .send()
+            .unwrap();
+
+        MintTo::new(&mut svm, &owner, &mint, &owner_ata, 10_000_000)
+            .send()
+            .unwrap();
+
+        Self::with_mint(
+            svm,
+            program_id,
+            owner,
+            beneficiary,
+            mint,
+            owner_ata,
+            beneficiary_ata,
+        )
+    }
+
+    /// Fixture for a wSOL vault funded with plain SOL; no token accounts exist yet
+    fn native() -> Self {
+        let (svm, program_id, owner, beneficiary) = Self::setup();
+        let mint = spl_token::native_mint::id();
+        let owner_ata = get_associated_token_address(&owner.pubkey(), &mint);
+        let beneficiary_ata = get_associated_token_address(&beneficiary.pubkey(), &mint);
+
+        Self::with_mint(
+            svm,
+            program_id,
+            owner,
+            beneficiary,
+            mint,
+            owner_ata,
+            beneficiary_ata,
+        )
+    }
+
+    fn setup() -> (LiteSVM, Pubkey, Keypair, Keypair) {
+        let mut svm = LiteSVM::new();
+
+        // Load the compiled program
+        let program_keypair =
+            read_keypair_file("target/deploy/cyber_vault_rs-keypair.json").unwrap();
+        let program_id = program_keypair.pubkey();
+        let program_bytes = include_bytes!("../target/deploy/cyber_vault_rs.so");
+        svm.add_program(program_id, program_bytes);
+
+        let owner = Keypair::new();
+        let beneficiary = Keypair::new();
+        svm.airdrop(&owner.pubkey(), 10_000_000_000).unwrap();
+        svm.airdrop(&beneficiary.pubkey(), 10_000_000_000).unwrap();
+
+        (svm, program_id, owner, beneficiary)
+    }
+
+    fn with_mint(
+        svm: LiteSVM,
+        program_id: Pubkey,
+        owner: Keypair,
+        beneficiary: Keypair,
+        mint: Pubkey,
+        owner_ata: Pubkey,
+        beneficiary_ata: Pubkey,
+    ) -> Self {
+        let (vault_pda, _) = Pubkey::find_program_address(
+            &[
+                b"vault",
+                owner.pubkey().as_ref(),
+                beneficiary.pubkey().as_ref(),
+                mint.as_ref(),
+            ],
+            &program_id,
+        );
+        let (vault_token_pda, _) =
+            Pubkey::find_program_address(&[b"vault_token", vault_pda.as_ref()], &program_id);
+        let (owner_registry_pda, _) = Pubkey::find_program_address(
+            &[b"owner_registry", owner.pubkey().as_ref()],
+            &program_id,
+        );
+        let (beneficiary_index_pda, _) = Pubkey::find_program_address(
+            &[b"beneficiary_index", beneficiary.pubkey().as_ref()],
+            &program_id,
+        );
+        let (global_stats_pda, _) = Pubkey::find_program_address(&[b"global_stats"], &program_id);
+        let (mint_stats_pda, _) =
+            Pubkey::find_program_address(&[b"mint_stats", mint.as_ref()], &program_id);
+        let (switch_pda, _) =
+            Pubkey::find_program_address(&[b"switch", owner.pubkey().as_ref()], &program_id);
+        let (recovery_pda, _) =
+            Pubkey::find_program_address(&[b"recovery", vault_pda.as_ref()], &program_id);
+        let (withdrawal_pda, _) =
+            Pubkey::find_program_address(&[b"withdrawal", vault_pda.as_ref()], &program_id);
+        let (unwrap_pda, _) =
+            Pubkey::find_program_address(&[b"unwrap", vault_pda.as_ref()], &program_id);
+
+        Self {
+            svm,
+            program_id,
+            owner,
+            beneficiary,
+            mint,
+            owner_ata,
+            beneficiary_ata,
+            vault_pda,
+            vault_token_pda,
+            owner_registry_pda,
+            beneficiary_index_pda,
+            global_stats_pda,
+            mint_stats_pda,
+            switch_pda,
+            recovery_pda,
+            withdrawal_pda,
+            unwrap_pda,
+            report: Vec::new(),
+        }
+    }
+
+    /// Sends one instruction and records its compute units under `name`
+    fn measure(
+        &mut self,
+        name: &'static str,
+        accounts: Vec<AccountMeta>,
+        data: Vec<u8>,
+        signer: &Keypair,
+    ) {
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts,
+            data,
+        };
+
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        let meta = self
+            .svm
+            .send_transaction(tx)
+            .unwrap_or_else(|e| panic!("{} failed: {:?}", name, e.err));
+        self.report.push((name, meta.compute_units_consumed));
+    }
+
+    /// Measures create_vault; without an owner token account the deposit is plain SOL
+    fn create_vault(&mut self, name: &'static str, owner_token_account: Option<Pubkey>) {
+        let owner = self.owner.insecure_clone();
+
+        // create_vault discriminator: [29, 237, 247, 208, 193, 82, 54, 135]
+        let mut data = vec![29, 237, 247, 208, 193, 82, 54, 135];
+        data.extend_from_slice(&self.beneficiary.pubkey().to_bytes());
+        data.extend_from_slice(&3600i64.to_le_bytes());
+        data.extend_from_slice(&5_000_000u64.to_le_bytes());
+        data.push(0); // acknowledge_freeze_authority
+        let (mint_policy_pda, _) =
+            Pubkey::find_program_address(&[b"mint_policy"], &self.program_id);
+        let accounts = vec![
+            AccountMeta::new(owner.pubkey(), true),
+            AccountMeta::new(self.vault_pda, false),
+            AccountMeta::new(self.vault_token_pda, false),
+            match owner_token_account {
+                Some(account) => AccountMeta::new(account, false),
+                None => AccountMeta::new_readonly(self.program_id, false),
+            },
+            AccountMeta::new_readonly(self.mint, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
+            AccountMeta::new(self.owner_registry_pda, false),
+            AccountMeta::new(self.beneficiary_index_pda, false),
+            AccountMeta::new(self.global_stats_pda, false),
+            AccountMeta::new(self.mint_stats_pda, false),
+            AccountMeta::new_readonly(mint_policy_pda, false),
+        ];
+        self.measure(name, accounts, data, &owner);
+    }
+
+    /// Prints the report and fails on any instruction over its budget
+    fn check_budgets(&self) {
+        // Rows are printed as markdown so they can be pasted into SMART_CONTRACT.md
+        println!("| Instruction | Measured (CU) | Budget (CU) |");
+        println!("|-------------|---------------|-------------|");
+        for (name, units) in &self.report {
+            let budget = BUDGETS
+                .iter()
+                .find(|(budgeted, _)| budgeted == name)
+                .map(|(_, budget)| *budget)
+                .unwrap_or_else(|| panic!("{} has no compute unit budget", name));
+            println!("| `{}` | {} | {} |", name, units, budget);
+            assert!(
+                *units <= budget,
+                "{} used {} compute units, over its budget of {}",
+                name,
+                units,
+                budget
+            );
+        }
+    }
+
+    fn vault_balance(&self) -> u64 {
+        get_spl_account::<spl_token::state::Account>(&self.svm, &self.vault_token_pda)
+            .unwrap()
+            .amount
+    }
+
+    fn registry_pda(&self, owner: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"owner_registry", owner.as_ref()], &self.program_id).0
+    }
+
+    fn advance_clock(&mut self, seconds: i64) {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp += seconds;
+        self.svm.set_sysvar(&clock);
+    }
+
+    fn owner_vault_accounts(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.owner.pubkey(), true),
+            AccountMeta::new(self.vault_pda, false),
+        ]
+    }
+}
+
+#[test]
+fn test_compute_units_per_instruction() {
+    println!("🧪 Measuring compute units for each vault instruction");
+
+    let mut fixture = ComputeUnitsFixture::new();
+    let owner = fixture.owner.insecure_clone();
+    let beneficiary = fixture.beneficiary.insecure_clone();
+    let inactivity_period: i64 = 3600;
+
+    let owner_ata = fixture.owner_ata;
+    fixture.create_vault("create_vault", Some(owner_ata));
+
+    // heartbeat discriminator: [202, 104, 56, 6, 240, 170, 63, 134]
+    let accounts = vec![
+        AccountMeta::new(fixture.vault_pda, false),
+        AccountMeta::new_readonly(owner.pubkey(), true),
+    ];
+    fixture.measure(
+        "heartbeat",
+        accounts,
+        vec![202, 104, 56, 6, 240, 170, 63, 134],
+        &owner,
+    );
+
+    // heartbeat_many discriminator: [170, 110, 125, 32, 241, 62, 57, 196]
+    let accounts = vec![
+        AccountMeta::new_readonly(owner.pubkey(), true),
+        AccountMeta::new(fixture.vault_pda, false),
+    ];
+    fixture.measure(
+        "heartbeat_many (1 vault)",
+        accounts,
+        vec![170, 110, 125, 32, 241, 62, 57, 196],
+        &owner,
+    );
+
+    // emergency_withdraw discriminator: [239, 45, 203, 64, 150, 73, 218, 92]
+    let mut data = vec![239, 45, 203, 64, 150, 73, 218, 92];
+    data.extend_from_slice(&1_000_000u64.to_le_bytes());
+    let mut accounts = fixture.owner_vault_accounts();
+    accounts.extend([
+        AccountMeta::new(fixture.owner_ata, false),
+        AccountMeta::new(fixture.vault_token_pda, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new(fixture.mint_stats_pda, false),
+    ]);
+    fixture.measure("emergency_withdraw", accounts, data, &owner);
+
+    // set_withdraw_policy discriminator: [44, 31, 170, 11, 16, 115, 56, 197]
+    let mut data = vec![44, 31, 170, 11, 16, 115, 56, 197];
+    data.extend_from_slice(&2_000_000u64.to_le_bytes());
+    data.extend_from_slice(&86_400i64.to_le_bytes());
+    data.extend_from_slice(&3_600i64.to_le_bytes());
+    let accounts = fixture.owner_vault_accounts();
+    fixture.measure("set_withdraw_policy", accounts, data, &owner);
+
+    // set_guardians discriminator: [166, 69, 140, 183, 157, 169, 253, 40]
+    let mut data = vec![166, 69, 140, 183, 157, 169, 253, 40];
+    data.extend_from_slice(&2u32.to_le_bytes());
+    data.extend_from_slice(&Pubkey::new_unique().to_bytes());
+    data.extend_from_slice(&Pubkey::new_unique().to_bytes());
+    data.push(2);
+    data.extend_from_slice(&3_600i64.to_le_bytes());
+    let mut accounts = fixture.owner_vault_accounts();
+    accounts.extend([
+        AccountMeta::new(fixture.recovery_pda, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ]);
+    fixture.measure("set_guardians", accounts, data, &owner);
+
+    // create_switch discriminator: [120, 11, 193, 114, 80, 234, 99, 128]
+    let mut data = vec![120, 11, 193, 114, 80, 234, 99, 128];
+    data.extend_from_slice(&inactivity_period.to_le_bytes());
+    let accounts = vec![
+        AccountMeta::new(owner.pubkey(), true),
+        AccountMeta::new(fixture.switch_pda, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    fixture.measure("create_switch", accounts, data, &owner);
+
+    // link_switch discriminator: [136, 247, 62, 224, 223, 183, 48, 117]
+    let accounts = vec![
+        AccountMeta::new_readonly(owner.pubkey(), true),
+        AccountMeta::new(fixture.vault_pda, false),
+        AccountMeta::new(fixture.switch_pda, false),
+    ];
+    fixture.measure(
+        "link_switch",
+        accounts.clone(),
+        vec![136, 247, 62, 224, 223, 183, 48, 117],
+        &owner,
+    );
+
+    // switch_heartbeat discriminator: [152, 58, 183, 196, 71, 231, 96, 5]
+    fixture.measure(
+        "switch_heartbeat",
+        vec![
+            AccountMeta::new(fixture.switch_pda, false),
+            AccountMeta::new_readonly(owner.pubkey(), true),
+        ],
+        vec![152, 58, 183, 196, 71, 231, 96, 5],
+        &owner,
+    );
+
+    // unlink_switch discriminator: [178, 63, 65, 24, 77, 44, 37, 233]
+    fixture.measure(
+        "unlink_switch",
+        accounts,
+        vec![178, 63, 65, 24, 77, 44, 37, 233],
+        &owner,
+    );
+
+    // queue_withdrawal discriminator: [153, 8, 176, 235, 189, 140, 146, 223]
+    let mut data = vec![153, 8, 176, 235, 189, 140, 146, 223];
+    data.extend_from_slice(&1_000_000u64.to_le_bytes());
+    let mut accounts = fixture.owner_vault_accounts();
+    accounts.extend([
+        AccountMeta::new_readonly(fixture.vault_token_pda, false),
+        AccountMeta::new(fixture.withdrawal_pda, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ]);
+    fixture.measure("queue_withdrawal", accounts, data, &owner);
+
+    fixture.advance_clock(3600); // withdrawal delay
+
+    // execute_withdrawal discriminator: [113, 121, 203, 232, 137, 139, 248, 249]
+    let mut accounts = fixture.owner_vault_accounts();
+    accounts.extend([
+        AccountMeta::new(fixture.withdrawal_pda, false),
+        AccountMeta::new(fixture.owner_ata, false),
+        AccountMeta::new(fixture.vault_token_pda, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new(fixture.mint_stats_pda, false),
+    ]);
+    fixture.measure(
+        "execute_withdrawal",
+        accounts,
+        vec![113, 121, 203, 232, 137, 139, 248, 249],
+        &owner,
+    );
+
+    // Executing was proof of life, so the vault has to go silent again
+    fixture.advance_clock(inactivity_period);
+
+    // claim discriminator: [62, 198, 214, 193, 213, 159, 108, 210]
+    let accounts = vec![
+        AccountMeta::new(fixture.vault_pda, false),
+        AccountMeta::new(fixture.vault_token_pda, false),
+        AccountMeta::new_readonly(fixture.mint, false),
+        AccountMeta::new(fixture.beneficiary_ata, false),
+        AccountMeta::new(beneficiary.pubkey(), true),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(fixture.owner_registry_pda, false),
+        AccountMeta::new(fixture.beneficiary_index_pda, false),
+        AccountMeta::new(fixture.global_stats_pda, false),
+        AccountMeta::new(fixture.mint_stats_pda, false),
+    ];
+    fixture.measure(
+        "claim",
+        accounts,
+        vec![62, 198, 214, 193, 213, 159, 108, 210],
+        &beneficiary,
+    );
+
+    fixture.check_budgets();
+    println!("✅ Every instruction within its compute unit budget");
+}
+
+#[test]
+fn test_compute_units_wsol_unwrap() {
+    println!("🧪 Measuring compute units for the wSOL unwrap path");
+
+    let mut fixture = ComputeUnitsFixture::native();
+    let owner = fixture.owner.insecure_clone();
+    fixture.create_vault("create_vault (wSOL)", None);
+
+    // emergency_withdraw discriminator: [239, 45, 203, 64, 150, 73, 218, 92]
+    let mut data = vec![239, 45, 203, 64, 150, 73, 218, 92];
+    data.extend_from_slice(&1_000_000u64.to_le_bytes());
+    let mut accounts = fixture.owner_vault_accounts();
+    accounts.extend([
+        AccountMeta::new_readonly(fixture.program_id, false), // no owner token account
+        AccountMeta::new(fixture.vault_token_pda, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new(fixture.mint_stats_pda, false),
+        AccountMeta::new_readonly(fixture.mint, false),
+        AccountMeta::new(fixture.unwrap_pda, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ]);
+    fixture.measure("emergency_withdraw (unwrap)", accounts, data, &owner);
+
+    fixture.check_budgets();
+    println!("✅ Unwrap path within its compute unit budget");
+}
+
+#[test]
+fn test_compute_units_owner_settings() {
+    println!("🧪 Measuring compute units for owner settings and deposits");
+
+    let mut fixture = ComputeUnitsFixture::new();
+    let owner = fixture.owner.insecure_clone();
+    let beneficiary = fixture.beneficiary.insecure_clone();
+    let owner_ata = fixture.owner_ata;
+    fixture.create_vault("create_vault", Some(owner_ata));
+
+    // set_duress_key discriminator: [36, 185, 208, 162, 145, 74, 235, 67]
+    let mut data = vec![36, 185, 208, 162, 145, 74, 235, 67];
+    data.extend_from_slice(&Pubkey::new_unique().to_bytes());
+    data.extend_from_slice(&3_600i64.to_le_bytes());
+    let accounts = fixture.owner_vault_accounts();
+    fixture.measure("set_duress_key", accounts, data, &owner);
+
+    // schedule_absence discriminator: [62, 170, 61, 17, 196, 83, 148, 227]
+    let now = fixture.svm.get_sysvar::<Clock>().unix_timestamp;
+    let mut data = vec![62, 170, 61, 17, 196, 83, 148, 227];
+    data.extend_from_slice(&(now + 60).to_le_bytes());
+    data.extend_from_slice(&(now + 86_400).to_le_bytes());
+    let accounts = fixture.owner_vault_accounts();
+    fixture.measure("schedule_absence", accounts, data, &owner);
+
+    // cancel_absence discriminator: [140, 19, 4, 167, 11, 211, 234, 71]
+    let accounts = fixture.owner_vault_accounts();
+    fixture.measure(
+        "cancel_absence",
+        accounts,
+        vec![140, 19, 4, 167, 11, 211, 234, 71],
+        &owner,
+    );
+
+    // set_expiry_mode discriminator: [204, 200, 36, 228, 151, 37, 215, 47]
+    let mut data = vec![204, 200, 36, 228, 151, 37, 215, 47];
+    data.push(0); // ExpiryMode::Timestamp
+    data.extend_from_slice(&3_600i64.to_le_bytes());
+    let accounts = fixture.owner_vault_accounts();
+    fixture.measure("set_expiry_mode", accounts, data, &owner);
+
+    // create_history discriminator: [17, 80, 83, 78, 168, 45, 161, 35]
+    let (history_pda, _) = Pubkey::find_program_address(
+        &[b"history", fixture.vault_pda.as_ref()],
+        &fixture.program_id,
+    );
+    let mut accounts = fixture.owner_vault_accounts();
+    accounts.extend([
+        AccountMeta::new(history_pda, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ]);
+    fixture.measure(
+        "create_history",
+        accounts,
+        vec![17, 80, 83, 78, 168, 45, 161, 35],
+        &owner,
+    );
+
+    // heartbeat discriminator: [202, 104, 56, 6, 240, 170, 63, 134]
+    let accounts = vec![
+        AccountMeta::new(fixture.vault_pda, false),
+        AccountMeta::new_readonly(owner.pubkey(), true),
+        AccountMeta::new(history_pda, false),
+    ];
+    fixture.measure(
+        "heartbeat (history)",
+        accounts,
+        vec![202, 104, 56, 6, 240, 170, 63, 134],
+        &owner,
+    );
+
+    // contribute discriminator: [82, 33, 68, 131, 32, 0, 205, 95]
+    let beneficiary_ata = fixture.beneficiary_ata;
+    let mint = fixture.mint;
+    MintTo::new(&mut fixture.svm, &owner, &mint, &beneficiary_ata, 1_000_000)
+        .send()
+        .unwrap();
+    let (contribution_pda, _) = Pubkey::find_program_address(
+        &[
+            b"contribution",
+            fixture.vault_pda.as_ref(),
+            beneficiary.pubkey().as_ref(),
+        ],
+        &fixture.program_id,
+    );
+    let mut data = vec![82, 33, 68, 131, 32, 0, 205, 95];
+    data.extend_from_slice(&1_000_000u64.to_le_bytes());
+    let accounts = vec![
+        AccountMeta::new(beneficiary.pubkey(), true),
+        AccountMeta::new_readonly(fixture.vault_pda, false),
+        AccountMeta::new(fixture.vault_token_pda, false),
+        AccountMeta::new(beneficiary_ata, false),
+        AccountMeta::new(contribution_pda, false),
+        AccountMeta::new(fixture.mint_stats_pda, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    fixture.measure("contribute", accounts, data, &beneficiary);
+
+    // set_deposit_schedule discriminator: [164, 217, 16, 89, 86, 63, 73, 223]
+    let vault_pda = fixture.vault_pda;
+    Approve::new(&mut fixture.svm, &owner, &vault_pda, &owner_ata, 1_000_000)
+        .send()
+        .unwrap();
+    let mut data = vec![164, 217, 16, 89, 86, 63, 73, 223];
+    data.extend_from_slice(&500_000u64.to_le_bytes());
+    data.extend_from_slice(&86_400i64.to_le_bytes());
+    data.extend_from_slice(&(now + 30 * 86_400).to_le_bytes());
+    let mut accounts = fixture.owner_vault_accounts();
+    accounts[0] = AccountMeta::new_readonly(owner.pubkey(), true);
+    accounts.push(AccountMeta::new_readonly(owner_ata, false));
+    fixture.measure("set_deposit_schedule", accounts, data, &owner);
+
+    // pull_contribution discriminator: [3, 228, 34, 207, 180, 0, 247, 19]
+    let accounts = vec![
+        AccountMeta::new_readonly(beneficiary.pubkey(), true),
+        AccountMeta::new(fixture.vault_pda, false),
+        AccountMeta::new(fixture.vault_token_pda, false),
+        AccountMeta::new(owner_ata, false),
+        AccountMeta::new(fixture.mint_stats_pda, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+    fixture.measure(
+        "pull_contribution",
+        accounts,
+        vec![3, 228, 34, 207, 180, 0, 247, 19],
+        &beneficiary,
+    );
+
+    // Empty the vault so it can be closed
+    let mut data = vec![239, 45, 203, 64, 150, 73, 218, 92];
+    data.extend_from_slice(&fixture.vault_balance().to_le_bytes());
+    let mut accounts = fixture.owner_vault_accounts();
+    accounts.extend([
+        AccountMeta::new(owner_ata, false),
+        AccountMeta::new(fixture.vault_token_pda, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new(fixture.mint_stats_pda, false),
+        AccountMeta::new_readonly(fixture.program_id, false), // token_mint: none
+        AccountMeta::new_readonly(fixture.program_id, false), // unwrap_account: none
+        AccountMeta::new_readonly(fixture.program_id, false), // system_program: none
+        AccountMeta::new(history_pda, false),
+    ]);
+    fixture.measure("emergency_withdraw (history)", accounts, data, &owner);
+
+    // close_vault discriminator: [141, 103, 17, 126, 72, 75, 29, 29]
+    let mut accounts = fixture.owner_vault_accounts();
+    accounts.extend([
+        AccountMeta::new(fixture.vault_token_pda, false),
+        AccountMeta::new(fixture.owner_registry_pda, false),
+        AccountMeta::new(fixture.beneficiary_index_pda, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new(fixture.global_stats_pda, false),
+        AccountMeta::new(fixture.mint_stats_pda, false),
+        AccountMeta::new_readonly(fixture.program_id, false), // switch: none
+        AccountMeta::new_readonly(fixture.program_id, false), // recovery: none
+        AccountMeta::new_readonly(fixture.program_id, false), // pending_withdrawal: none
+        AccountMeta::new(history_pda, false),
+    ]);
+    fixture.measure(
+        "close_vault",
+        accounts,
+        vec![141, 103, 17, 126, 72, 75, 29, 29],
+        &owner,
+    );
+
+    fixture.check_budgets();
+    println!("✅ Every instruction within its compute unit budget");
+}
+
+#[test]
+fn test_compute_units_recovery_and_ownership() {
+    println!("🧪 Measuring compute units for recovery, vetoes and handoffs");
+
+    let mut fixture = ComputeUnitsFixture::new();
+    let owner = fixture.owner.insecure_clone();
+    let beneficiary = fixture.beneficiary.insecure_clone();
+    let owner_ata = fixture.owner_ata;
+    fixture.create_vault("create_vault", Some(owner_ata));
+
+    let guardians: Vec<Keypair> = (0..2).map(|_| Keypair::new()).collect();
+    for guardian in &guardians {
+        fixture
+            .svm
+            .airdrop(&guardian.pubkey(), 1_000_000_000)
+            .unwrap();
+    }
+    let mut data = vec![166, 69, 140, 183, 157, 169, 253, 40];
+    data.extend_from_slice(&2u32.to_le_bytes());
+    for guardian in &guardians {
+        data.extend_from_slice(&guardian.pubkey().to_bytes());
+    }
+    data.push(2);
+    data.extend_from_slice(&3_600i64.to_le_bytes());
+    let mut accounts = fixture.owner_vault_accounts();
+    accounts.extend([
+        AccountMeta::new(fixture.recovery_pda, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ]);
+    fixture.measure("set_guardians", accounts, data, &owner);
+
+    // set_withdraw_policy, queue_withdrawal and a beneficiary veto
+    let mut data = vec![44, 31, 170, 11, 16, 115, 56, 197];
+    data.extend_from_slice(&2_000_000u64.to_le_bytes());
+    data.extend_from_slice(&86_400i64.to_le_bytes());
+    data.extend_from_slice(&3_600i64.to_le_bytes());
+    let accounts = fixture.owner_vault_accounts();
+    fixture.measure("set_withdraw_policy", accounts, data, &owner);
+
+    let mut data = vec![153, 8, 176, 235, 189, 140, 146, 223];
+    data.extend_from_slice(&1_000_000u64.to_le_bytes());
+    let mut accounts = fixture.owner_vault_accounts();
+    accounts.extend([
+        AccountMeta::new_readonly(fixture.vault_token_pda, false),
+        AccountMeta::new(fixture.withdrawal_pda, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ]);
+    fixture.measure("queue_withdrawal", accounts, data, &owner);
+
+    // veto_withdrawal discriminator: [110, 183, 59, 83, 93, 65, 59, 212]
+    let accounts = vec![
+        AccountMeta::new_readonly(beneficiary.pubkey(), true),
+        AccountMeta::new(fixture.vault_pda, false),
+        AccountMeta::new(owner.pubkey(), false),
+        AccountMeta::new(fixture.withdrawal_pda, false),
+    ];
+    fixture.measure(
+        "veto_withdrawal",
+        accounts,
+        vec![110, 183, 59, 83, 93, 65, 59, 212],
+        &beneficiary,
+    );
+
+    // approve_recovery discriminator: [148, 96, 41, 38, 108, 189, 129, 214]
+    let new_owner = Keypair::new();
+    fixture
+        .svm
+        .airdrop(&new_owner.pubkey(), 1_000_000_000)
+        .unwrap();
+    let approve = |fixture: &mut ComputeUnitsFixture, guardian: &Keypair| {
+        let mut data = vec![148, 96, 41, 38, 108, 189, 129, 214];
+        data.extend_from_slice(&new_owner.pubkey().to_bytes());
+        let accounts = vec![
+            AccountMeta::new_readonly(guardian.pubkey(), true),
+            AccountMeta::new_readonly(fixture.vault_pda, false),
+            AccountMeta::new(fixture.recovery_pda, false),
+        ];
+        fixture.measure("approve_recovery", accounts, data, guardian);
+    };
+    approve(&mut fixture, &guardians[0]);
+
+    // cancel_recovery discriminator: [176, 23, 203, 37, 121, 251, 227, 83]
+    let mut accounts = fixture.owner_vault_accounts();
+    accounts[0] = AccountMeta::new_readonly(owner.pubkey(), true);
+    accounts.push(AccountMeta::new(fixture.recovery_pda, false));
+    fixture.measure(
+        "cancel_recovery",
+        accounts,
+        vec![176, 23, 203, 37, 121, 251, 227, 83],
+        &owner,
+    );
+
+    fixture.advance_clock(60);
+    approve(&mut fixture, &guardians[0]);
+    approve(&mut fixture, &guardians[1]);
+    fixture.advance_clock(3600);
+
+    // execute_recovery discriminator: [203, 133, 133, 228, 153, 121, 182, 237]
+    let accounts = vec![
+        AccountMeta::new(new_owner.pubkey(), true),
+        AccountMeta::new(fixture.vault_pda, false),
+        AccountMeta::new(fixture.recovery_pda, false),
+        AccountMeta::new(fixture.owner_registry_pda, false),
+        AccountMeta::new(fixture.registry_pda(&new_owner.pubkey()), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    fixture.measure(
+        "execute_recovery",
+        accounts,
+        vec![203, 133, 133, 228, 153, 121, 182, 237],
+        &new_owner,
+    );
+
+    // propose_owner discriminator: [90, 57, 141, 110, 196, 241, 172, 39]
+    let successor = Keypair::new();
+    fixture
+        .svm
+        .airdrop(&successor.pubkey(), 1_000_000_000)
+        .unwrap();
+    let mut data = vec![90, 57, 141, 110, 196, 241, 172, 39];
+    data.extend_from_slice(successor.pubkey().as_ref());
+    let accounts = vec![
+        AccountMeta::new_readonly(new_owner.pubkey(), true),
+        AccountMeta::new(fixture.vault_pda, false),
+    ];
+    fixture.measure("propose_owner", accounts, data, &new_owner);
+
+    // accept_owner discriminator: [176, 23, 41, 28, 23, 111, 8, 4]
+    let accounts = vec![
+        AccountMeta::new(successor.pubkey(), true),
+        AccountMeta::new(fixture.vault_pda, false),
+        AccountMeta::new(fixture.registry_pda(&new_owner.pubkey()), false),
+        AccountMeta::new(fixture.registry_pda(&successor.pubkey()), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    fixture.measure(
+        "accept_owner",
+        accounts,
+        vec![176, 23, 41, 28, 23, 111, 8, 4],
+        &successor,
+    );
+
+    fixture.check_budgets();
+    println!("✅ Every instruction within its compute unit budget");
+}
@@ -0,0 +1,338 @@
+use litesvm::LiteSVM;
+use litesvm_token::{get_spl_account, spl_token, CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+
+// VaultError codes
+const UNAUTHORIZED_ACCESS: u32 = 6000;
+
+/// Test fixture for third-party contributions to a vault
+struct ContributionTestFixture {
+    svm: LiteSVM,
+    program_id: Pubkey,
+    mint_authority: Keypair,
+    mint: Pubkey,
+    global_stats_pda: Pubkey,
+    mint_stats_pda: Pubkey,
+}
+
+/// PDAs for one (owner, beneficiary) vault
+struct VaultAccounts {
+    vault: Pubkey,
+    vault_token: Pubkey,
+    owner_registry: Pubkey,
+    beneficiary_index: Pubkey,
+}
+
+impl ContributionTestFixture {
+    fn new() -> Self {
+        let mut svm = LiteSVM::new();
+
+        // Load the compiled program
+        let program_keypair =
+            read_keypair_file("target/deploy/cyber_vault_rs-keypair.json").unwrap();
+        let program_id = program_keypair.pubkey();
+        let program_bytes = include_bytes!("../target/deploy/cyber_vault_rs.so");
+        svm.add_program(program_id, program_bytes);
+
+        let mint_authority = Keypair::new();
+        svm.airdrop(&mint_authority.pubkey(), 10_000_000_000)
+            .unwrap();
+
+        let mint = CreateMint::new(&mut svm, &mint_authority)
+            .authority(&mint_authority.pubkey())
+            .decimals(6)
+            .send()
+            .unwrap();
+
+        let (global_stats_pda, _) = Pubkey::find_program_address(&[b"global_stats"], &program_id);
+        let (mint_stats_pda, _) =
+            Pubkey::find_program_address(&[b"mint_stats", mint.as_ref()], &program_id);
+
+        Self {
+            svm,
+            program_id,
+            mint_authority,
+            mint,
+            global_stats_pda,
+            mint_stats_pda,
+        }
+    }
+
+    fn funded_user(&mut self) -> (Keypair, Pubkey) {
+        let user = Keypair::new();
+        self.svm.airdrop(&user.pubkey(), 10_000_000_000).unwrap();
+        let ata = CreateAssociatedTokenAccount::new(&mut self.svm, &user, &self.mint)
+            .owner(&user.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(
+            &mut self.svm,
+            &self.mint_authority,
+            &self.mint,
+            &ata,
+            5_000_000,
+        )
+        .send()
+        .unwrap();
+        (user, ata)
+    }
+
+    fn accounts(&self, owner: &Pubkey, beneficiary: &Pubkey) -> VaultAccounts {
+        let (vault, _) = Pubkey::find_program_address(
+            &[
+                b"vault",
+                owner.as_ref(),
+                beneficiary.as_ref(),
+                self.mint.as_ref(),
+            ],
+            &self.program_id,
+        );
+        let (vault_token, _) =
+            Pubkey::find_program_address(&[b"vault_token", vault.as_ref()], &self.program_id);
+        let (owner_registry, _) =
+            Pubkey::find_program_address(&[b"owner_registry", owner.as_ref()], &self.program_id);
+        let (beneficiary_index, _) = Pubkey::find_program_address(
+            &[b"beneficiary_index", beneficiary.as_ref()],
+            &self.program_id,
+        );
+        VaultAccounts {
+            vault,
+            vault_token,
+            owner_registry,
+            beneficiary_index,
+        }
+    }
+
+    fn send(&mut self, instruction: Instruction, signer: &Keypair) -> Result<(), String> {
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        self.svm
+            .send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn create_vault(
+        &mut self,
+        owner: &Keypair,
+        owner_ata: &Pubkey,
+        beneficiary: &Pubkey,
+        amount: u64,
+    ) -> VaultAccounts {
+        let accounts = self.accounts(&owner.pubkey(), beneficiary);
+        let inactivity_period: i64 = 3600;
+
+        // create_vault discriminator: [29, 237, 247, 208, 193, 82, 54, 135]
+        let mut instruction_data = vec![29, 237, 247, 208, 193, 82, 54, 135];
+        instruction_data.extend_from_slice(&beneficiary.to_bytes());
+        instruction_data.extend_from_slice(&inactivity_period.to_le_bytes());
+        instruction_data.extend_from_slice(&amount.to_le_bytes());
+        instruction_data.push(0); // acknowledge_freeze_authority
+        let (mint_policy_pda, _) =
+            Pubkey::find_program_address(&[b"mint_policy"], &self.program_id);
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(owner.pubkey(), true),
+                AccountMeta::new(accounts.vault, false),
+                AccountMeta::new(accounts.vault_token, false),
+                AccountMeta::new(*owner_ata, false),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
+                AccountMeta::new(accounts.owner_registry, false),
+                AccountMeta::new(accounts.beneficiary_index, false),
+                AccountMeta::new(self.global_stats_pda, false),
+                AccountMeta::new(self.mint_stats_pda, false),
+                AccountMeta::new_readonly(mint_policy_pda, false),
+            ],
+            data: instruction_data,
+        };
+
+        self.send(instruction, owner).unwrap();
+        accounts
+    }
+
+    fn contribute(
+        &mut self,
+        accounts: &VaultAccounts,
+        contributor: &Keypair,
+        contributor_ata: &Pubkey,
+        amount: u64,
+    ) -> Result<(), String> {
+        let contribution = self.contribution_pda(accounts, &contributor.pubkey());
+
+        // contribute discriminator: [82, 33, 68, 131, 32, 0, 205, 95]
+        let mut instruction_data = vec![82, 33, 68, 131, 32, 0, 205, 95];
+        instruction_data.extend_from_slice(&amount.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(contributor.pubkey(), true),
+                AccountMeta::new_readonly(accounts.vault, false),
+                AccountMeta::new(accounts.vault_token, false),
+                AccountMeta::new(*contributor_ata, false),
+                AccountMeta::new(contribution, false),
+                AccountMeta::new(self.mint_stats_pda, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: instruction_data,
+        };
+
+        self.send(instruction, contributor)
+    }
+
+    fn emergency_withdraw(
+        &mut self,
+        accounts: &VaultAccounts,
+        signer: &Keypair,
+        destination_ata: &Pubkey,
+        amount: u64,
+    ) -> Result<(), String> {
+        // emergency_withdraw discriminator: [239, 45, 203, 64, 150, 73, 218, 92]
+        let mut instruction_data = vec![239, 45, 203, 64, 150, 73, 218, 92];
+        instruction_data.extend_from_slice(&amount.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(signer.pubkey(), true),
+                AccountMeta::new(accounts.vault, false),
+                AccountMeta::new(*destination_ata, false),
+                AccountMeta::new(accounts.vault_token, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new(self.mint_stats_pda, false),
+            ],
+            data: instruction_data,
+        };
+
+        self.send(instruction, signer)
+    }
+
+    fn contribution_pda(&self, accounts: &VaultAccounts, contributor: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                b"contribution",
+                accounts.vault.as_ref(),
+                contributor.as_ref(),
+            ],
+            &self.program_id,
+        )
+        .0
+    }
+
+    fn read_u64(data: &[u8], offset: usize) -> u64 {
+        u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+    }
+
+    /// Contribution layout: disc(8) + vault(32) + contributor(32) + total_amount(8)
+    fn contributed(&self, accounts: &VaultAccounts, contributor: &Pubkey) -> u64 {
+        let pda = self.contribution_pda(accounts, contributor);
+        let data = self.svm.get_account(&pda).unwrap().data;
+        assert_eq!(&data[8..40], accounts.vault.as_ref());
+        assert_eq!(&data[40..72], contributor.as_ref());
+        Self::read_u64(&data, 72)
+    }
+
+    /// Vault layout: disc(8) + 6 pubkeys(192) + inactivity_period(8) + last_heartbeat(8)
+    fn last_heartbeat(&self, accounts: &VaultAccounts) -> i64 {
+        let data = self.svm.get_account(&accounts.vault).unwrap().data;
+        i64::from_le_bytes(data[208..216].try_into().unwrap())
+    }
+
+    fn token_balance(&self, account: &Pubkey) -> u64 {
+        get_spl_account::<spl_token::state::Account>(&self.svm, account)
+            .unwrap()
+            .amount
+    }
+}
+
+#[test]
+fn test_contributions_are_recorded_per_contributor() {
+    println!("🧪 Testing contributions from family members");
+
+    let mut fixture = ContributionTestFixture::new();
+    let (owner, owner_ata) = fixture.funded_user();
+    let (grandma, grandma_ata) = fixture.funded_user();
+    let (grandpa, grandpa_ata) = fixture.funded_user();
+    let accounts = fixture.create_vault(&owner, &owner_ata, &Pubkey::new_unique(), 1_000_000);
+    let last_heartbeat = fixture.last_heartbeat(&accounts);
+
+    fixture
+        .contribute(&accounts, &grandma, &grandma_ata, 200_000)
+        .unwrap();
+    fixture
+        .contribute(&accounts, &grandma, &grandma_ata, 300_000)
+        .unwrap();
+    fixture
+        .contribute(&accounts, &grandpa, &grandpa_ata, 50_000)
+        .unwrap();
+
+    assert_eq!(fixture.token_balance(&accounts.vault_token), 1_550_000);
+    assert_eq!(fixture.contributed(&accounts, &grandma.pubkey()), 500_000);
+    assert_eq!(fixture.contributed(&accounts, &grandpa.pubkey()), 50_000);
+    println!("✅ Each contributor's running total recorded");
+
+    let mint_stats = fixture
+        .svm
+        .get_account(&fixture.mint_stats_pda)
+        .unwrap()
+        .data;
+    assert_eq!(
+        ContributionTestFixture::read_u64(&mint_stats, 40),
+        1_550_000
+    );
+    assert_eq!(fixture.last_heartbeat(&accounts), last_heartbeat);
+    println!("✅ Contributions counted as locked without resetting the timer");
+
+    assert!(fixture
+        .contribute(&accounts, &grandpa, &grandpa_ata, 0)
+        .is_err());
+    println!("✅ Zero contribution rejected");
+}
+
+#[test]
+fn test_contributor_gains_no_withdrawal_rights() {
+    println!("🧪 Testing that contributors cannot withdraw");
+
+    let mut fixture = ContributionTestFixture::new();
+    let (owner, owner_ata) = fixture.funded_user();
+    let (contributor, contributor_ata) = fixture.funded_user();
+    let accounts = fixture.create_vault(&owner, &owner_ata, &Pubkey::new_unique(), 1_000_000);
+
+    fixture
+        .contribute(&accounts, &contributor, &contributor_ata, 500_000)
+        .unwrap();
+
+    let err = fixture
+        .emergency_withdraw(&accounts, &contributor, &contributor_ata, 500_000)
+        .unwrap_err();
+    assert!(
+        err.contains(&format!("Custom({})", UNAUTHORIZED_ACCESS)),
+        "expected UnauthorizedAccess, got {}",
+        err
+    );
+    assert_eq!(fixture.token_balance(&contributor_ata), 4_500_000);
+    println!("✅ Contributor's emergency withdrawal rejected");
+
+    fixture
+        .emergency_withdraw(&accounts, &owner, &owner_ata, 1_500_000)
+        .unwrap();
+    assert_eq!(fixture.token_balance(&owner_ata), 5_500_000);
+    println!("✅ Owner can still withdraw everything, gifts included");
+}
@@ -0,0 +1,344 @@
+use litesvm::LiteSVM;
+use litesvm_token::{spl_token, CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_program, sysvar,
+    transaction::Transaction,
+};
+
+// VaultError codes
+const SWITCH_ALREADY_LINKED: u32 = 6023;
+
+/// Test fixture for heartbeats relayed by another program through CPI
+struct CpiHeartbeatTestFixture {
+    svm: LiteSVM,
+    program_id: Pubkey,
+    consumer_id: Pubkey,
+    owner: Keypair,
+    vault_pda: Pubkey,
+    allowlist_pda: Pubkey,
+}
+
+impl CpiHeartbeatTestFixture {
+    fn new() -> Self {
+        let mut svm = LiteSVM::new();
+
+        // Load the vault program and the example consumer
+        let program_keypair =
+            read_keypair_file("target/deploy/cyber_vault_rs-keypair.json").unwrap();
+        let program_id = program_keypair.pubkey();
+        let program_bytes = include_bytes!("../target/deploy/cyber_vault_rs.so");
+        svm.add_program(program_id, program_bytes);
+
+        let consumer_keypair =
+            read_keypair_file("target/deploy/heartbeat_consumer-keypair.json").unwrap();
+        let consumer_id = consumer_keypair.pubkey();
+        let consumer_bytes = include_bytes!("../target/deploy/heartbeat_consumer.so");
+        svm.add_program(consumer_id, consumer_bytes);
+
+        let owner = Keypair::new();
+        let beneficiary = Pubkey::new_unique();
+        svm.airdrop(&owner.pubkey(), 10_000_000_000).unwrap();
+
+        let mint = CreateMint::new(&mut svm, &owner)
+            .authority(&owner.pubkey())
+            .decimals(6)
+            .send()
+            .unwrap();
+        let owner_ata = CreateAssociatedTokenAccount::new(&mut svm, &owner, &mint)
+            .owner(&owner.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &owner, &mint, &owner_ata, 1_000_000)
+            .send()
+            .unwrap();
+
+        let (vault_pda, _) = Pubkey::find_program_address(
+            &[
+                b"vault",
+                owner.pubkey().as_ref(),
+                beneficiary.as_ref(),
+                mint.as_ref(),
+            ],
+            &program_id,
+        );
+        let (vault_token_pda, _) =
+            Pubkey::find_program_address(&[b"vault_token", vault_pda.as_ref()], &program_id);
+        let (owner_registry_pda, _) = Pubkey::find_program_address(
+            &[b"owner_registry", owner.pubkey().as_ref()],
+            &program_id,
+        );
+        let (beneficiary_index_pda, _) = Pubkey::find_program_address(
+            &[b"beneficiary_index", beneficiary.as_ref()],
+            &program_id,
+        );
+        let (global_stats_pda, _) = Pubkey::find_program_address(&[b"global_stats"], &program_id);
+        let (mint_stats_pda, _) =
+            Pubkey::find_program_address(&[b"mint_stats", mint.as_ref()], &program_id);
+        let (allowlist_pda, _) = Pubkey::find_program_address(
+            &[b"caller_allowlist", owner.pubkey().as_ref()],
+            &program_id,
+        );
+
+        let mut fixture = Self {
+            svm,
+            program_id,
+            consumer_id,
+            owner,
+            vault_pda,
+            allowlist_pda,
+        };
+
+        // create_vault discriminator: [29, 237, 247, 208, 193, 82, 54, 135]
+        let mut instruction_data = vec![29, 237, 247, 208, 193, 82, 54, 135];
+        instruction_data.extend_from_slice(&beneficiary.to_bytes());
+        instruction_data.extend_from_slice(&3600i64.to_le_bytes());
+        instruction_data.extend_from_slice(&1_000_000u64.to_le_bytes());
+        instruction_data.push(0); // acknowledge_freeze_authority
+        let (mint_policy_pda, _) = Pubkey::find_program_address(&[b"mint_policy"], &program_id);
+        let instruction = Instruction {
+            program_id,
+            accounts: vec![
+                AccountMeta::new(fixture.owner.pubkey(), true),
+                AccountMeta::new(vault_pda, false),
+                AccountMeta::new(vault_token_pda, false),
+                AccountMeta::new(owner_ata, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(sysvar::rent::id(), false),
+                AccountMeta::new(owner_registry_pda, false),
+                AccountMeta::new(beneficiary_index_pda, false),
+                AccountMeta::new(global_stats_pda, false),
+                AccountMeta::new(mint_stats_pda, false),
+                AccountMeta::new_readonly(mint_policy_pda, false),
+            ],
+            data: instruction_data,
+        };
+        let owner = fixture.owner.insecure_clone();
+        fixture.send(instruction, &owner).unwrap();
+
+        fixture
+    }
+
+    fn send(&mut self, instruction: Instruction, signer: &Keypair) -> Result<(), String> {
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        self.svm
+            .send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn set_allowed_callers(&mut self, programs: &[Pubkey]) -> Result<(), String> {
+        // set_allowed_callers discriminator: [206, 75, 219, 6, 147, 196, 67, 152]
+        let mut instruction_data = vec![206, 75, 219, 6, 147, 196, 67, 152];
+        instruction_data.extend_from_slice(&(programs.len() as u32).to_le_bytes());
+        for program in programs {
+            instruction_data.extend_from_slice(&program.to_bytes());
+        }
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.owner.pubkey(), true),
+                AccountMeta::new(self.allowlist_pda, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: instruction_data,
+        };
+
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner)
+    }
+
+    fn cpi_heartbeat_accounts(&self, signer: &Pubkey) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.vault_pda, false),
+            AccountMeta::new_readonly(*signer, true),
+            AccountMeta::new_readonly(self.allowlist_pda, false),
+            AccountMeta::new_readonly(sysvar::instructions::id(), false),
+        ]
+    }
+
+    /// Calls the example consumer, which relays the heartbeat through CPI
+    fn record_activity(&mut self, signer: &Keypair) -> Result<(), String> {
+        let mut accounts = self.cpi_heartbeat_accounts(&signer.pubkey());
+        accounts.push(AccountMeta::new_readonly(self.program_id, false));
+
+        // record_activity discriminator: [199, 86, 104, 65, 200, 211, 71, 50]
+        let instruction = Instruction {
+            program_id: self.consumer_id,
+            accounts,
+            data: vec![199, 86, 104, 65, 200, 211, 71, 50],
+        };
+
+        self.send(instruction, signer)
+    }
+
+    /// Creates the owner's master switch and links the vault to it
+    fn link_switch(&mut self) {
+        let owner = self.owner.insecure_clone();
+        let vault = &self.vault_pda;
+        let (switch, _) =
+            Pubkey::find_program_address(&[b"switch", owner.pubkey().as_ref()], &self.program_id);
+
+        // create_switch discriminator: [120, 11, 193, 114, 80, 234, 99, 128]
+        let mut instruction_data = vec![120, 11, 193, 114, 80, 234, 99, 128];
+        instruction_data.extend_from_slice(&3600i64.to_le_bytes());
+        let create = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(owner.pubkey(), true),
+                AccountMeta::new(switch, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: instruction_data,
+        };
+
+        // link_switch discriminator: [136, 247, 62, 224, 223, 183, 48, 117]
+        let link = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(owner.pubkey(), true),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(switch, false),
+            ],
+            data: vec![136, 247, 62, 224, 223, 183, 48, 117],
+        };
+        self.send(create, &owner).unwrap();
+        self.send(link, &owner).unwrap();
+    }
+
+    fn advance_clock(&mut self, seconds: i64) -> i64 {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp += seconds;
+        self.svm.set_sysvar(&clock);
+        clock.unix_timestamp
+    }
+
+    fn last_heartbeat(&self) -> i64 {
+        let data = self.svm.get_account(&self.vault_pda).unwrap().data;
+        i64::from_le_bytes(data[208..216].try_into().unwrap())
+    }
+}
+
+#[test]
+fn test_cpi_heartbeat_from_allowed_program() {
+    println!("🧪 Testing a heartbeat relayed by an allowlisted program");
+
+    let mut fixture = CpiHeartbeatTestFixture::new();
+    let owner = fixture.owner.insecure_clone();
+    let consumer = fixture.consumer_id;
+    fixture.set_allowed_callers(&[consumer]).unwrap();
+
+    let now = fixture.advance_clock(1800);
+    fixture.record_activity(&owner).unwrap();
+
+    assert_eq!(fixture.last_heartbeat(), now);
+    println!("✅ Activity in another program reset the vault timer");
+}
+
+#[test]
+fn test_cpi_heartbeat_rejects_unlisted_program() {
+    println!("🧪 Testing a heartbeat relayed by a program not on the allowlist");
+
+    let mut fixture = CpiHeartbeatTestFixture::new();
+    let owner = fixture.owner.insecure_clone();
+    let before = fixture.last_heartbeat();
+
+    fixture
+        .set_allowed_callers(&[Pubkey::new_unique()])
+        .unwrap();
+    fixture.advance_clock(1800);
+    assert!(
+        fixture.record_activity(&owner).is_err(),
+        "Heartbeat from an unlisted program should fail"
+    );
+
+    // An empty list turns the feature off again
+    let consumer = fixture.consumer_id;
+    fixture.set_allowed_callers(&[consumer]).unwrap();
+    fixture.set_allowed_callers(&[]).unwrap();
+    assert!(fixture.record_activity(&owner).is_err());
+
+    assert_eq!(fixture.last_heartbeat(), before);
+    println!("✅ Unlisted caller rejected");
+}
+
+#[test]
+fn test_cpi_heartbeat_requires_owner_and_cpi() {
+    println!("🧪 Testing CPI heartbeat signer and call-path checks");
+
+    let mut fixture = CpiHeartbeatTestFixture::new();
+    let owner = fixture.owner.insecure_clone();
+    let consumer = fixture.consumer_id;
+    fixture.set_allowed_callers(&[consumer]).unwrap();
+
+    let stranger = Keypair::new();
+    fixture
+        .svm
+        .airdrop(&stranger.pubkey(), 1_000_000_000)
+        .unwrap();
+    assert!(
+        fixture.record_activity(&stranger).is_err(),
+        "Relayed heartbeat signed by a non-owner should fail"
+    );
+
+    // cpi_heartbeat discriminator: [244, 65, 247, 151, 100, 77, 23, 236]
+    let instruction = Instruction {
+        program_id: fixture.program_id,
+        accounts: fixture.cpi_heartbeat_accounts(&owner.pubkey()),
+        data: vec![244, 65, 247, 151, 100, 77, 23, 236],
+    };
+    assert!(
+        fixture.send(instruction, &owner).is_err(),
+        "Calling cpi_heartbeat directly should fail"
+    );
+    println!("✅ Non-owner and direct calls rejected");
+}
+
+#[test]
+fn test_set_allowed_callers_validation() {
+    println!("🧪 Testing caller allowlist validation");
+
+    let mut fixture = CpiHeartbeatTestFixture::new();
+    let program_id = fixture.program_id;
+    let consumer = fixture.consumer_id;
+
+    assert!(fixture.set_allowed_callers(&[consumer, consumer]).is_err());
+    assert!(fixture.set_allowed_callers(&[program_id]).is_err());
+    let too_many: Vec<Pubkey> = (0..9).map(|_| Pubkey::new_unique()).collect();
+    assert!(fixture.set_allowed_callers(&too_many).is_err());
+
+    let max: Vec<Pubkey> = (0..8).map(|_| Pubkey::new_unique()).collect();
+    fixture.set_allowed_callers(&max).unwrap();
+    println!("✅ Duplicate, self-referencing and oversized lists rejected");
+}
+
+#[test]
+fn test_cpi_heartbeat_rejected_on_linked_vault() {
+    println!("🧪 Testing a relayed heartbeat on a vault linked to a master switch");
+
+    let mut fixture = CpiHeartbeatTestFixture::new();
+    let owner = fixture.owner.insecure_clone();
+    let consumer = fixture.consumer_id;
+    fixture.set_allowed_callers(&[consumer]).unwrap();
+    fixture.link_switch();
+
+    let err = fixture.record_activity(&owner).unwrap_err();
+    assert!(
+        err.contains(&format!("Custom({})", SWITCH_ALREADY_LINKED)),
+        "expected SwitchAlreadyLinked, got {}",
+        err
+    );
+    println!("✅ Linked vault takes its heartbeats from the switch only");
+}
@@ -123,6 +123,17 @@ fn test_cyber_vault_full_flow() {
     let (vault_token_pda, _vault_token_bump) =
         Pubkey::find_program_address(&[b"vault_token", vault_pda.as_ref()], &program_id);
 
+    // Find registry PDAs that index the vault by owner and by beneficiary
+    let (owner_registry_pda, _) =
+        Pubkey::find_program_address(&[b"owner_registry", owner.pubkey().as_ref()], &program_id);
+    let (beneficiary_index_pda, _) = Pubkey::find_program_address(
+        &[b"beneficiary_index", beneficiary.pubkey().as_ref()],
+        &program_id,
+    );
+    let (global_stats_pda, _) = Pubkey::find_program_address(&[b"global_stats"], &program_id);
+    let (mint_stats_pda, _) =
+        Pubkey::find_program_address(&[b"mint_stats", mint.as_ref()], &program_id);
+
     println!("📋 Test 1: Creating Vault");
     println!("   Using Vault PDA: {}", vault_pda);
     println!("   Vault Token PDA: {}", vault_token_pda);
@@ -136,6 +147,8 @@ fn test_cyber_vault_full_flow() {
     create_vault_instruction_data.extend_from_slice(&beneficiary.pubkey().to_bytes());
     create_vault_instruction_data.extend_from_slice(&inactivity_period.to_le_bytes());
     create_vault_instruction_data.extend_from_slice(&deposit_amount.to_le_bytes());
+    create_vault_instruction_data.push(0); // acknowledge_freeze_authority
+    let (mint_policy_pda, _) = Pubkey::find_program_address(&[b"mint_policy"], &program_id);
 
     // Build the create_vault instruction following the exact account order
     let create_vault_instruction = Instruction {
@@ -149,6 +162,11 @@ fn test_cyber_vault_full_flow() {
             AccountMeta::new_readonly(spl_token::id(), false), // token_program
             AccountMeta::new_readonly(system_program::id(), false), // system_program
             AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false), // rent
+            AccountMeta::new(owner_registry_pda, false),       // owner_registry
+            AccountMeta::new(beneficiary_index_pda, false),    // beneficiary_index
+            AccountMeta::new(global_stats_pda, false),         // global_stats
+            AccountMeta::new(mint_stats_pda, false),           // mint_stats
+            AccountMeta::new_readonly(mint_policy_pda, false), // mint_policy
         ],
         data: create_vault_instruction_data,
     };
@@ -225,9 +243,16 @@ fn test_cyber_vault_full_flow() {
         accounts: vec![
             AccountMeta::new(vault_pda, false),                // vault
             AccountMeta::new(vault_token_pda, false),          // vault_token_account
+            AccountMeta::new_readonly(mint, false),            // token_mint
             AccountMeta::new(beneficiary_ata, false),          // beneficiary_token_account
             AccountMeta::new(beneficiary.pubkey(), true),      // beneficiary
             AccountMeta::new_readonly(spl_token::id(), false), // token_program
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false), // associated_token_program
+            AccountMeta::new_readonly(system_program::id(), false),               // system_program
+            AccountMeta::new(owner_registry_pda, false),                          // owner_registry
+            AccountMeta::new(beneficiary_index_pda, false), // beneficiary_index
+            AccountMeta::new(global_stats_pda, false),      // global_stats
+            AccountMeta::new(mint_stats_pda, false),        // mint_stats
         ],
         data: claim_instruction_data.clone(),
     };
@@ -238,9 +263,16 @@ fn test_cyber_vault_full_flow() {
         accounts: vec![
             AccountMeta::new(vault_pda, false),                // vault
             AccountMeta::new(vault_token_pda, false),          // vault_token_account
+            AccountMeta::new_readonly(mint, false),            // token_mint
             AccountMeta::new(beneficiary_ata, false),          // beneficiary_token_account
             AccountMeta::new(beneficiary.pubkey(), true),      // beneficiary
             AccountMeta::new_readonly(spl_token::id(), false), // token_program
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false), // associated_token_program
+            AccountMeta::new_readonly(system_program::id(), false),               // system_program
+            AccountMeta::new(owner_registry_pda, false),                          // owner_registry
+            AccountMeta::new(beneficiary_index_pda, false), // beneficiary_index
+            AccountMeta::new(global_stats_pda, false),      // global_stats
+            AccountMeta::new(mint_stats_pda, false),        // mint_stats
         ],
         data: claim_instruction_data,
     };
@@ -347,11 +379,19 @@ fn test_cyber_vault_full_flow() {
     let (vault_token_pda_emergency, _vault_token_bump_emergency) =
         Pubkey::find_program_address(&[b"vault_token", vault_pda_emergency.as_ref()], &program_id);
 
+    let (owner2_registry_pda, _) =
+        Pubkey::find_program_address(&[b"owner_registry", owner2.pubkey().as_ref()], &program_id);
+    let (beneficiary2_index_pda, _) = Pubkey::find_program_address(
+        &[b"beneficiary_index", beneficiary2.pubkey().as_ref()],
+        &program_id,
+    );
+
     // Build create_vault instruction for emergency test
     let mut create_vault_emergency_data = create_vault_discriminator.to_vec();
     create_vault_emergency_data.extend_from_slice(&beneficiary2.pubkey().to_bytes());
     create_vault_emergency_data.extend_from_slice(&inactivity_period_emergency.to_le_bytes());
     create_vault_emergency_data.extend_from_slice(&deposit_amount_emergency.to_le_bytes());
+    create_vault_emergency_data.push(0); // acknowledge_freeze_authority
 
     let create_vault_emergency_instruction = Instruction {
         program_id,
@@ -364,6 +404,11 @@ fn test_cyber_vault_full_flow() {
             AccountMeta::new_readonly(spl_token::id(), false),  // token_program
             AccountMeta::new_readonly(system_program::id(), false), // system_program
             AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false), // rent
+            AccountMeta::new(owner2_registry_pda, false),       // owner_registry
+            AccountMeta::new(beneficiary2_index_pda, false),    // beneficiary_index
+            AccountMeta::new(global_stats_pda, false),          // global_stats
+            AccountMeta::new(mint_stats_pda, false),            // mint_stats
+            AccountMeta::new_readonly(mint_policy_pda, false),  // mint_policy
         ],
         data: create_vault_emergency_data,
     };
@@ -401,6 +446,7 @@ fn test_cyber_vault_full_flow() {
             AccountMeta::new(owner2_ata, false),                // owner_token_account
             AccountMeta::new(vault_token_pda_emergency, false), // vault_token_account
             AccountMeta::new_readonly(spl_token::id(), false),  // token_program
+            AccountMeta::new(mint_stats_pda, false),            // mint_stats
         ],
         data: emergency_withdraw_data,
     };
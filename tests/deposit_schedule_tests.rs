@@ -0,0 +1,390 @@
+use litesvm::LiteSVM;
+use litesvm_token::{
+    get_spl_account, spl_token, Approve, CreateAssociatedTokenAccount, CreateMint, MintTo,
+};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+
+// VaultError codes
+const INVALID_DEPOSIT_SCHEDULE: u32 = 6045;
+const NO_DEPOSIT_SCHEDULE: u32 = 6046;
+const DEPOSIT_NOT_DUE: u32 = 6047;
+const DEPOSIT_MISSED: u32 = 6048;
+
+const INSTALLMENT: u64 = 500_000;
+const INTERVAL: i64 = 3600;
+
+/// Test fixture for scheduled deposits pulled through a token delegate
+struct DepositScheduleTestFixture {
+    svm: LiteSVM,
+    program_id: Pubkey,
+    mint_authority: Keypair,
+    mint: Pubkey,
+    global_stats_pda: Pubkey,
+    mint_stats_pda: Pubkey,
+}
+
+/// PDAs for one (owner, beneficiary) vault
+struct VaultAccounts {
+    vault: Pubkey,
+    vault_token: Pubkey,
+    owner_registry: Pubkey,
+    beneficiary_index: Pubkey,
+}
+
+impl DepositScheduleTestFixture {
+    fn new() -> Self {
+        let mut svm = LiteSVM::new();
+
+        // Load the compiled program
+        let program_keypair =
+            read_keypair_file("target/deploy/cyber_vault_rs-keypair.json").unwrap();
+        let program_id = program_keypair.pubkey();
+        let program_bytes = include_bytes!("../target/deploy/cyber_vault_rs.so");
+        svm.add_program(program_id, program_bytes);
+
+        let mint_authority = Keypair::new();
+        svm.airdrop(&mint_authority.pubkey(), 10_000_000_000)
+            .unwrap();
+
+        let mint = CreateMint::new(&mut svm, &mint_authority)
+            .authority(&mint_authority.pubkey())
+            .decimals(6)
+            .send()
+            .unwrap();
+
+        let (global_stats_pda, _) = Pubkey::find_program_address(&[b"global_stats"], &program_id);
+        let (mint_stats_pda, _) =
+            Pubkey::find_program_address(&[b"mint_stats", mint.as_ref()], &program_id);
+
+        Self {
+            svm,
+            program_id,
+            mint_authority,
+            mint,
+            global_stats_pda,
+            mint_stats_pda,
+        }
+    }
+
+    fn funded_user(&mut self) -> (Keypair, Pubkey) {
+        let user = Keypair::new();
+        self.svm.airdrop(&user.pubkey(), 10_000_000_000).unwrap();
+        let ata = CreateAssociatedTokenAccount::new(&mut self.svm, &user, &self.mint)
+            .owner(&user.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(
+            &mut self.svm,
+            &self.mint_authority,
+            &self.mint,
+            &ata,
+            5_000_000,
+        )
+        .send()
+        .unwrap();
+        (user, ata)
+    }
+
+    fn accounts(&self, owner: &Pubkey, beneficiary: &Pubkey) -> VaultAccounts {
+        let (vault, _) = Pubkey::find_program_address(
+            &[
+                b"vault",
+                owner.as_ref(),
+                beneficiary.as_ref(),
+                self.mint.as_ref(),
+            ],
+            &self.program_id,
+        );
+        let (vault_token, _) =
+            Pubkey::find_program_address(&[b"vault_token", vault.as_ref()], &self.program_id);
+        let (owner_registry, _) =
+            Pubkey::find_program_address(&[b"owner_registry", owner.as_ref()], &self.program_id);
+        let (beneficiary_index, _) = Pubkey::find_program_address(
+            &[b"beneficiary_index", beneficiary.as_ref()],
+            &self.program_id,
+        );
+        VaultAccounts {
+            vault,
+            vault_token,
+            owner_registry,
+            beneficiary_index,
+        }
+    }
+
+    fn send(&mut self, instruction: Instruction, signer: &Keypair) -> Result<(), String> {
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        self.svm
+            .send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn create_vault(
+        &mut self,
+        owner: &Keypair,
+        owner_ata: &Pubkey,
+        beneficiary: &Pubkey,
+        amount: u64,
+    ) -> VaultAccounts {
+        let accounts = self.accounts(&owner.pubkey(), beneficiary);
+        let inactivity_period: i64 = 3600;
+
+        // create_vault discriminator: [29, 237, 247, 208, 193, 82, 54, 135]
+        let mut instruction_data = vec![29, 237, 247, 208, 193, 82, 54, 135];
+        instruction_data.extend_from_slice(&beneficiary.to_bytes());
+        instruction_data.extend_from_slice(&inactivity_period.to_le_bytes());
+        instruction_data.extend_from_slice(&amount.to_le_bytes());
+        instruction_data.push(0); // acknowledge_freeze_authority
+        let (mint_policy_pda, _) =
+            Pubkey::find_program_address(&[b"mint_policy"], &self.program_id);
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(owner.pubkey(), true),
+                AccountMeta::new(accounts.vault, false),
+                AccountMeta::new(accounts.vault_token, false),
+                AccountMeta::new(*owner_ata, false),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
+                AccountMeta::new(accounts.owner_registry, false),
+                AccountMeta::new(accounts.beneficiary_index, false),
+                AccountMeta::new(self.global_stats_pda, false),
+                AccountMeta::new(self.mint_stats_pda, false),
+                AccountMeta::new_readonly(mint_policy_pda, false),
+            ],
+            data: instruction_data,
+        };
+
+        self.send(instruction, owner).unwrap();
+        accounts
+    }
+
+    fn approve(
+        &mut self,
+        accounts: &VaultAccounts,
+        owner: &Keypair,
+        owner_ata: &Pubkey,
+        amount: u64,
+    ) {
+        Approve::new(&mut self.svm, owner, &accounts.vault, owner_ata, amount)
+            .send()
+            .unwrap();
+    }
+
+    fn set_deposit_schedule(
+        &mut self,
+        accounts: &VaultAccounts,
+        owner: &Keypair,
+        source: Option<&Pubkey>,
+        amount: u64,
+        interval: i64,
+        end: i64,
+    ) -> Result<(), String> {
+        // set_deposit_schedule discriminator: [164, 217, 16, 89, 86, 63, 73, 223]
+        let mut instruction_data = vec![164, 217, 16, 89, 86, 63, 73, 223];
+        instruction_data.extend_from_slice(&amount.to_le_bytes());
+        instruction_data.extend_from_slice(&interval.to_le_bytes());
+        instruction_data.extend_from_slice(&end.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(owner.pubkey(), true),
+                AccountMeta::new(accounts.vault, false),
+                AccountMeta::new_readonly(*source.unwrap_or(&self.program_id), false),
+            ],
+            data: instruction_data,
+        };
+
+        self.send(instruction, owner)
+    }
+
+    fn pull_contribution(
+        &mut self,
+        accounts: &VaultAccounts,
+        source: &Pubkey,
+        caller: &Keypair,
+    ) -> Result<(), String> {
+        // pull_contribution discriminator: [3, 228, 34, 207, 180, 0, 247, 19]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(caller.pubkey(), true),
+                AccountMeta::new(accounts.vault, false),
+                AccountMeta::new(accounts.vault_token, false),
+                AccountMeta::new(*source, false),
+                AccountMeta::new(self.mint_stats_pda, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+            ],
+            data: vec![3, 228, 34, 207, 180, 0, 247, 19],
+        };
+
+        self.send(instruction, caller)
+    }
+
+    fn now(&self) -> i64 {
+        self.svm.get_sysvar::<Clock>().unix_timestamp
+    }
+
+    fn advance_clock(&mut self, seconds: i64) {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp += seconds;
+        self.svm.set_sysvar(&clock);
+    }
+
+    fn token_balance(&self, account: &Pubkey) -> u64 {
+        get_spl_account::<spl_token::state::Account>(&self.svm, account)
+            .unwrap()
+            .amount
+    }
+}
+
+fn assert_vault_error(result: Result<(), String>, code: u32) {
+    let err = result.expect_err("instruction should have been rejected");
+    assert!(
+        err.contains(&format!("Custom({})", code)),
+        "expected error {}, got {}",
+        code,
+        err
+    );
+}
+
+#[test]
+fn test_installments_are_pulled_once_per_interval() {
+    println!("🧪 Testing scheduled deposits");
+
+    let mut fixture = DepositScheduleTestFixture::new();
+    let (owner, owner_ata) = fixture.funded_user();
+    let cranker = Keypair::new();
+    fixture
+        .svm
+        .airdrop(&cranker.pubkey(), 1_000_000_000)
+        .unwrap();
+    let accounts = fixture.create_vault(&owner, &owner_ata, &Pubkey::new_unique(), 1_000_000);
+
+    fixture.approve(&accounts, &owner, &owner_ata, 3 * INSTALLMENT);
+    let end = fixture.now() + 10 * INTERVAL;
+    fixture
+        .set_deposit_schedule(
+            &accounts,
+            &owner,
+            Some(&owner_ata),
+            INSTALLMENT,
+            INTERVAL,
+            end,
+        )
+        .unwrap();
+
+    // The first installment is due as soon as the schedule is set
+    fixture
+        .pull_contribution(&accounts, &owner_ata, &cranker)
+        .unwrap();
+    assert_eq!(fixture.token_balance(&accounts.vault_token), 1_500_000);
+    assert_vault_error(
+        fixture.pull_contribution(&accounts, &owner_ata, &cranker),
+        DEPOSIT_NOT_DUE,
+    );
+    println!("✅ Installment pulled, early pull rejected");
+
+    fixture.advance_clock(INTERVAL);
+    fixture
+        .pull_contribution(&accounts, &owner_ata, &cranker)
+        .unwrap();
+    assert_eq!(fixture.token_balance(&accounts.vault_token), 2_000_000);
+
+    // Windows the crank slept through are skipped rather than pulled in a burst
+    fixture.advance_clock(3 * INTERVAL);
+    fixture
+        .pull_contribution(&accounts, &owner_ata, &cranker)
+        .unwrap();
+    assert_vault_error(
+        fixture.pull_contribution(&accounts, &owner_ata, &cranker),
+        DEPOSIT_NOT_DUE,
+    );
+    assert_eq!(fixture.token_balance(&accounts.vault_token), 2_500_000);
+    println!("✅ One installment per interval, missed windows skipped");
+
+    // The approval covered three installments
+    fixture.advance_clock(INTERVAL);
+    assert_vault_error(
+        fixture.pull_contribution(&accounts, &owner_ata, &cranker),
+        DEPOSIT_MISSED,
+    );
+    assert_eq!(fixture.token_balance(&owner_ata), 2_500_000);
+    println!("✅ Pull beyond the approved allowance rejected");
+}
+
+#[test]
+fn test_schedule_validation_and_end() {
+    println!("🧪 Testing deposit schedule validation");
+
+    let mut fixture = DepositScheduleTestFixture::new();
+    let (owner, owner_ata) = fixture.funded_user();
+    let accounts = fixture.create_vault(&owner, &owner_ata, &Pubkey::new_unique(), 1_000_000);
+
+    assert_vault_error(
+        fixture.pull_contribution(&accounts, &owner_ata, &owner),
+        NO_DEPOSIT_SCHEDULE,
+    );
+
+    let end = fixture.now() + 2 * INTERVAL;
+    assert_vault_error(
+        fixture.set_deposit_schedule(&accounts, &owner, Some(&owner_ata), INSTALLMENT, 60, end),
+        INVALID_DEPOSIT_SCHEDULE,
+    );
+    assert_vault_error(
+        fixture.set_deposit_schedule(&accounts, &owner, None, INSTALLMENT, INTERVAL, end),
+        INVALID_DEPOSIT_SCHEDULE,
+    );
+    println!("✅ Short interval and missing source rejected");
+
+    fixture
+        .set_deposit_schedule(
+            &accounts,
+            &owner,
+            Some(&owner_ata),
+            INSTALLMENT,
+            INTERVAL,
+            end,
+        )
+        .unwrap();
+    assert_vault_error(
+        fixture.pull_contribution(&accounts, &owner_ata, &owner),
+        DEPOSIT_MISSED,
+    );
+    println!("✅ Pull without a delegate approval rejected");
+
+    fixture.approve(&accounts, &owner, &owner_ata, 10 * INSTALLMENT);
+    fixture.advance_clock(2 * INTERVAL + 1);
+    assert_vault_error(
+        fixture.pull_contribution(&accounts, &owner_ata, &owner),
+        NO_DEPOSIT_SCHEDULE,
+    );
+    println!("✅ No pulls after the schedule ends");
+
+    fixture
+        .set_deposit_schedule(&accounts, &owner, None, 0, 0, 0)
+        .unwrap();
+    assert_vault_error(
+        fixture.pull_contribution(&accounts, &owner_ata, &owner),
+        NO_DEPOSIT_SCHEDULE,
+    );
+    assert_eq!(fixture.token_balance(&accounts.vault_token), 1_000_000);
+    println!("✅ Cleared schedule stops pulls");
+}
@@ -0,0 +1,371 @@
+use litesvm::LiteSVM;
+use litesvm_token::{spl_token, CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+
+const LOCK_PERIOD: i64 = 7200;
+
+// VaultError codes
+const VAULT_FROZEN: u32 = 6038;
+const DURESS_LOCK_TOO_LONG: u32 = 6058;
+
+/// Test fixture for duress heartbeats
+struct DuressTestFixture {
+    svm: LiteSVM,
+    program_id: Pubkey,
+    owner: Keypair,
+    duress: Keypair,
+    beneficiary: Pubkey,
+    owner_ata: Pubkey,
+    vault_pda: Pubkey,
+    vault_token_pda: Pubkey,
+    mint_stats_pda: Pubkey,
+}
+
+impl DuressTestFixture {
+    fn new() -> Self {
+        let mut svm = LiteSVM::new();
+
+        // Load the compiled program
+        let program_keypair =
+            read_keypair_file("target/deploy/cyber_vault_rs-keypair.json").unwrap();
+        let program_id = program_keypair.pubkey();
+        let program_bytes = include_bytes!("../target/deploy/cyber_vault_rs.so");
+        svm.add_program(program_id, program_bytes);
+
+        let owner = Keypair::new();
+        let duress = Keypair::new();
+        let beneficiary = Pubkey::new_unique();
+        svm.airdrop(&owner.pubkey(), 10_000_000_000).unwrap();
+        svm.airdrop(&duress.pubkey(), 1_000_000_000).unwrap();
+
+        let mint = CreateMint::new(&mut svm, &owner)
+            .authority(&owner.pubkey())
+            .decimals(6)
+            .send()
+            .unwrap();
+        let owner_ata = CreateAssociatedTokenAccount::new(&mut svm, &owner, &mint)
+            .owner(&owner.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &owner, &mint, &owner_ata, 1_000_000)
+            .send()
+            .unwrap();
+
+        let (vault_pda, _) = Pubkey::find_program_address(
+            &[
+                b"vault",
+                owner.pubkey().as_ref(),
+                beneficiary.as_ref(),
+                mint.as_ref(),
+            ],
+            &program_id,
+        );
+        let (vault_token_pda, _) =
+            Pubkey::find_program_address(&[b"vault_token", vault_pda.as_ref()], &program_id);
+        let (owner_registry_pda, _) = Pubkey::find_program_address(
+            &[b"owner_registry", owner.pubkey().as_ref()],
+            &program_id,
+        );
+        let (beneficiary_index_pda, _) = Pubkey::find_program_address(
+            &[b"beneficiary_index", beneficiary.as_ref()],
+            &program_id,
+        );
+        let (global_stats_pda, _) = Pubkey::find_program_address(&[b"global_stats"], &program_id);
+        let (mint_stats_pda, _) =
+            Pubkey::find_program_address(&[b"mint_stats", mint.as_ref()], &program_id);
+
+        let mut fixture = Self {
+            svm,
+            program_id,
+            owner,
+            duress,
+            beneficiary,
+            owner_ata,
+            vault_pda,
+            vault_token_pda,
+            mint_stats_pda,
+        };
+
+        // create_vault discriminator: [29, 237, 247, 208, 193, 82, 54, 135]
+        let mut instruction_data = vec![29, 237, 247, 208, 193, 82, 54, 135];
+        instruction_data.extend_from_slice(&beneficiary.to_bytes());
+        instruction_data.extend_from_slice(&3600i64.to_le_bytes());
+        instruction_data.extend_from_slice(&1_000_000u64.to_le_bytes());
+        instruction_data.push(0); // acknowledge_freeze_authority
+        let (mint_policy_pda, _) = Pubkey::find_program_address(&[b"mint_policy"], &program_id);
+        let instruction = Instruction {
+            program_id,
+            accounts: vec![
+                AccountMeta::new(fixture.owner.pubkey(), true),
+                AccountMeta::new(vault_pda, false),
+                AccountMeta::new(vault_token_pda, false),
+                AccountMeta::new(owner_ata, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
+                AccountMeta::new(owner_registry_pda, false),
+                AccountMeta::new(beneficiary_index_pda, false),
+                AccountMeta::new(global_stats_pda, false),
+                AccountMeta::new(mint_stats_pda, false),
+                AccountMeta::new_readonly(mint_policy_pda, false),
+            ],
+            data: instruction_data,
+        };
+        let owner = fixture.owner.insecure_clone();
+        fixture.send(instruction, &owner).unwrap();
+
+        fixture
+    }
+
+    /// Sends one instruction and returns its program logs
+    fn send(&mut self, instruction: Instruction, signer: &Keypair) -> Result<Vec<String>, String> {
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        self.svm
+            .send_transaction(tx)
+            .map(|meta| meta.logs)
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn set_duress_key(&mut self, duress_key: &Pubkey, lock_period: i64) -> Result<(), String> {
+        // set_duress_key discriminator: [36, 185, 208, 162, 145, 74, 235, 67]
+        let mut instruction_data = vec![36, 185, 208, 162, 145, 74, 235, 67];
+        instruction_data.extend_from_slice(&duress_key.to_bytes());
+        instruction_data.extend_from_slice(&lock_period.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(self.owner.pubkey(), true),
+                AccountMeta::new(self.vault_pda, false),
+            ],
+            data: instruction_data,
+        };
+
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner).map(|_| ())
+    }
+
+    fn heartbeat(&mut self, signer: &Keypair) -> Result<Vec<String>, String> {
+        // heartbeat discriminator: [202, 104, 56, 6, 240, 170, 63, 134]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.vault_pda, false),
+                AccountMeta::new_readonly(signer.pubkey(), true),
+            ],
+            data: vec![202, 104, 56, 6, 240, 170, 63, 134],
+        };
+
+        self.send(instruction, signer)
+    }
+
+    fn emergency_withdraw(&mut self, amount: u64) -> Result<(), String> {
+        // emergency_withdraw discriminator: [239, 45, 203, 64, 150, 73, 218, 92]
+        let mut instruction_data = vec![239, 45, 203, 64, 150, 73, 218, 92];
+        instruction_data.extend_from_slice(&amount.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.owner.pubkey(), true),
+                AccountMeta::new(self.vault_pda, false),
+                AccountMeta::new(self.owner_ata, false),
+                AccountMeta::new(self.vault_token_pda, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new(self.mint_stats_pda, false),
+            ],
+            data: instruction_data,
+        };
+
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner).map(|_| ())
+    }
+
+    fn propose_owner(&mut self, new_owner: &Pubkey) -> Result<(), String> {
+        // propose_owner discriminator: [90, 57, 141, 110, 196, 241, 172, 39]
+        let mut instruction_data = vec![90, 57, 141, 110, 196, 241, 172, 39];
+        instruction_data.extend_from_slice(new_owner.as_ref());
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(self.owner.pubkey(), true),
+                AccountMeta::new(self.vault_pda, false),
+            ],
+            data: instruction_data,
+        };
+
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner).map(|_| ())
+    }
+
+    fn accept_owner(&mut self, new_owner: &Keypair) -> Result<(), String> {
+        let (owner_registry, _) = Pubkey::find_program_address(
+            &[b"owner_registry", self.owner.pubkey().as_ref()],
+            &self.program_id,
+        );
+        let (new_owner_registry, _) = Pubkey::find_program_address(
+            &[b"owner_registry", new_owner.pubkey().as_ref()],
+            &self.program_id,
+        );
+
+        // accept_owner discriminator: [176, 23, 41, 28, 23, 111, 8, 4]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(new_owner.pubkey(), true),
+                AccountMeta::new(self.vault_pda, false),
+                AccountMeta::new(owner_registry, false),
+                AccountMeta::new(new_owner_registry, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: vec![176, 23, 41, 28, 23, 111, 8, 4],
+        };
+
+        self.send(instruction, new_owner).map(|_| ())
+    }
+
+    fn advance_clock(&mut self, seconds: i64) {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp += seconds;
+        self.svm.set_sysvar(&clock);
+    }
+}
+
+#[test]
+fn test_duress_heartbeat_freezes_withdrawals() {
+    println!("🧪 Testing that a duress heartbeat locks the vault");
+
+    let mut fixture = DuressTestFixture::new();
+    let duress = fixture.duress.insecure_clone();
+    fixture
+        .set_duress_key(&duress.pubkey(), LOCK_PERIOD)
+        .unwrap();
+    fixture.emergency_withdraw(100_000).unwrap();
+
+    fixture.heartbeat(&duress).unwrap();
+    assert!(
+        fixture.emergency_withdraw(100_000).is_err(),
+        "Withdrawal during a duress lock should fail"
+    );
+    println!("✅ Withdrawals frozen");
+
+    fixture.advance_clock(LOCK_PERIOD);
+    fixture.emergency_withdraw(100_000).unwrap();
+    println!("✅ Lock lifted after the lock period");
+}
+
+fn assert_vault_error(result: Result<(), String>, code: u32) {
+    let err = result.expect_err("instruction should have been rejected");
+    assert!(
+        err.contains(&format!("Custom({})", code)),
+        "expected error {}, got {}",
+        code,
+        err
+    );
+}
+
+#[test]
+fn test_duress_lock_blocks_ownership_transfer() {
+    println!("🧪 Testing ownership transfer during a duress lock");
+
+    let mut fixture = DuressTestFixture::new();
+    let duress = fixture.duress.insecure_clone();
+    let attacker = Keypair::new();
+    fixture
+        .svm
+        .airdrop(&attacker.pubkey(), 1_000_000_000)
+        .unwrap();
+    fixture
+        .set_duress_key(&duress.pubkey(), LOCK_PERIOD)
+        .unwrap();
+
+    // Proposed before the coerced heartbeat, accepted during the lock
+    fixture.propose_owner(&attacker.pubkey()).unwrap();
+    fixture.heartbeat(&duress).unwrap();
+    assert_vault_error(fixture.accept_owner(&attacker), VAULT_FROZEN);
+    assert_vault_error(fixture.propose_owner(&Pubkey::new_unique()), VAULT_FROZEN);
+    println!("✅ Ownership cannot be proposed or accepted while frozen");
+
+    fixture.advance_clock(LOCK_PERIOD);
+    fixture.accept_owner(&attacker).unwrap();
+    println!("✅ Transfer proceeds once the lock has lifted");
+}
+
+#[test]
+fn test_duress_heartbeat_looks_normal() {
+    println!("🧪 Testing that a duress heartbeat mirrors a normal one");
+
+    let mut fixture = DuressTestFixture::new();
+    let owner = fixture.owner.insecure_clone();
+    let duress = fixture.duress.insecure_clone();
+    fixture
+        .set_duress_key(&duress.pubkey(), LOCK_PERIOD)
+        .unwrap();
+
+    let messages = |logs: Vec<String>| -> Vec<String> {
+        logs.into_iter()
+            .filter(|line| {
+                line.starts_with("Program log: 💓") || line.starts_with("Program log: ⏰")
+            })
+            .collect()
+    };
+    let normal = fixture.heartbeat(&owner).unwrap();
+    let coerced = fixture.heartbeat(&duress).unwrap();
+
+    assert_eq!(messages(normal.clone()), messages(coerced.clone()));
+    println!("✅ Same log messages for both heartbeats");
+
+    // Only the duress heartbeat carries the DuressAlarm event
+    assert!(!normal.iter().any(|line| line.starts_with("Program data: ")));
+    assert!(coerced
+        .iter()
+        .any(|line| line.starts_with("Program data: ")));
+    println!("✅ DuressAlarm event emitted for guardians and beneficiary");
+}
+
+#[test]
+fn test_set_duress_key_validation() {
+    println!("🧪 Testing duress key validation");
+
+    let mut fixture = DuressTestFixture::new();
+    let owner = fixture.owner.pubkey();
+    let beneficiary = fixture.beneficiary;
+    let duress = fixture.duress.insecure_clone();
+
+    assert!(fixture.set_duress_key(&owner, LOCK_PERIOD).is_err());
+    assert!(fixture.set_duress_key(&beneficiary, LOCK_PERIOD).is_err());
+    assert!(fixture.set_duress_key(&duress.pubkey(), 60).is_err());
+    assert_vault_error(
+        fixture.set_duress_key(&duress.pubkey(), i64::MAX),
+        DURESS_LOCK_TOO_LONG,
+    );
+    println!("✅ Owner, beneficiary, short and overlong lock periods rejected");
+
+    // Unregistered keys cannot heartbeat
+    assert!(fixture.heartbeat(&duress).is_err());
+
+    fixture
+        .set_duress_key(&duress.pubkey(), LOCK_PERIOD)
+        .unwrap();
+    fixture.heartbeat(&duress).unwrap();
+    assert!(
+        fixture.set_duress_key(&Pubkey::default(), 0).is_err(),
+        "Disabling duress mode while frozen should fail"
+    );
+    println!("✅ Duress mode cannot be switched off during a lock");
+}
@@ -89,12 +89,28 @@ impl EmergencyWithdrawTestFixture {
     fn create_vault(&mut self, deposit_amount: u64) -> Result<(), String> {
         let inactivity_period: i64 = 3600; // 1 hour (minimum)
 
+        let (owner_registry_pda, _) = Pubkey::find_program_address(
+            &[b"owner_registry", self.owner.pubkey().as_ref()],
+            &self.program_id,
+        );
+        let (beneficiary_index_pda, _) = Pubkey::find_program_address(
+            &[b"beneficiary_index", self.beneficiary.pubkey().as_ref()],
+            &self.program_id,
+        );
+        let (global_stats_pda, _) =
+            Pubkey::find_program_address(&[b"global_stats"], &self.program_id);
+        let (mint_stats_pda, _) =
+            Pubkey::find_program_address(&[b"mint_stats", self.mint.as_ref()], &self.program_id);
+
         // create_vault discriminator: [29, 237, 247, 208, 193, 82, 54, 135]
         let create_vault_discriminator = [29, 237, 247, 208, 193, 82, 54, 135];
         let mut instruction_data = create_vault_discriminator.to_vec();
         instruction_data.extend_from_slice(&self.beneficiary.pubkey().to_bytes());
         instruction_data.extend_from_slice(&inactivity_period.to_le_bytes());
         instruction_data.extend_from_slice(&deposit_amount.to_le_bytes());
+        instruction_data.push(0); // acknowledge_freeze_authority
+        let (mint_policy_pda, _) =
+            Pubkey::find_program_address(&[b"mint_policy"], &self.program_id);
 
         let instruction = Instruction {
             program_id: self.program_id,
@@ -107,6 +123,11 @@ impl EmergencyWithdrawTestFixture {
                 AccountMeta::new_readonly(spl_token::id(), false),
                 AccountMeta::new_readonly(system_program::id(), false),
                 AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
+                AccountMeta::new(owner_registry_pda, false),
+                AccountMeta::new(beneficiary_index_pda, false),
+                AccountMeta::new(global_stats_pda, false),
+                AccountMeta::new(mint_stats_pda, false),
+                AccountMeta::new_readonly(mint_policy_pda, false),
             ],
             data: instruction_data,
         };
@@ -129,6 +150,8 @@ impl EmergencyWithdrawTestFixture {
         let emergency_withdraw_discriminator = [239, 45, 203, 64, 150, 73, 218, 92];
         let mut instruction_data = emergency_withdraw_discriminator.to_vec();
         instruction_data.extend_from_slice(&amount.to_le_bytes());
+        let (mint_stats_pda, _) =
+            Pubkey::find_program_address(&[b"mint_stats", self.mint.as_ref()], &self.program_id);
 
         let instruction = Instruction {
             program_id: self.program_id,
@@ -138,6 +161,7 @@ impl EmergencyWithdrawTestFixture {
                 AccountMeta::new(self.owner_ata, false),
                 AccountMeta::new(self.vault_token_pda, false),
                 AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new(mint_stats_pda, false),
             ],
             data: instruction_data,
         };
@@ -0,0 +1,351 @@
+use litesvm::LiteSVM;
+use litesvm_token::{spl_token, CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+
+// heartbeat_many discriminator: [170, 110, 125, 32, 241, 62, 57, 196]
+const HEARTBEAT_MANY_DISCRIMINATOR: [u8; 8] = [170, 110, 125, 32, 241, 62, 57, 196];
+
+// A legacy transaction with one signer fits at most ~31 extra account keys
+const MAX_VAULTS_PER_TX: usize = 30;
+
+// VaultError codes
+const SWITCH_ALREADY_LINKED: u32 = 6023;
+
+/// Test fixture for batch heartbeat tests
+struct HeartbeatManyTestFixture {
+    svm: LiteSVM,
+    program_id: Pubkey,
+    owner: Keypair,
+    mint: Pubkey,
+    owner_ata: Pubkey,
+    vaults: Vec<Pubkey>,
+}
+
+impl HeartbeatManyTestFixture {
+    fn new(vault_count: usize) -> Self {
+        let mut svm = LiteSVM::new();
+
+        // Load the compiled program
+        let program_keypair =
+            read_keypair_file("target/deploy/cyber_vault_rs-keypair.json").unwrap();
+        let program_id = program_keypair.pubkey();
+        let program_bytes = include_bytes!("../target/deploy/cyber_vault_rs.so");
+        svm.add_program(program_id, program_bytes);
+
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), 100_000_000_000).unwrap();
+
+        // Create token mint and fund the owner
+        let mint = CreateMint::new(&mut svm, &owner)
+            .authority(&owner.pubkey())
+            .decimals(6)
+            .send()
+            .unwrap();
+
+        let owner_ata = CreateAssociatedTokenAccount::new(&mut svm, &owner, &mint)
+            .owner(&owner.pubkey())
+            .send()
+            .unwrap();
+
+        MintTo::new(&mut svm, &owner, &mint, &owner_ata, 100_000_000)
+            .send()
+            .unwrap();
+
+        let mut fixture = Self {
+            svm,
+            program_id,
+            owner,
+            mint,
+            owner_ata,
+            vaults: Vec::new(),
+        };
+        for _ in 0..vault_count {
+            let vault = fixture.create_vault(&Pubkey::new_unique());
+            fixture.vaults.push(vault);
+        }
+        fixture
+    }
+
+    fn create_vault(&mut self, beneficiary: &Pubkey) -> Pubkey {
+        let inactivity_period: i64 = 3600;
+        let deposit_amount: u64 = 1_000;
+
+        let (vault_pda, _) = Pubkey::find_program_address(
+            &[
+                b"vault",
+                self.owner.pubkey().as_ref(),
+                beneficiary.as_ref(),
+                self.mint.as_ref(),
+            ],
+            &self.program_id,
+        );
+        let (vault_token_pda, _) =
+            Pubkey::find_program_address(&[b"vault_token", vault_pda.as_ref()], &self.program_id);
+
+        let (owner_registry_pda, _) = Pubkey::find_program_address(
+            &[b"owner_registry", self.owner.pubkey().as_ref()],
+            &self.program_id,
+        );
+        let (beneficiary_index_pda, _) = Pubkey::find_program_address(
+            &[b"beneficiary_index", beneficiary.as_ref()],
+            &self.program_id,
+        );
+        let (global_stats_pda, _) =
+            Pubkey::find_program_address(&[b"global_stats"], &self.program_id);
+        let (mint_stats_pda, _) =
+            Pubkey::find_program_address(&[b"mint_stats", self.mint.as_ref()], &self.program_id);
+
+        // create_vault discriminator: [29, 237, 247, 208, 193, 82, 54, 135]
+        let mut instruction_data = vec![29, 237, 247, 208, 193, 82, 54, 135];
+        instruction_data.extend_from_slice(&beneficiary.to_bytes());
+        instruction_data.extend_from_slice(&inactivity_period.to_le_bytes());
+        instruction_data.extend_from_slice(&deposit_amount.to_le_bytes());
+        instruction_data.push(0); // acknowledge_freeze_authority
+        let (mint_policy_pda, _) =
+            Pubkey::find_program_address(&[b"mint_policy"], &self.program_id);
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.owner.pubkey(), true),
+                AccountMeta::new(vault_pda, false),
+                AccountMeta::new(vault_token_pda, false),
+                AccountMeta::new(self.owner_ata, false),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
+                AccountMeta::new(owner_registry_pda, false),
+                AccountMeta::new(beneficiary_index_pda, false),
+                AccountMeta::new(global_stats_pda, false),
+                AccountMeta::new(mint_stats_pda, false),
+                AccountMeta::new_readonly(mint_policy_pda, false),
+            ],
+            data: instruction_data,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&self.owner.pubkey()),
+            &[&self.owner],
+            self.svm.latest_blockhash(),
+        );
+        self.svm.send_transaction(tx).unwrap();
+
+        vault_pda
+    }
+
+    /// Sends heartbeat_many and returns the compute units consumed
+    fn heartbeat_many(&mut self, vaults: &[Pubkey], signer: &Keypair) -> Result<u64, String> {
+        let mut accounts = vec![AccountMeta::new_readonly(signer.pubkey(), true)];
+        accounts.extend(vaults.iter().map(|vault| AccountMeta::new(*vault, false)));
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: HEARTBEAT_MANY_DISCRIMINATOR.to_vec(),
+        };
+
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        self.svm
+            .send_transaction(tx)
+            .map(|meta| meta.compute_units_consumed)
+            .map_err(|e| format!("{:?} ({} CU)", e.err, e.meta.compute_units_consumed))
+    }
+
+    /// Creates the owner's master switch and links `vault` to it
+    fn link_switch(&mut self, vault: &Pubkey) {
+        let owner = self.owner.insecure_clone();
+        let (switch, _) =
+            Pubkey::find_program_address(&[b"switch", owner.pubkey().as_ref()], &self.program_id);
+
+        // create_switch discriminator: [120, 11, 193, 114, 80, 234, 99, 128]
+        let mut instruction_data = vec![120, 11, 193, 114, 80, 234, 99, 128];
+        instruction_data.extend_from_slice(&3600i64.to_le_bytes());
+        let create = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(owner.pubkey(), true),
+                AccountMeta::new(switch, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: instruction_data,
+        };
+
+        // link_switch discriminator: [136, 247, 62, 224, 223, 183, 48, 117]
+        let link = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(owner.pubkey(), true),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(switch, false),
+            ],
+            data: vec![136, 247, 62, 224, 223, 183, 48, 117],
+        };
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[create, link],
+            Some(&owner.pubkey()),
+            &[&owner],
+            self.svm.latest_blockhash(),
+        );
+        self.svm.send_transaction(tx).unwrap();
+    }
+
+    fn last_heartbeat(&self, vault: &Pubkey) -> i64 {
+        // 8 discriminator + 6 pubkeys + inactivity_period
+        let data = self.svm.get_account(vault).unwrap().data;
+        i64::from_le_bytes(data[208..216].try_into().unwrap())
+    }
+}
+
+#[test]
+fn test_heartbeat_many_updates_every_vault() {
+    println!("🧪 Testing batch heartbeat across several vaults");
+
+    let mut fixture = HeartbeatManyTestFixture::new(5);
+
+    let mut clock = fixture.svm.get_sysvar::<Clock>();
+    clock.unix_timestamp += 1_800;
+    fixture.svm.set_sysvar(&clock);
+
+    let vaults = fixture.vaults.clone();
+    let owner = fixture.owner.insecure_clone();
+    fixture.heartbeat_many(&vaults, &owner).unwrap();
+
+    for vault in &vaults {
+        assert_eq!(fixture.last_heartbeat(vault), clock.unix_timestamp);
+    }
+    println!(
+        "✅ All {} vaults refreshed in one instruction",
+        vaults.len()
+    );
+}
+
+#[test]
+fn test_heartbeat_many_rejects_foreign_vaults() {
+    println!("🧪 Testing batch heartbeat with a vault the signer does not own");
+
+    let mut fixture = HeartbeatManyTestFixture::new(2);
+
+    // A second owner's vault slipped into the batch
+    let other = HeartbeatManyTestFixture::new(1);
+    let stranger = other.owner.insecure_clone();
+    let stranger_vault = other.vaults[0];
+    fixture
+        .svm
+        .set_account(
+            stranger_vault,
+            other.svm.get_account(&stranger_vault).unwrap(),
+        )
+        .unwrap();
+
+    let mut vaults = fixture.vaults.clone();
+    vaults.push(stranger_vault);
+    let owner = fixture.owner.insecure_clone();
+    assert!(
+        fixture.heartbeat_many(&vaults, &owner).is_err(),
+        "Batch containing another owner's vault should fail"
+    );
+
+    fixture
+        .svm
+        .airdrop(&stranger.pubkey(), 1_000_000_000)
+        .unwrap();
+    let own_vaults = fixture.vaults.clone();
+    assert!(
+        fixture.heartbeat_many(&own_vaults, &stranger).is_err(),
+        "Non-owner signer should fail"
+    );
+    println!("✅ Foreign vaults and signers rejected");
+}
+
+#[test]
+fn test_heartbeat_many_rejects_non_vault_accounts() {
+    println!("🧪 Testing batch heartbeat with non-vault accounts");
+
+    let mut fixture = HeartbeatManyTestFixture::new(1);
+    let owner = fixture.owner.insecure_clone();
+
+    // A token account is program-foreign and must be rejected
+    let vaults = vec![fixture.vaults[0], fixture.owner_ata];
+    assert!(
+        fixture.heartbeat_many(&vaults, &owner).is_err(),
+        "Non-vault account should fail"
+    );
+
+    assert!(
+        fixture.heartbeat_many(&[], &owner).is_err(),
+        "Empty batch should fail"
+    );
+    println!("✅ Non-vault accounts and empty batches rejected");
+}
+
+#[test]
+fn test_heartbeat_many_compute_benchmark() {
+    println!("🧪 Benchmarking heartbeat_many compute units");
+
+    let mut fixture = HeartbeatManyTestFixture::new(MAX_VAULTS_PER_TX);
+    let owner = fixture.owner.insecure_clone();
+
+    println!("   vaults | compute units | CU per vault");
+    let mut last_ok = 0;
+    for count in [1, 5, 10, 15, 20, 25, MAX_VAULTS_PER_TX] {
+        let vaults = fixture.vaults[..count].to_vec();
+        match fixture.heartbeat_many(&vaults, &owner) {
+            Ok(units) => {
+                println!(
+                    "   {:>6} | {:>13} | {:>12}",
+                    count,
+                    units,
+                    units / count as u64
+                );
+                last_ok = count;
+            }
+            Err(e) => {
+                println!("   {:>6} | failed: {}", count, e);
+                break;
+            }
+        }
+    }
+
+    println!(
+        "📊 Largest batch within the default 200k CU budget: {} vaults",
+        last_ok
+    );
+    assert!(
+        last_ok >= 20,
+        "heartbeat_many must handle at least 20 vaults in one transaction"
+    );
+}
+
+#[test]
+fn test_heartbeat_many_rejects_linked_vaults() {
+    println!("🧪 Testing batch heartbeat with a vault linked to a master switch");
+
+    let mut fixture = HeartbeatManyTestFixture::new(3);
+    let vaults = fixture.vaults.clone();
+    fixture.link_switch(&vaults[1]);
+
+    let owner = fixture.owner.insecure_clone();
+    let err = fixture.heartbeat_many(&vaults, &owner).unwrap_err();
+    assert!(
+        err.contains(&format!("Custom({})", SWITCH_ALREADY_LINKED)),
+        "expected SwitchAlreadyLinked, got {}",
+        err
+    );
+    println!("✅ Batch containing a linked vault rejected");
+}
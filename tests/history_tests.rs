@@ -0,0 +1,481 @@
+use litesvm::LiteSVM;
+use litesvm_token::{spl_token, CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+
+const HISTORY_LEN: usize = 32;
+const KIND_HEARTBEAT: u8 = 0;
+const KIND_EMERGENCY_WITHDRAW: u8 = 1;
+const KIND_QUEUED_WITHDRAWAL: u8 = 2;
+
+/// Test fixture for the per-vault heartbeat history
+struct HistoryTestFixture {
+    svm: LiteSVM,
+    program_id: Pubkey,
+    owner: Keypair,
+    beneficiary: Pubkey,
+    mint: Pubkey,
+    owner_ata: Pubkey,
+    vault_pda: Pubkey,
+    vault_token_pda: Pubkey,
+    history_pda: Pubkey,
+    owner_registry_pda: Pubkey,
+    beneficiary_index_pda: Pubkey,
+    global_stats_pda: Pubkey,
+    mint_stats_pda: Pubkey,
+}
+
+struct Entry {
+    timestamp: i64,
+    amount: u64,
+    signer: Pubkey,
+    kind: u8,
+}
+
+impl HistoryTestFixture {
+    fn new() -> Self {
+        let mut svm = LiteSVM::new();
+
+        // Load the compiled program
+        let program_keypair =
+            read_keypair_file("target/deploy/cyber_vault_rs-keypair.json").unwrap();
+        let program_id = program_keypair.pubkey();
+        let program_bytes = include_bytes!("../target/deploy/cyber_vault_rs.so");
+        svm.add_program(program_id, program_bytes);
+
+        let owner = Keypair::new();
+        let beneficiary = Pubkey::new_unique();
+        svm.airdrop(&owner.pubkey(), 10_000_000_000).unwrap();
+
+        let mint = CreateMint::new(&mut svm, &owner)
+            .authority(&owner.pubkey())
+            .decimals(6)
+            .send()
+            .unwrap();
+        let owner_ata = CreateAssociatedTokenAccount::new(&mut svm, &owner, &mint)
+            .owner(&owner.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &owner, &mint, &owner_ata, 1_000_000)
+            .send()
+            .unwrap();
+
+        let (vault_pda, _) = Pubkey::find_program_address(
+            &[
+                b"vault",
+                owner.pubkey().as_ref(),
+                beneficiary.as_ref(),
+                mint.as_ref(),
+            ],
+            &program_id,
+        );
+        let (vault_token_pda, _) =
+            Pubkey::find_program_address(&[b"vault_token", vault_pda.as_ref()], &program_id);
+        let (owner_registry_pda, _) = Pubkey::find_program_address(
+            &[b"owner_registry", owner.pubkey().as_ref()],
+            &program_id,
+        );
+        let (beneficiary_index_pda, _) = Pubkey::find_program_address(
+            &[b"beneficiary_index", beneficiary.as_ref()],
+            &program_id,
+        );
+        let (global_stats_pda, _) = Pubkey::find_program_address(&[b"global_stats"], &program_id);
+        let (mint_stats_pda, _) =
+            Pubkey::find_program_address(&[b"mint_stats", mint.as_ref()], &program_id);
+        let (history_pda, _) =
+            Pubkey::find_program_address(&[b"history", vault_pda.as_ref()], &program_id);
+
+        let mut fixture = Self {
+            svm,
+            program_id,
+            owner,
+            beneficiary,
+            mint,
+            owner_ata,
+            vault_pda,
+            vault_token_pda,
+            history_pda,
+            owner_registry_pda,
+            beneficiary_index_pda,
+            global_stats_pda,
+            mint_stats_pda,
+        };
+        fixture.create_vault().unwrap();
+
+        fixture
+    }
+
+    fn create_vault(&mut self) -> Result<(), String> {
+        // create_vault discriminator: [29, 237, 247, 208, 193, 82, 54, 135]
+        let mut instruction_data = vec![29, 237, 247, 208, 193, 82, 54, 135];
+        instruction_data.extend_from_slice(&self.beneficiary.to_bytes());
+        instruction_data.extend_from_slice(&3600i64.to_le_bytes());
+        instruction_data.extend_from_slice(&1_000_000u64.to_le_bytes());
+        instruction_data.push(0); // acknowledge_freeze_authority
+        let (mint_policy_pda, _) =
+            Pubkey::find_program_address(&[b"mint_policy"], &self.program_id);
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.owner.pubkey(), true),
+                AccountMeta::new(self.vault_pda, false),
+                AccountMeta::new(self.vault_token_pda, false),
+                AccountMeta::new(self.owner_ata, false),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
+                AccountMeta::new(self.owner_registry_pda, false),
+                AccountMeta::new(self.beneficiary_index_pda, false),
+                AccountMeta::new(self.global_stats_pda, false),
+                AccountMeta::new(self.mint_stats_pda, false),
+                AccountMeta::new_readonly(mint_policy_pda, false),
+            ],
+            data: instruction_data,
+        };
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner)
+    }
+
+    fn close_vault(&mut self, with_history: bool) -> Result<(), String> {
+        // close_vault discriminator: [141, 103, 17, 126, 72, 75, 29, 29]
+        let mut accounts = vec![
+            AccountMeta::new(self.owner.pubkey(), true),
+            AccountMeta::new(self.vault_pda, false),
+            AccountMeta::new(self.vault_token_pda, false),
+            AccountMeta::new(self.owner_registry_pda, false),
+            AccountMeta::new(self.beneficiary_index_pda, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new(self.global_stats_pda, false),
+            AccountMeta::new(self.mint_stats_pda, false),
+        ];
+        if with_history {
+            accounts.extend([
+                AccountMeta::new_readonly(self.program_id, false), // switch: none
+                AccountMeta::new_readonly(self.program_id, false), // recovery: none
+                AccountMeta::new_readonly(self.program_id, false), // pending_withdrawal: none
+                AccountMeta::new(self.history_pda, false),
+            ]);
+        }
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: vec![141, 103, 17, 126, 72, 75, 29, 29],
+        };
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner)
+    }
+
+    fn send(&mut self, instruction: Instruction, signer: &Keypair) -> Result<(), String> {
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        self.svm
+            .send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn create_history(&mut self, signer: &Keypair) -> Result<(), String> {
+        // create_history discriminator: [17, 80, 83, 78, 168, 45, 161, 35]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(signer.pubkey(), true),
+                AccountMeta::new(self.vault_pda, false),
+                AccountMeta::new(self.history_pda, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: vec![17, 80, 83, 78, 168, 45, 161, 35],
+        };
+
+        self.send(instruction, signer)
+    }
+
+    fn heartbeat(&mut self, with_history: bool) -> Result<(), String> {
+        let mut accounts = vec![
+            AccountMeta::new(self.vault_pda, false),
+            AccountMeta::new_readonly(self.owner.pubkey(), true),
+        ];
+        if with_history {
+            accounts.push(AccountMeta::new(self.history_pda, false));
+        }
+
+        // heartbeat discriminator: [202, 104, 56, 6, 240, 170, 63, 134]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: vec![202, 104, 56, 6, 240, 170, 63, 134],
+        };
+
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner)
+    }
+
+    fn emergency_withdraw(&mut self, amount: u64, with_history: bool) -> Result<(), String> {
+        let mut accounts = vec![
+            AccountMeta::new(self.owner.pubkey(), true),
+            AccountMeta::new(self.vault_pda, false),
+            AccountMeta::new(self.owner_ata, false),
+            AccountMeta::new(self.vault_token_pda, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new(self.mint_stats_pda, false),
+        ];
+        if with_history {
+            // No unwrap: token_mint, unwrap_account and system_program left out
+            let placeholder = AccountMeta::new_readonly(self.program_id, false);
+            accounts.extend([placeholder.clone(), placeholder.clone(), placeholder]);
+            accounts.push(AccountMeta::new(self.history_pda, false));
+        }
+
+        // emergency_withdraw discriminator: [239, 45, 203, 64, 150, 73, 218, 92]
+        let mut instruction_data = vec![239, 45, 203, 64, 150, 73, 218, 92];
+        instruction_data.extend_from_slice(&amount.to_le_bytes());
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: instruction_data,
+        };
+
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner)
+    }
+
+    fn withdrawal_pda(&self) -> Pubkey {
+        Pubkey::find_program_address(&[b"withdrawal", self.vault_pda.as_ref()], &self.program_id).0
+    }
+
+    /// With no policy configured the withdrawal is executable right away
+    fn queue_withdrawal(&mut self, amount: u64) -> Result<(), String> {
+        let withdrawal_pda = self.withdrawal_pda();
+
+        // queue_withdrawal discriminator: [153, 8, 176, 235, 189, 140, 146, 223]
+        let mut instruction_data = vec![153, 8, 176, 235, 189, 140, 146, 223];
+        instruction_data.extend_from_slice(&amount.to_le_bytes());
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.owner.pubkey(), true),
+                AccountMeta::new(self.vault_pda, false),
+                AccountMeta::new_readonly(self.vault_token_pda, false),
+                AccountMeta::new(withdrawal_pda, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: instruction_data,
+        };
+
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner)
+    }
+
+    fn execute_withdrawal(&mut self, with_history: bool) -> Result<(), String> {
+        let withdrawal_pda = self.withdrawal_pda();
+        let mut accounts = vec![
+            AccountMeta::new(self.owner.pubkey(), true),
+            AccountMeta::new(self.vault_pda, false),
+            AccountMeta::new(withdrawal_pda, false),
+            AccountMeta::new(self.owner_ata, false),
+            AccountMeta::new(self.vault_token_pda, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new(self.mint_stats_pda, false),
+        ];
+        if with_history {
+            accounts.push(AccountMeta::new(self.history_pda, false));
+        }
+
+        // execute_withdrawal discriminator: [113, 121, 203, 232, 137, 139, 248, 249]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: vec![113, 121, 203, 232, 137, 139, 248, 249],
+        };
+
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner)
+    }
+
+    fn heartbeat_many(&mut self, with_history: bool) -> Result<(), String> {
+        let mut accounts = vec![
+            AccountMeta::new_readonly(self.owner.pubkey(), true),
+            AccountMeta::new(self.vault_pda, false),
+        ];
+        if with_history {
+            accounts.push(AccountMeta::new(self.history_pda, false));
+        }
+
+        // heartbeat_many discriminator: [170, 110, 125, 32, 241, 62, 57, 196]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: vec![170, 110, 125, 32, 241, 62, 57, 196],
+        };
+
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner)
+    }
+
+    fn advance_clock(&mut self, seconds: i64) -> i64 {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp += seconds;
+        self.svm.set_sysvar(&clock);
+        clock.unix_timestamp
+    }
+
+    /// Returns (next, len) and the raw entries in buffer order
+    fn history(&self) -> (u32, u32, Vec<Entry>) {
+        let data = self.svm.get_account(&self.history_pda).unwrap().data;
+        let next = u32::from_le_bytes(data[40..44].try_into().unwrap());
+        let len = u32::from_le_bytes(data[44..48].try_into().unwrap());
+        let entries = (0..HISTORY_LEN)
+            .map(|i| {
+                let entry = &data[56 + i * 56..56 + (i + 1) * 56];
+                Entry {
+                    timestamp: i64::from_le_bytes(entry[0..8].try_into().unwrap()),
+                    amount: u64::from_le_bytes(entry[8..16].try_into().unwrap()),
+                    signer: Pubkey::try_from(&entry[16..48]).unwrap(),
+                    kind: entry[48],
+                }
+            })
+            .collect();
+        (next, len, entries)
+    }
+}
+
+#[test]
+fn test_history_records_heartbeats_and_withdrawals() {
+    println!("🧪 Testing that heartbeats and withdrawals are logged");
+
+    let mut fixture = HistoryTestFixture::new();
+    let owner = fixture.owner.insecure_clone();
+    fixture.create_history(&owner).unwrap();
+
+    let t1 = fixture.advance_clock(60);
+    fixture.heartbeat(true).unwrap();
+    let t2 = fixture.advance_clock(60);
+    fixture.emergency_withdraw(250_000, true).unwrap();
+
+    let (next, len, entries) = fixture.history();
+    assert_eq!((next, len), (2, 2));
+    assert_eq!(entries[0].timestamp, t1);
+    assert_eq!(entries[0].kind, KIND_HEARTBEAT);
+    assert_eq!(entries[0].signer, owner.pubkey());
+    assert_eq!(entries[1].timestamp, t2);
+    assert_eq!(entries[1].kind, KIND_EMERGENCY_WITHDRAW);
+    assert_eq!(entries[1].amount, 250_000);
+    println!("✅ Timestamp, signer, kind and amount recorded");
+}
+
+#[test]
+fn test_history_ring_buffer_wraps() {
+    println!("🧪 Testing that the history keeps only the last entries");
+
+    let mut fixture = HistoryTestFixture::new();
+    let owner = fixture.owner.insecure_clone();
+    fixture.create_history(&owner).unwrap();
+
+    let mut timestamps = Vec::new();
+    for _ in 0..HISTORY_LEN + 3 {
+        timestamps.push(fixture.advance_clock(60));
+        fixture.heartbeat(true).unwrap();
+    }
+
+    let (next, len, entries) = fixture.history();
+    assert_eq!((next, len), (3, HISTORY_LEN as u32));
+    // The three oldest heartbeats were overwritten in place
+    assert_eq!(entries[0].timestamp, timestamps[HISTORY_LEN]);
+    assert_eq!(entries[3].timestamp, timestamps[3]);
+    println!("✅ Oldest entries overwritten");
+}
+
+#[test]
+fn test_history_account_required_once_enabled() {
+    println!("🧪 Testing that a vault with history cannot skip logging");
+
+    let mut fixture = HistoryTestFixture::new();
+    let owner = fixture.owner.insecure_clone();
+
+    // Without a history, the short account lists still work
+    fixture.heartbeat(false).unwrap();
+    fixture.emergency_withdraw(1_000, false).unwrap();
+
+    let stranger = Keypair::new();
+    fixture
+        .svm
+        .airdrop(&stranger.pubkey(), 1_000_000_000)
+        .unwrap();
+    assert!(
+        fixture.create_history(&stranger).is_err(),
+        "Only the owner can enable the history"
+    );
+
+    fixture.create_history(&owner).unwrap();
+    assert!(fixture.heartbeat(false).is_err());
+    assert!(fixture.emergency_withdraw(1_000, false).is_err());
+    println!("✅ Heartbeat and withdrawal without the history rejected");
+}
+
+#[test]
+fn test_history_covers_queued_withdrawals_and_batch_heartbeats() {
+    println!("🧪 Testing the other owner paths that must be logged");
+
+    let mut fixture = HistoryTestFixture::new();
+    let owner = fixture.owner.insecure_clone();
+    fixture.create_history(&owner).unwrap();
+
+    fixture.queue_withdrawal(100_000).unwrap();
+    assert!(fixture.execute_withdrawal(false).is_err());
+    assert!(fixture.heartbeat_many(false).is_err());
+    println!("✅ Queued withdrawal and batch heartbeat without the history rejected");
+
+    let t1 = fixture.advance_clock(60);
+    fixture.execute_withdrawal(true).unwrap();
+    let t2 = fixture.advance_clock(60);
+    fixture.heartbeat_many(true).unwrap();
+
+    let (next, len, entries) = fixture.history();
+    assert_eq!((next, len), (2, 2));
+    assert_eq!(entries[0].timestamp, t1);
+    assert_eq!(entries[0].kind, KIND_QUEUED_WITHDRAWAL);
+    assert_eq!(entries[0].amount, 100_000);
+    assert_eq!(entries[1].timestamp, t2);
+    assert_eq!(entries[1].kind, KIND_HEARTBEAT);
+    assert_eq!(entries[1].signer, owner.pubkey());
+    println!("✅ Both recorded");
+}
+
+#[test]
+fn test_history_closes_with_vault() {
+    println!("🧪 Testing history cleanup when the vault is closed");
+
+    let mut fixture = HistoryTestFixture::new();
+    let owner = fixture.owner.insecure_clone();
+    fixture.create_history(&owner).unwrap();
+    fixture.emergency_withdraw(1_000_000, true).unwrap();
+
+    assert!(
+        fixture.close_vault(false).is_err(),
+        "Closing must not leave the history behind"
+    );
+
+    fixture.close_vault(true).unwrap();
+    assert!(fixture.svm.get_account(&fixture.history_pda).is_none());
+    println!("✅ History closed along with the vault");
+
+    // A vault re-created at the same address can start a fresh history
+    fixture.create_vault().unwrap();
+    fixture.create_history(&owner).unwrap();
+    let (_, len, _) = fixture.history();
+    assert_eq!(len, 0);
+    println!("✅ Re-created vault starts with an empty history");
+}
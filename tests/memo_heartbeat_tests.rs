@@ -0,0 +1,419 @@
+use litesvm::LiteSVM;
+use litesvm_token::{spl_token, CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_program, sysvar,
+    transaction::Transaction,
+};
+
+// VaultError codes
+const HISTORY_REQUIRED: u32 = 6040;
+const MEMO_HEARTBEAT_NOT_FOUND: u32 = 6055;
+const SWITCH_ALREADY_LINKED: u32 = 6023;
+
+/// Test fixture for heartbeats carried by an SPL Memo
+struct MemoHeartbeatTestFixture {
+    svm: LiteSVM,
+    program_id: Pubkey,
+    mint_authority: Keypair,
+    mint: Pubkey,
+    global_stats_pda: Pubkey,
+    mint_stats_pda: Pubkey,
+}
+
+/// PDAs for one (owner, beneficiary) vault
+struct VaultAccounts {
+    vault: Pubkey,
+    vault_token: Pubkey,
+    owner_registry: Pubkey,
+    beneficiary_index: Pubkey,
+}
+
+impl MemoHeartbeatTestFixture {
+    fn new() -> Self {
+        let mut svm = LiteSVM::new();
+
+        // Load the compiled program
+        let program_keypair =
+            read_keypair_file("target/deploy/cyber_vault_rs-keypair.json").unwrap();
+        let program_id = program_keypair.pubkey();
+        let program_bytes = include_bytes!("../target/deploy/cyber_vault_rs.so");
+        svm.add_program(program_id, program_bytes);
+
+        let mint_authority = Keypair::new();
+        svm.airdrop(&mint_authority.pubkey(), 10_000_000_000)
+            .unwrap();
+
+        let mint = CreateMint::new(&mut svm, &mint_authority)
+            .authority(&mint_authority.pubkey())
+            .decimals(6)
+            .send()
+            .unwrap();
+
+        let (global_stats_pda, _) = Pubkey::find_program_address(&[b"global_stats"], &program_id);
+        let (mint_stats_pda, _) =
+            Pubkey::find_program_address(&[b"mint_stats", mint.as_ref()], &program_id);
+
+        Self {
+            svm,
+            program_id,
+            mint_authority,
+            mint,
+            global_stats_pda,
+            mint_stats_pda,
+        }
+    }
+
+    fn funded_user(&mut self) -> (Keypair, Pubkey) {
+        let user = Keypair::new();
+        self.svm.airdrop(&user.pubkey(), 10_000_000_000).unwrap();
+        let ata = CreateAssociatedTokenAccount::new(&mut self.svm, &user, &self.mint)
+            .owner(&user.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(
+            &mut self.svm,
+            &self.mint_authority,
+            &self.mint,
+            &ata,
+            5_000_000,
+        )
+        .send()
+        .unwrap();
+        (user, ata)
+    }
+
+    fn accounts(&self, owner: &Pubkey, beneficiary: &Pubkey) -> VaultAccounts {
+        let (vault, _) = Pubkey::find_program_address(
+            &[
+                b"vault",
+                owner.as_ref(),
+                beneficiary.as_ref(),
+                self.mint.as_ref(),
+            ],
+            &self.program_id,
+        );
+        let (vault_token, _) =
+            Pubkey::find_program_address(&[b"vault_token", vault.as_ref()], &self.program_id);
+        let (owner_registry, _) =
+            Pubkey::find_program_address(&[b"owner_registry", owner.as_ref()], &self.program_id);
+        let (beneficiary_index, _) = Pubkey::find_program_address(
+            &[b"beneficiary_index", beneficiary.as_ref()],
+            &self.program_id,
+        );
+        VaultAccounts {
+            vault,
+            vault_token,
+            owner_registry,
+            beneficiary_index,
+        }
+    }
+
+    fn send(&mut self, instruction: Instruction, signer: &Keypair) -> Result<(), String> {
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        self.svm
+            .send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn create_vault(
+        &mut self,
+        owner: &Keypair,
+        owner_ata: &Pubkey,
+        beneficiary: &Pubkey,
+        amount: u64,
+    ) -> VaultAccounts {
+        let accounts = self.accounts(&owner.pubkey(), beneficiary);
+        let inactivity_period: i64 = 3600;
+
+        // create_vault discriminator: [29, 237, 247, 208, 193, 82, 54, 135]
+        let mut instruction_data = vec![29, 237, 247, 208, 193, 82, 54, 135];
+        instruction_data.extend_from_slice(&beneficiary.to_bytes());
+        instruction_data.extend_from_slice(&inactivity_period.to_le_bytes());
+        instruction_data.extend_from_slice(&amount.to_le_bytes());
+        instruction_data.push(0); // acknowledge_freeze_authority
+        let (mint_policy_pda, _) =
+            Pubkey::find_program_address(&[b"mint_policy"], &self.program_id);
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(owner.pubkey(), true),
+                AccountMeta::new(accounts.vault, false),
+                AccountMeta::new(accounts.vault_token, false),
+                AccountMeta::new(*owner_ata, false),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
+                AccountMeta::new(accounts.owner_registry, false),
+                AccountMeta::new(accounts.beneficiary_index, false),
+                AccountMeta::new(self.global_stats_pda, false),
+                AccountMeta::new(self.mint_stats_pda, false),
+                AccountMeta::new_readonly(mint_policy_pda, false),
+            ],
+            data: instruction_data,
+        };
+
+        self.send(instruction, owner).unwrap();
+        accounts
+    }
+
+    /// SPL Memo v3 instruction listing `signer` as a signing account
+    fn memo(&self, text: &str, signer: &Pubkey) -> Instruction {
+        Instruction {
+            program_id: Pubkey::from_str_const("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"),
+            accounts: vec![AccountMeta::new_readonly(*signer, true)],
+            data: text.as_bytes().to_vec(),
+        }
+    }
+
+    fn memo_heartbeat(&self, vault: &Pubkey) -> Instruction {
+        self.memo_heartbeat_with_history(vault, None)
+    }
+
+    fn memo_heartbeat_with_history(&self, vault: &Pubkey, history: Option<Pubkey>) -> Instruction {
+        let mut accounts = vec![
+            AccountMeta::new(*vault, false),
+            AccountMeta::new_readonly(sysvar::instructions::id(), false),
+        ];
+        if let Some(history) = history {
+            accounts.push(AccountMeta::new(history, false));
+        }
+
+        // memo_heartbeat discriminator: [19, 218, 128, 78, 170, 166, 100, 179]
+        Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: vec![19, 218, 128, 78, 170, 166, 100, 179],
+        }
+    }
+
+    fn create_history(&mut self, vault: &Pubkey, owner: &Keypair) -> Pubkey {
+        let (history, _) =
+            Pubkey::find_program_address(&[b"history", vault.as_ref()], &self.program_id);
+
+        // create_history discriminator: [17, 80, 83, 78, 168, 45, 161, 35]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(owner.pubkey(), true),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(history, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: vec![17, 80, 83, 78, 168, 45, 161, 35],
+        };
+        self.send(instruction, owner).unwrap();
+
+        history
+    }
+
+    /// Creates the owner's master switch and links `vault` to it
+    fn link_switch(&mut self, vault: &Pubkey, owner: &Keypair) {
+        let (switch, _) =
+            Pubkey::find_program_address(&[b"switch", owner.pubkey().as_ref()], &self.program_id);
+
+        // create_switch discriminator: [120, 11, 193, 114, 80, 234, 99, 128]
+        let mut instruction_data = vec![120, 11, 193, 114, 80, 234, 99, 128];
+        instruction_data.extend_from_slice(&3600i64.to_le_bytes());
+        let create = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(owner.pubkey(), true),
+                AccountMeta::new(switch, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: instruction_data,
+        };
+
+        // link_switch discriminator: [136, 247, 62, 224, 223, 183, 48, 117]
+        let link = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(owner.pubkey(), true),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(switch, false),
+            ],
+            data: vec![136, 247, 62, 224, 223, 183, 48, 117],
+        };
+        self.send_all(&[create, link], &[owner]).unwrap();
+    }
+
+    fn send_all(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<(), String> {
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&signers[0].pubkey()),
+            signers,
+            self.svm.latest_blockhash(),
+        );
+        self.svm
+            .send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn advance_clock(&mut self, seconds: i64) {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp += seconds;
+        self.svm.set_sysvar(&clock);
+    }
+
+    fn last_heartbeat(&self, vault: &Pubkey) -> i64 {
+        let data = self.svm.get_account(vault).unwrap().data;
+        i64::from_le_bytes(data[208..216].try_into().unwrap())
+    }
+}
+
+fn assert_vault_error(result: Result<(), String>, code: u32) {
+    let err = result.expect_err("instruction should have been rejected");
+    assert!(
+        err.contains(&format!("Custom({})", code)),
+        "expected error {}, got {}",
+        code,
+        err
+    );
+}
+
+#[test]
+fn test_memo_heartbeat_resets_timer() {
+    println!("🧪 Testing a heartbeat piggybacked on an owner-signed memo");
+
+    let mut fixture = MemoHeartbeatTestFixture::new();
+    let (owner, owner_ata) = fixture.funded_user();
+    let beneficiary = Pubkey::new_unique();
+    let vault = fixture
+        .create_vault(&owner, &owner_ata, &beneficiary, 1_000_000)
+        .vault;
+    let created_at = fixture.last_heartbeat(&vault);
+
+    fixture.advance_clock(1800);
+    let memo = fixture.memo(&format!("cyber-vault:heartbeat:{}", vault), &owner.pubkey());
+    let heartbeat = fixture.memo_heartbeat(&vault);
+    fixture.send_all(&[memo, heartbeat], &[&owner]).unwrap();
+    assert_eq!(fixture.last_heartbeat(&vault), created_at + 1800);
+    println!("✅ Memo before the heartbeat instruction accepted");
+
+    // The memo may also follow the heartbeat, and a relayer may pay the fee
+    let relayer = Keypair::new();
+    fixture
+        .svm
+        .airdrop(&relayer.pubkey(), 1_000_000_000)
+        .unwrap();
+    fixture.advance_clock(1800);
+    let memo = fixture.memo(&format!("cyber-vault:heartbeat:{}", vault), &owner.pubkey());
+    let heartbeat = fixture.memo_heartbeat(&vault);
+    fixture
+        .send_all(&[heartbeat, memo], &[&relayer, &owner])
+        .unwrap();
+    assert_eq!(fixture.last_heartbeat(&vault), created_at + 3600);
+    println!("✅ Memo after the heartbeat instruction accepted");
+}
+
+#[test]
+fn test_memo_heartbeat_requires_owner_memo() {
+    println!("🧪 Testing memo heartbeats without a valid owner memo");
+
+    let mut fixture = MemoHeartbeatTestFixture::new();
+    let (owner, owner_ata) = fixture.funded_user();
+    let (stranger, _) = fixture.funded_user();
+    let beneficiary = Pubkey::new_unique();
+    let vault = fixture
+        .create_vault(&owner, &owner_ata, &beneficiary, 1_000_000)
+        .vault;
+    let text = format!("cyber-vault:heartbeat:{}", vault);
+
+    let heartbeat = fixture.memo_heartbeat(&vault);
+    assert_vault_error(
+        fixture.send_all(&[heartbeat], &[&stranger]),
+        MEMO_HEARTBEAT_NOT_FOUND,
+    );
+    println!("✅ No memo rejected");
+
+    let memo = fixture.memo(&text, &stranger.pubkey());
+    let heartbeat = fixture.memo_heartbeat(&vault);
+    assert_vault_error(
+        fixture.send_all(&[memo, heartbeat], &[&stranger]),
+        MEMO_HEARTBEAT_NOT_FOUND,
+    );
+    println!("✅ Memo signed by someone else rejected");
+
+    let memo = fixture.memo(
+        &format!("cyber-vault:heartbeat:{}", Pubkey::new_unique()),
+        &owner.pubkey(),
+    );
+    let heartbeat = fixture.memo_heartbeat(&vault);
+    assert_vault_error(
+        fixture.send_all(&[memo, heartbeat], &[&owner]),
+        MEMO_HEARTBEAT_NOT_FOUND,
+    );
+    println!("✅ Memo naming another vault rejected");
+}
+
+#[test]
+fn test_memo_heartbeat_is_recorded_in_history() {
+    println!("🧪 Testing that memo heartbeats reach the vault history");
+
+    let mut fixture = MemoHeartbeatTestFixture::new();
+    let (owner, owner_ata) = fixture.funded_user();
+    let beneficiary = Pubkey::new_unique();
+    let vault = fixture
+        .create_vault(&owner, &owner_ata, &beneficiary, 1_000_000)
+        .vault;
+    let history = fixture.create_history(&vault, &owner);
+    let text = format!("cyber-vault:heartbeat:{}", vault);
+
+    let memo = fixture.memo(&text, &owner.pubkey());
+    let heartbeat = fixture.memo_heartbeat(&vault);
+    assert_vault_error(
+        fixture.send_all(&[memo, heartbeat], &[&owner]),
+        HISTORY_REQUIRED,
+    );
+    println!("✅ Memo heartbeat cannot skip the history");
+
+    let memo = fixture.memo(&text, &owner.pubkey());
+    let heartbeat = fixture.memo_heartbeat_with_history(&vault, Some(history));
+    fixture.send_all(&[memo, heartbeat], &[&owner]).unwrap();
+
+    // HeartbeatHistory: disc(8) + vault(32) + next(4) + len(4), then entries
+    let data = fixture.svm.get_account(&history).unwrap().data;
+    assert_eq!(u32::from_le_bytes(data[44..48].try_into().unwrap()), 1);
+    assert_eq!(&data[72..104], owner.pubkey().as_ref());
+    println!("✅ Entry recorded with the owner as signer");
+}
+
+#[test]
+fn test_memo_heartbeat_rejected_on_linked_vault() {
+    println!("🧪 Testing a memo heartbeat on a vault linked to a master switch");
+
+    let mut fixture = MemoHeartbeatTestFixture::new();
+    let (owner, owner_ata) = fixture.funded_user();
+    let beneficiary = Pubkey::new_unique();
+    let vault = fixture
+        .create_vault(&owner, &owner_ata, &beneficiary, 1_000_000)
+        .vault;
+    fixture.link_switch(&vault, &owner);
+
+    let memo = fixture.memo(&format!("cyber-vault:heartbeat:{}", vault), &owner.pubkey());
+    let heartbeat = fixture.memo_heartbeat(&vault);
+    assert_vault_error(
+        fixture.send_all(&[memo, heartbeat], &[&owner]),
+        SWITCH_ALREADY_LINKED,
+    );
+    println!("✅ Linked vault takes its heartbeats from the switch only");
+}
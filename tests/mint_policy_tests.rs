@@ -0,0 +1,267 @@
+use litesvm::LiteSVM;
+use litesvm_token::{spl_token, CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    account::Account,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+
+// VaultError codes
+const MINT_HAS_FREEZE_AUTHORITY: u32 = 6041;
+const MINT_NOT_ALLOWED: u32 = 6042;
+
+/// Test fixture for mint policy checks at vault creation
+struct MintPolicyTestFixture {
+    svm: LiteSVM,
+    program_id: Pubkey,
+    owner: Keypair,
+    mint_policy_pda: Pubkey,
+}
+
+impl MintPolicyTestFixture {
+    fn new() -> Self {
+        let mut svm = LiteSVM::new();
+
+        // Load the compiled program
+        let program_keypair =
+            read_keypair_file("target/deploy/cyber_vault_rs-keypair.json").unwrap();
+        let program_id = program_keypair.pubkey();
+        let program_bytes = include_bytes!("../target/deploy/cyber_vault_rs.so");
+        svm.add_program(program_id, program_bytes);
+
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), 10_000_000_000).unwrap();
+
+        let (mint_policy_pda, _) = Pubkey::find_program_address(&[b"mint_policy"], &program_id);
+
+        Self {
+            svm,
+            program_id,
+            owner,
+            mint_policy_pda,
+        }
+    }
+
+    /// Creates a mint and funds the owner's token account; returns (mint, owner_ata)
+    fn funded_mint(&mut self, freeze_authority: Option<&Pubkey>) -> (Pubkey, Pubkey) {
+        let mint_authority = self.owner.pubkey();
+        let mut create_mint = CreateMint::new(&mut self.svm, &self.owner)
+            .authority(&mint_authority)
+            .decimals(6);
+        if let Some(freeze_authority) = freeze_authority {
+            create_mint = create_mint.freeze_authority(freeze_authority);
+        }
+        let mint = create_mint.send().unwrap();
+
+        let owner_ata = CreateAssociatedTokenAccount::new(&mut self.svm, &self.owner, &mint)
+            .owner(&self.owner.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(&mut self.svm, &self.owner, &mint, &owner_ata, 1_000_000)
+            .send()
+            .unwrap();
+        (mint, owner_ata)
+    }
+
+    /// Writes the MintPolicy account the upgrade authority would create with set_mint_policy
+    fn install_policy(&mut self, allowlist_enabled: bool, mints: &[Pubkey]) {
+        let (_, bump) = Pubkey::find_program_address(&[b"mint_policy"], &self.program_id);
+
+        // MintPolicy discriminator: [191, 78, 230, 47, 121, 49, 66, 222]
+        let mut data = vec![191, 78, 230, 47, 121, 49, 66, 222];
+        data.push(allowlist_enabled as u8);
+        data.extend_from_slice(&(mints.len() as u32).to_le_bytes());
+        for mint in mints {
+            data.extend_from_slice(mint.as_ref());
+        }
+        data.push(bump);
+
+        self.svm
+            .set_account(
+                self.mint_policy_pda,
+                Account {
+                    lamports: 1_000_000_000,
+                    data,
+                    owner: self.program_id,
+                    executable: false,
+                    rent_epoch: 0,
+                },
+            )
+            .unwrap();
+    }
+
+    fn send(&mut self, instruction: Instruction, signer: &Keypair) -> Result<(), String> {
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        self.svm
+            .send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn create_vault(
+        &mut self,
+        mint: &Pubkey,
+        owner_ata: &Pubkey,
+        acknowledge_freeze_authority: bool,
+    ) -> Result<(), String> {
+        let owner = self.owner.insecure_clone();
+        let beneficiary = Pubkey::new_unique();
+        let (vault_pda, _) = Pubkey::find_program_address(
+            &[
+                b"vault",
+                owner.pubkey().as_ref(),
+                beneficiary.as_ref(),
+                mint.as_ref(),
+            ],
+            &self.program_id,
+        );
+        let (vault_token_pda, _) =
+            Pubkey::find_program_address(&[b"vault_token", vault_pda.as_ref()], &self.program_id);
+        let (owner_registry_pda, _) = Pubkey::find_program_address(
+            &[b"owner_registry", owner.pubkey().as_ref()],
+            &self.program_id,
+        );
+        let (beneficiary_index_pda, _) = Pubkey::find_program_address(
+            &[b"beneficiary_index", beneficiary.as_ref()],
+            &self.program_id,
+        );
+        let (global_stats_pda, _) =
+            Pubkey::find_program_address(&[b"global_stats"], &self.program_id);
+        let (mint_stats_pda, _) =
+            Pubkey::find_program_address(&[b"mint_stats", mint.as_ref()], &self.program_id);
+
+        // create_vault discriminator: [29, 237, 247, 208, 193, 82, 54, 135]
+        let mut instruction_data = vec![29, 237, 247, 208, 193, 82, 54, 135];
+        instruction_data.extend_from_slice(&beneficiary.to_bytes());
+        instruction_data.extend_from_slice(&3600i64.to_le_bytes());
+        instruction_data.extend_from_slice(&1_000_000u64.to_le_bytes());
+        instruction_data.push(acknowledge_freeze_authority as u8);
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(owner.pubkey(), true),
+                AccountMeta::new(vault_pda, false),
+                AccountMeta::new(vault_token_pda, false),
+                AccountMeta::new(*owner_ata, false),
+                AccountMeta::new_readonly(*mint, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
+                AccountMeta::new(owner_registry_pda, false),
+                AccountMeta::new(beneficiary_index_pda, false),
+                AccountMeta::new(global_stats_pda, false),
+                AccountMeta::new(mint_stats_pda, false),
+                AccountMeta::new_readonly(self.mint_policy_pda, false),
+            ],
+            data: instruction_data,
+        };
+
+        self.send(instruction, &owner)
+    }
+}
+
+fn assert_vault_error(result: Result<(), String>, code: u32) {
+    let err = result.expect_err("create_vault should have been rejected");
+    assert!(
+        err.contains(&format!("Custom({})", code)),
+        "expected error {}, got {}",
+        code,
+        err
+    );
+}
+
+#[test]
+fn test_freeze_authority_requires_acknowledgement() {
+    println!("🧪 Testing a mint with a freeze authority");
+
+    let mut fixture = MintPolicyTestFixture::new();
+    let freezer = Pubkey::new_unique();
+    let (mint, owner_ata) = fixture.funded_mint(Some(&freezer));
+
+    assert_vault_error(
+        fixture.create_vault(&mint, &owner_ata, false),
+        MINT_HAS_FREEZE_AUTHORITY,
+    );
+    println!("✅ Unacknowledged freeze authority rejected");
+
+    fixture.create_vault(&mint, &owner_ata, true).unwrap();
+    println!("✅ Acknowledged freeze authority accepted");
+}
+
+#[test]
+fn test_allowlist_rejects_unlisted_mint() {
+    println!("🧪 Testing the admin mint allowlist");
+
+    let mut fixture = MintPolicyTestFixture::new();
+    let (listed, listed_ata) = fixture.funded_mint(None);
+    let (unlisted, unlisted_ata) = fixture.funded_mint(None);
+
+    // No policy account yet: any mint is accepted
+    fixture
+        .create_vault(&unlisted, &unlisted_ata, false)
+        .unwrap();
+
+    fixture.install_policy(true, &[listed]);
+    assert_vault_error(
+        fixture.create_vault(&unlisted, &unlisted_ata, false),
+        MINT_NOT_ALLOWED,
+    );
+    fixture.create_vault(&listed, &listed_ata, false).unwrap();
+    println!("✅ Only allowlisted mints accepted while the allowlist is on");
+
+    fixture.install_policy(false, &[listed]);
+    fixture
+        .create_vault(&unlisted, &unlisted_ata, false)
+        .unwrap();
+    println!("✅ Disabled allowlist accepts any mint");
+}
+
+#[test]
+fn test_set_mint_policy_requires_upgrade_authority() {
+    println!("🧪 Testing set_mint_policy by a non-admin");
+
+    let mut fixture = MintPolicyTestFixture::new();
+    let stranger = Keypair::new();
+    fixture
+        .svm
+        .airdrop(&stranger.pubkey(), 1_000_000_000)
+        .unwrap();
+
+    // set_mint_policy discriminator: [12, 208, 252, 52, 166, 250, 137, 169]
+    let mut instruction_data = vec![12, 208, 252, 52, 166, 250, 137, 169];
+    instruction_data.push(1);
+    instruction_data.extend_from_slice(&0u32.to_le_bytes());
+
+    let (program_data, _) = Pubkey::find_program_address(
+        &[fixture.program_id.as_ref()],
+        &solana_sdk::bpf_loader_upgradeable::id(),
+    );
+    let instruction = Instruction {
+        program_id: fixture.program_id,
+        accounts: vec![
+            AccountMeta::new(stranger.pubkey(), true),
+            AccountMeta::new(fixture.mint_policy_pda, false),
+            AccountMeta::new_readonly(fixture.program_id, false),
+            AccountMeta::new_readonly(program_data, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: instruction_data,
+    };
+
+    assert!(
+        fixture.send(instruction, &stranger).is_err(),
+        "Only the upgrade authority may set the mint policy"
+    );
+    assert!(fixture.svm.get_account(&fixture.mint_policy_pda).is_none());
+    println!("✅ Non-admin policy change rejected");
+}
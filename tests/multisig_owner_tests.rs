@@ -0,0 +1,344 @@
+use litesvm::LiteSVM;
+use litesvm_token::{get_spl_account, spl_token, CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+
+// VaultError codes
+const UNAUTHORIZED_ACCESS: u32 = 6000;
+
+/// Test fixture for a vault owned by a multisig's signer PDA
+struct MultisigOwnerTestFixture {
+    svm: LiteSVM,
+    program_id: Pubkey,
+    multisig_program_id: Pubkey,
+    members: Vec<Keypair>,
+    multisig: Pubkey,
+    signer_pda: Pubkey,
+    signer_ata: Pubkey,
+    mint: Pubkey,
+    beneficiary: Pubkey,
+}
+
+impl MultisigOwnerTestFixture {
+    fn new() -> Self {
+        let mut svm = LiteSVM::new();
+
+        // Load the vault program and the test multisig
+        let program_keypair =
+            read_keypair_file("target/deploy/cyber_vault_rs-keypair.json").unwrap();
+        let program_id = program_keypair.pubkey();
+        let program_bytes = include_bytes!("../target/deploy/cyber_vault_rs.so");
+        svm.add_program(program_id, program_bytes);
+
+        let multisig_keypair =
+            read_keypair_file("target/deploy/minimal_multisig-keypair.json").unwrap();
+        let multisig_program_id = multisig_keypair.pubkey();
+        let multisig_bytes = include_bytes!("../target/deploy/minimal_multisig.so");
+        svm.add_program(multisig_program_id, multisig_bytes);
+
+        let members: Vec<Keypair> = (0..3).map(|_| Keypair::new()).collect();
+        for member in &members {
+            svm.airdrop(&member.pubkey(), 10_000_000_000).unwrap();
+        }
+
+        // 2-of-3 multisig
+        let multisig = Keypair::new();
+        let (signer_pda, _) = Pubkey::find_program_address(
+            &[b"signer", multisig.pubkey().as_ref()],
+            &multisig_program_id,
+        );
+
+        // create_multisig discriminator: [148, 146, 240, 10, 226, 215, 167, 174]
+        let mut instruction_data = vec![148, 146, 240, 10, 226, 215, 167, 174];
+        instruction_data.extend_from_slice(&(members.len() as u32).to_le_bytes());
+        for member in &members {
+            instruction_data.extend_from_slice(member.pubkey().as_ref());
+        }
+        instruction_data.push(2);
+
+        let instruction = Instruction {
+            program_id: multisig_program_id,
+            accounts: vec![
+                AccountMeta::new(members[0].pubkey(), true),
+                AccountMeta::new(multisig.pubkey(), true),
+                AccountMeta::new_readonly(signer_pda, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: instruction_data,
+        };
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&members[0].pubkey()),
+            &[&members[0], &multisig],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+
+        // The signer PDA holds no data, so it can pay rent like a wallet
+        svm.airdrop(&signer_pda, 10_000_000_000).unwrap();
+
+        let mint = CreateMint::new(&mut svm, &members[0])
+            .authority(&members[0].pubkey())
+            .decimals(6)
+            .send()
+            .unwrap();
+        let signer_ata = CreateAssociatedTokenAccount::new(&mut svm, &members[0], &mint)
+            .owner(&signer_pda)
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &members[0], &mint, &signer_ata, 5_000_000)
+            .send()
+            .unwrap();
+
+        Self {
+            svm,
+            program_id,
+            multisig_program_id,
+            members,
+            multisig: multisig.pubkey(),
+            signer_pda,
+            signer_ata,
+            mint,
+            beneficiary: Pubkey::new_unique(),
+        }
+    }
+
+    fn vault_pda(&self) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                b"vault",
+                self.signer_pda.as_ref(),
+                self.beneficiary.as_ref(),
+                self.mint.as_ref(),
+            ],
+            &self.program_id,
+        )
+        .0
+    }
+
+    fn vault_token_pda(&self) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"vault_token", self.vault_pda().as_ref()],
+            &self.program_id,
+        )
+        .0
+    }
+
+    fn send(&mut self, instruction: Instruction, signers: &[&Keypair]) -> Result<(), String> {
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signers[0].pubkey()),
+            signers,
+            self.svm.latest_blockhash(),
+        );
+        self.svm
+            .send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    /// Relays a vault instruction through the multisig, signed by `approvers`
+    fn execute(
+        &mut self,
+        vault_instruction: Instruction,
+        approvers: &[&Keypair],
+    ) -> Result<(), String> {
+        // execute discriminator: [130, 221, 242, 154, 13, 193, 189, 29]
+        let mut instruction_data = vec![130, 221, 242, 154, 13, 193, 189, 29];
+        instruction_data.push(approvers.len() as u8);
+        instruction_data.extend_from_slice(&(vault_instruction.data.len() as u32).to_le_bytes());
+        instruction_data.extend_from_slice(&vault_instruction.data);
+
+        let mut accounts = vec![
+            AccountMeta::new_readonly(self.multisig, false),
+            AccountMeta::new(self.signer_pda, false),
+            AccountMeta::new_readonly(vault_instruction.program_id, false),
+        ];
+        for approver in approvers {
+            accounts.push(AccountMeta::new_readonly(approver.pubkey(), true));
+        }
+        // Only the multisig program can sign for its PDA
+        for meta in vault_instruction.accounts {
+            accounts.push(AccountMeta {
+                is_signer: meta.is_signer && meta.pubkey != self.signer_pda,
+                ..meta
+            });
+        }
+
+        let instruction = Instruction {
+            program_id: self.multisig_program_id,
+            accounts,
+            data: instruction_data,
+        };
+
+        self.send(instruction, approvers)
+    }
+
+    fn create_vault_instruction(&self, amount: u64) -> Instruction {
+        let vault_pda = self.vault_pda();
+        let (owner_registry_pda, _) = Pubkey::find_program_address(
+            &[b"owner_registry", self.signer_pda.as_ref()],
+            &self.program_id,
+        );
+        let (beneficiary_index_pda, _) = Pubkey::find_program_address(
+            &[b"beneficiary_index", self.beneficiary.as_ref()],
+            &self.program_id,
+        );
+        let (global_stats_pda, _) =
+            Pubkey::find_program_address(&[b"global_stats"], &self.program_id);
+        let (mint_policy_pda, _) =
+            Pubkey::find_program_address(&[b"mint_policy"], &self.program_id);
+
+        // create_vault discriminator: [29, 237, 247, 208, 193, 82, 54, 135]
+        let mut instruction_data = vec![29, 237, 247, 208, 193, 82, 54, 135];
+        instruction_data.extend_from_slice(&self.beneficiary.to_bytes());
+        instruction_data.extend_from_slice(&3600i64.to_le_bytes());
+        instruction_data.extend_from_slice(&amount.to_le_bytes());
+        instruction_data.push(0); // acknowledge_freeze_authority
+
+        Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.signer_pda, true),
+                AccountMeta::new(vault_pda, false),
+                AccountMeta::new(self.vault_token_pda(), false),
+                AccountMeta::new(self.signer_ata, false),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
+                AccountMeta::new(owner_registry_pda, false),
+                AccountMeta::new(beneficiary_index_pda, false),
+                AccountMeta::new(global_stats_pda, false),
+                AccountMeta::new(self.mint_stats_pda(), false),
+                AccountMeta::new_readonly(mint_policy_pda, false),
+            ],
+            data: instruction_data,
+        }
+    }
+
+    fn heartbeat_instruction(&self, signer: &Pubkey) -> Instruction {
+        // heartbeat discriminator: [202, 104, 56, 6, 240, 170, 63, 134]
+        Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.vault_pda(), false),
+                AccountMeta::new_readonly(*signer, true),
+            ],
+            data: vec![202, 104, 56, 6, 240, 170, 63, 134],
+        }
+    }
+
+    fn emergency_withdraw_instruction(&self, amount: u64) -> Instruction {
+        // emergency_withdraw discriminator: [239, 45, 203, 64, 150, 73, 218, 92]
+        let mut instruction_data = vec![239, 45, 203, 64, 150, 73, 218, 92];
+        instruction_data.extend_from_slice(&amount.to_le_bytes());
+
+        Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.signer_pda, true),
+                AccountMeta::new(self.vault_pda(), false),
+                AccountMeta::new(self.signer_ata, false),
+                AccountMeta::new(self.vault_token_pda(), false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new(self.mint_stats_pda(), false),
+            ],
+            data: instruction_data,
+        }
+    }
+
+    fn mint_stats_pda(&self) -> Pubkey {
+        Pubkey::find_program_address(&[b"mint_stats", self.mint.as_ref()], &self.program_id).0
+    }
+
+    fn advance_clock(&mut self, seconds: i64) {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp += seconds;
+        self.svm.set_sysvar(&clock);
+    }
+
+    /// Returns (owner, last_heartbeat) from the vault account
+    fn vault_owner_and_heartbeat(&self) -> (Pubkey, i64) {
+        let data = self.svm.get_account(&self.vault_pda()).unwrap().data;
+        (
+            Pubkey::try_from(&data[8..40]).unwrap(),
+            i64::from_le_bytes(data[208..216].try_into().unwrap()),
+        )
+    }
+
+    fn token_balance(&self, account: &Pubkey) -> u64 {
+        get_spl_account::<spl_token::state::Account>(&self.svm, account)
+            .unwrap()
+            .amount
+    }
+}
+
+#[test]
+fn test_multisig_drives_vault_through_cpi() {
+    println!("🧪 Testing a vault owned by a 2-of-3 multisig");
+
+    let mut fixture = MultisigOwnerTestFixture::new();
+    let alice = fixture.members[0].insecure_clone();
+    let bob = fixture.members[1].insecure_clone();
+
+    let create = fixture.create_vault_instruction(1_000_000);
+    fixture.execute(create, &[&alice, &bob]).unwrap();
+
+    let (owner, created_at) = fixture.vault_owner_and_heartbeat();
+    assert_eq!(owner, fixture.signer_pda);
+    assert_eq!(fixture.token_balance(&fixture.vault_token_pda()), 1_000_000);
+    println!("✅ Vault created and funded by the multisig's PDA");
+
+    fixture.advance_clock(1800);
+    let heartbeat = fixture.heartbeat_instruction(&fixture.signer_pda);
+    fixture.execute(heartbeat, &[&alice, &bob]).unwrap();
+    let (_, last_heartbeat) = fixture.vault_owner_and_heartbeat();
+    assert_eq!(last_heartbeat, created_at + 1800);
+    println!("✅ Heartbeat relayed through CPI");
+
+    let withdraw = fixture.emergency_withdraw_instruction(400_000);
+    fixture.execute(withdraw, &[&alice, &bob]).unwrap();
+    assert_eq!(fixture.token_balance(&fixture.signer_ata), 4_400_000);
+    assert_eq!(fixture.token_balance(&fixture.vault_token_pda()), 600_000);
+    println!("✅ Emergency withdrawal paid back to the multisig");
+}
+
+#[test]
+fn test_members_cannot_act_alone() {
+    println!("🧪 Testing that members cannot bypass the threshold");
+
+    let mut fixture = MultisigOwnerTestFixture::new();
+    let alice = fixture.members[0].insecure_clone();
+    let bob = fixture.members[1].insecure_clone();
+    let outsider = Keypair::new();
+    fixture
+        .svm
+        .airdrop(&outsider.pubkey(), 1_000_000_000)
+        .unwrap();
+
+    let create = fixture.create_vault_instruction(1_000_000);
+    fixture.execute(create, &[&alice, &bob]).unwrap();
+
+    let withdraw = fixture.emergency_withdraw_instruction(1_000_000);
+    assert!(fixture.execute(withdraw.clone(), &[&alice]).is_err());
+    assert!(fixture.execute(withdraw, &[&alice, &outsider]).is_err());
+    assert_eq!(fixture.token_balance(&fixture.vault_token_pda()), 1_000_000);
+    println!("✅ One member, or one member plus an outsider, cannot withdraw");
+
+    let heartbeat = fixture.heartbeat_instruction(&alice.pubkey());
+    let err = fixture.send(heartbeat, &[&alice]).unwrap_err();
+    assert!(
+        err.contains(&format!("Custom({})", UNAUTHORIZED_ACCESS)),
+        "expected UnauthorizedAccess, got {}",
+        err
+    );
+    println!("✅ A member's own signature is not the owner's");
+}
@@ -0,0 +1,450 @@
+use litesvm::LiteSVM;
+use litesvm_token::{get_spl_account, spl_token, CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+
+// VaultError codes
+const UNAUTHORIZED_ACCESS: u32 = 6000;
+const VAULT_NOT_EXPIRED: u32 = 6001;
+const INVALID_BENEFICIARY_VAULT: u32 = 6044;
+
+/// Test fixture for vaults whose beneficiary is another vault
+struct NestedVaultTestFixture {
+    svm: LiteSVM,
+    program_id: Pubkey,
+    mint_authority: Keypair,
+    mint: Pubkey,
+    global_stats_pda: Pubkey,
+    mint_stats_pda: Pubkey,
+}
+
+/// PDAs for one (owner, beneficiary) vault
+struct VaultAccounts {
+    vault: Pubkey,
+    vault_token: Pubkey,
+    owner_registry: Pubkey,
+    beneficiary_index: Pubkey,
+}
+
+impl NestedVaultTestFixture {
+    fn new() -> Self {
+        let mut svm = LiteSVM::new();
+
+        // Load the compiled program
+        let program_keypair =
+            read_keypair_file("target/deploy/cyber_vault_rs-keypair.json").unwrap();
+        let program_id = program_keypair.pubkey();
+        let program_bytes = include_bytes!("../target/deploy/cyber_vault_rs.so");
+        svm.add_program(program_id, program_bytes);
+
+        let mint_authority = Keypair::new();
+        svm.airdrop(&mint_authority.pubkey(), 10_000_000_000)
+            .unwrap();
+
+        let mint = CreateMint::new(&mut svm, &mint_authority)
+            .authority(&mint_authority.pubkey())
+            .decimals(6)
+            .send()
+            .unwrap();
+
+        let (global_stats_pda, _) = Pubkey::find_program_address(&[b"global_stats"], &program_id);
+        let (mint_stats_pda, _) =
+            Pubkey::find_program_address(&[b"mint_stats", mint.as_ref()], &program_id);
+
+        Self {
+            svm,
+            program_id,
+            mint_authority,
+            mint,
+            global_stats_pda,
+            mint_stats_pda,
+        }
+    }
+
+    fn funded_user(&mut self) -> (Keypair, Pubkey) {
+        let user = Keypair::new();
+        self.svm.airdrop(&user.pubkey(), 10_000_000_000).unwrap();
+        let ata = CreateAssociatedTokenAccount::new(&mut self.svm, &user, &self.mint)
+            .owner(&user.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(
+            &mut self.svm,
+            &self.mint_authority,
+            &self.mint,
+            &ata,
+            5_000_000,
+        )
+        .send()
+        .unwrap();
+        (user, ata)
+    }
+
+    fn accounts(&self, owner: &Pubkey, beneficiary: &Pubkey) -> VaultAccounts {
+        let (vault, _) = Pubkey::find_program_address(
+            &[
+                b"vault",
+                owner.as_ref(),
+                beneficiary.as_ref(),
+                self.mint.as_ref(),
+            ],
+            &self.program_id,
+        );
+        let (vault_token, _) =
+            Pubkey::find_program_address(&[b"vault_token", vault.as_ref()], &self.program_id);
+        let (owner_registry, _) =
+            Pubkey::find_program_address(&[b"owner_registry", owner.as_ref()], &self.program_id);
+        let (beneficiary_index, _) = Pubkey::find_program_address(
+            &[b"beneficiary_index", beneficiary.as_ref()],
+            &self.program_id,
+        );
+        VaultAccounts {
+            vault,
+            vault_token,
+            owner_registry,
+            beneficiary_index,
+        }
+    }
+
+    fn send(&mut self, instruction: Instruction, signer: &Keypair) -> Result<(), String> {
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        self.svm
+            .send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn create_vault(
+        &mut self,
+        owner: &Keypair,
+        owner_ata: &Pubkey,
+        beneficiary: &Pubkey,
+        amount: u64,
+    ) -> VaultAccounts {
+        let accounts = self.accounts(&owner.pubkey(), beneficiary);
+        let inactivity_period: i64 = 3600;
+
+        // create_vault discriminator: [29, 237, 247, 208, 193, 82, 54, 135]
+        let mut instruction_data = vec![29, 237, 247, 208, 193, 82, 54, 135];
+        instruction_data.extend_from_slice(&beneficiary.to_bytes());
+        instruction_data.extend_from_slice(&inactivity_period.to_le_bytes());
+        instruction_data.extend_from_slice(&amount.to_le_bytes());
+        instruction_data.push(0); // acknowledge_freeze_authority
+        let (mint_policy_pda, _) =
+            Pubkey::find_program_address(&[b"mint_policy"], &self.program_id);
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(owner.pubkey(), true),
+                AccountMeta::new(accounts.vault, false),
+                AccountMeta::new(accounts.vault_token, false),
+                AccountMeta::new(*owner_ata, false),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
+                AccountMeta::new(accounts.owner_registry, false),
+                AccountMeta::new(accounts.beneficiary_index, false),
+                AccountMeta::new(self.global_stats_pda, false),
+                AccountMeta::new(self.mint_stats_pda, false),
+                AccountMeta::new_readonly(mint_policy_pda, false),
+            ],
+            data: instruction_data,
+        };
+
+        self.send(instruction, owner).unwrap();
+        accounts
+    }
+
+    fn heartbeat(&mut self, accounts: &VaultAccounts, owner: &Keypair) {
+        // heartbeat discriminator: [202, 104, 56, 6, 240, 170, 63, 134]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(accounts.vault, false),
+                AccountMeta::new_readonly(owner.pubkey(), true),
+            ],
+            data: vec![202, 104, 56, 6, 240, 170, 63, 134],
+        };
+
+        self.send(instruction, owner).unwrap();
+    }
+
+    fn claim(&mut self, accounts: &VaultAccounts, beneficiary: &Keypair) -> Result<(), String> {
+        let beneficiary_ata = get_associated_token_address(&beneficiary.pubkey(), &self.mint);
+
+        // claim discriminator: [62, 198, 214, 193, 213, 159, 108, 210]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(accounts.vault, false),
+                AccountMeta::new(accounts.vault_token, false),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new(beneficiary_ata, false),
+                AccountMeta::new(beneficiary.pubkey(), true),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new(accounts.owner_registry, false),
+                AccountMeta::new(accounts.beneficiary_index, false),
+                AccountMeta::new(self.global_stats_pda, false),
+                AccountMeta::new(self.mint_stats_pda, false),
+            ],
+            data: vec![62, 198, 214, 193, 213, 159, 108, 210],
+        };
+
+        self.send(instruction, beneficiary)
+    }
+
+    /// Permissionless claim of `accounts` into the vault at `target`
+    fn claim_to_vault(
+        &mut self,
+        accounts: &VaultAccounts,
+        target: &VaultAccounts,
+        caller: &Keypair,
+    ) -> Result<(), String> {
+        // claim_to_vault discriminator: [162, 245, 238, 109, 103, 131, 143, 178]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(caller.pubkey(), true),
+                AccountMeta::new(accounts.vault, false),
+                AccountMeta::new(accounts.vault_token, false),
+                AccountMeta::new(target.vault, false),
+                AccountMeta::new(target.vault_token, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new(accounts.owner_registry, false),
+                AccountMeta::new(accounts.beneficiary_index, false),
+                AccountMeta::new(self.global_stats_pda, false),
+                AccountMeta::new(self.mint_stats_pda, false),
+            ],
+            data: vec![162, 245, 238, 109, 103, 131, 143, 178],
+        };
+
+        self.send(instruction, caller)
+    }
+
+    /// claim_to_vault paying the beneficiary vault's own heir, with the
+    /// beneficiary vault's token account and the switch left out
+    fn claim_to_heir(
+        &mut self,
+        accounts: &VaultAccounts,
+        target: &VaultAccounts,
+        heir_creator: &Pubkey,
+        heir: &Pubkey,
+        caller: &Keypair,
+    ) -> Result<(), String> {
+        let heir_token = get_associated_token_address(heir, &self.mint);
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(caller.pubkey(), true),
+                AccountMeta::new(accounts.vault, false),
+                AccountMeta::new(accounts.vault_token, false),
+                AccountMeta::new(target.vault, false),
+                AccountMeta::new_readonly(self.program_id, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new(accounts.owner_registry, false),
+                AccountMeta::new(accounts.beneficiary_index, false),
+                AccountMeta::new(self.global_stats_pda, false),
+                AccountMeta::new(self.mint_stats_pda, false),
+                AccountMeta::new_readonly(self.program_id, false),
+                AccountMeta::new_readonly(*heir_creator, false),
+                AccountMeta::new(*heir, false),
+                AccountMeta::new(heir_token, false),
+            ],
+            data: vec![162, 245, 238, 109, 103, 131, 143, 178],
+        };
+
+        self.send(instruction, caller)
+    }
+
+    fn advance_clock(&mut self, seconds: i64) {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp += seconds;
+        self.svm.set_sysvar(&clock);
+    }
+
+    fn token_balance(&self, account: &Pubkey) -> u64 {
+        get_spl_account::<spl_token::state::Account>(&self.svm, account)
+            .unwrap()
+            .amount
+    }
+}
+
+fn assert_vault_error(result: Result<(), String>, code: u32) {
+    let err = result.expect_err("instruction should have been rejected");
+    assert!(
+        err.contains(&format!("Custom({})", code)),
+        "expected error {}, got {}",
+        code,
+        err
+    );
+}
+
+#[test]
+fn test_inheritance_passes_down_generations() {
+    println!("🧪 Testing grandparent → parent → child inheritance");
+
+    let mut fixture = NestedVaultTestFixture::new();
+    let (grandparent, grandparent_ata) = fixture.funded_user();
+    let (parent, parent_ata) = fixture.funded_user();
+    let (child, _) = fixture.funded_user();
+    let cranker = Keypair::new();
+    fixture
+        .svm
+        .airdrop(&cranker.pubkey(), 1_000_000_000)
+        .unwrap();
+
+    let parent_vault = fixture.create_vault(&parent, &parent_ata, &child.pubkey(), 1_000_000);
+    let grandparent_vault = fixture.create_vault(
+        &grandparent,
+        &grandparent_ata,
+        &parent_vault.vault,
+        2_000_000,
+    );
+
+    // The parent stays alive while the grandparent goes silent
+    fixture.advance_clock(3600);
+    fixture.heartbeat(&parent_vault, &parent);
+
+    fixture
+        .claim_to_vault(&grandparent_vault, &parent_vault, &cranker)
+        .unwrap();
+    assert!(fixture.svm.get_account(&grandparent_vault.vault).is_none());
+    assert!(fixture
+        .svm
+        .get_account(&grandparent_vault.vault_token)
+        .is_none());
+    assert_eq!(fixture.token_balance(&parent_vault.vault_token), 3_000_000);
+    println!("✅ Grandparent's tokens landed in the parent's vault");
+
+    // The inherited tokens now follow the parent's switch
+    assert_vault_error(fixture.claim(&parent_vault, &child), VAULT_NOT_EXPIRED);
+    fixture.advance_clock(3600);
+    fixture.claim(&parent_vault, &child).unwrap();
+
+    let child_ata = get_associated_token_address(&child.pubkey(), &fixture.mint);
+    assert_eq!(fixture.token_balance(&child_ata), 8_000_000);
+    println!("✅ Child claimed both generations once the parent went silent");
+}
+
+#[test]
+fn test_claim_to_vault_validates_target_and_expiry() {
+    println!("🧪 Testing claim_to_vault validation");
+
+    let mut fixture = NestedVaultTestFixture::new();
+    let (grandparent, grandparent_ata) = fixture.funded_user();
+    let (parent, parent_ata) = fixture.funded_user();
+    let (stranger, stranger_ata) = fixture.funded_user();
+
+    let parent_vault = fixture.create_vault(&parent, &parent_ata, &Pubkey::new_unique(), 1_000_000);
+    let stranger_vault =
+        fixture.create_vault(&stranger, &stranger_ata, &Pubkey::new_unique(), 1_000_000);
+    let grandparent_vault = fixture.create_vault(
+        &grandparent,
+        &grandparent_ata,
+        &parent_vault.vault,
+        2_000_000,
+    );
+
+    assert_vault_error(
+        fixture.claim_to_vault(&grandparent_vault, &parent_vault, &stranger),
+        VAULT_NOT_EXPIRED,
+    );
+    println!("✅ Live vault cannot be cranked");
+
+    fixture.advance_clock(3600);
+    assert_vault_error(
+        fixture.claim_to_vault(&grandparent_vault, &stranger_vault, &stranger),
+        UNAUTHORIZED_ACCESS,
+    );
+    assert_eq!(
+        fixture.token_balance(&stranger_vault.vault_token),
+        1_000_000
+    );
+    println!("✅ Funds cannot be diverted to a vault other than the beneficiary");
+
+    fixture
+        .claim_to_vault(&grandparent_vault, &parent_vault, &stranger)
+        .unwrap();
+    assert_eq!(fixture.token_balance(&parent_vault.vault_token), 3_000_000);
+    println!("✅ Anyone may crank the claim into the beneficiary vault");
+}
+
+#[test]
+fn test_claim_to_vault_pays_heir_once_beneficiary_vault_is_gone() {
+    println!("🧪 Testing claim_to_vault after the beneficiary vault was claimed");
+
+    let mut fixture = NestedVaultTestFixture::new();
+    let (grandparent, grandparent_ata) = fixture.funded_user();
+    let (parent, parent_ata) = fixture.funded_user();
+    let (child, child_ata) = fixture.funded_user();
+    let (stranger, _) = fixture.funded_user();
+
+    let parent_vault = fixture.create_vault(&parent, &parent_ata, &child.pubkey(), 1_000_000);
+    let grandparent_vault = fixture.create_vault(
+        &grandparent,
+        &grandparent_ata,
+        &parent_vault.vault,
+        2_000_000,
+    );
+
+    // The heir route stays shut while the beneficiary vault is live
+    fixture.advance_clock(3600);
+    assert_vault_error(
+        fixture.claim_to_heir(
+            &grandparent_vault,
+            &parent_vault,
+            &parent.pubkey(),
+            &child.pubkey(),
+            &stranger,
+        ),
+        INVALID_BENEFICIARY_VAULT,
+    );
+    println!("✅ Heir cannot bypass a live beneficiary vault");
+
+    // Both went silent; the child claims the parent first, removing the target
+    fixture.claim(&parent_vault, &child).unwrap();
+    assert!(fixture.svm.get_account(&parent_vault.vault).is_none());
+    assert_eq!(fixture.token_balance(&child_ata), 6_000_000);
+
+    assert_vault_error(
+        fixture.claim_to_heir(
+            &grandparent_vault,
+            &parent_vault,
+            &stranger.pubkey(),
+            &stranger.pubkey(),
+            &stranger,
+        ),
+        INVALID_BENEFICIARY_VAULT,
+    );
+    println!("✅ Heir must re-derive the beneficiary vault address");
+
+    fixture
+        .claim_to_heir(
+            &grandparent_vault,
+            &parent_vault,
+            &parent.pubkey(),
+            &child.pubkey(),
+            &stranger,
+        )
+        .unwrap();
+    assert!(fixture.svm.get_account(&grandparent_vault.vault).is_none());
+    assert_eq!(fixture.token_balance(&child_ata), 8_000_000);
+    println!("✅ Grandparent's tokens went straight to the child");
+}
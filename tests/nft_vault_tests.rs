@@ -0,0 +1,450 @@
+use litesvm::LiteSVM;
+use litesvm_token::{get_spl_account, spl_token, CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+
+// VaultError codes
+const VAULT_NOT_EXPIRED: u32 = 6001;
+const VAULT_INACTIVE: u32 = 6006;
+const INVALID_NFT_MINT: u32 = 6052;
+const MINT_HAS_FREEZE_AUTHORITY: u32 = 6041;
+const INVALID_NFT_ESCROW: u32 = 6053;
+
+/// Test fixture for vaults holding a collection of NFTs
+struct NftVaultTestFixture {
+    svm: LiteSVM,
+    program_id: Pubkey,
+    owner: Keypair,
+    beneficiary: Keypair,
+    nft_vault_pda: Pubkey,
+}
+
+impl NftVaultTestFixture {
+    fn new() -> Self {
+        let mut svm = LiteSVM::new();
+
+        // Load the compiled program
+        let program_keypair =
+            read_keypair_file("target/deploy/cyber_vault_rs-keypair.json").unwrap();
+        let program_id = program_keypair.pubkey();
+        let program_bytes = include_bytes!("../target/deploy/cyber_vault_rs.so");
+        svm.add_program(program_id, program_bytes);
+
+        let owner = Keypair::new();
+        let beneficiary = Keypair::new();
+        svm.airdrop(&owner.pubkey(), 10_000_000_000).unwrap();
+        svm.airdrop(&beneficiary.pubkey(), 10_000_000_000).unwrap();
+
+        let (nft_vault_pda, _) = Pubkey::find_program_address(
+            &[
+                b"nft_vault",
+                owner.pubkey().as_ref(),
+                beneficiary.pubkey().as_ref(),
+            ],
+            &program_id,
+        );
+
+        Self {
+            svm,
+            program_id,
+            owner,
+            beneficiary,
+            nft_vault_pda,
+        }
+    }
+
+    /// Creates a mint, mints `supply` to the owner and returns (mint, owner_ata)
+    fn owner_mint(&mut self, decimals: u8, supply: u64) -> (Pubkey, Pubkey) {
+        let owner = self.owner.insecure_clone();
+        let mint = CreateMint::new(&mut self.svm, &owner)
+            .authority(&owner.pubkey())
+            .decimals(decimals)
+            .send()
+            .unwrap();
+        self.mint_to_owner(mint, supply)
+    }
+
+    /// Like `owner_mint` for an NFT whose freeze authority is the owner
+    fn freezable_nft(&mut self) -> (Pubkey, Pubkey) {
+        let owner = self.owner.insecure_clone();
+        let mint = CreateMint::new(&mut self.svm, &owner)
+            .authority(&owner.pubkey())
+            .freeze_authority(&owner.pubkey())
+            .decimals(0)
+            .send()
+            .unwrap();
+        self.mint_to_owner(mint, 1)
+    }
+
+    fn mint_to_owner(&mut self, mint: Pubkey, supply: u64) -> (Pubkey, Pubkey) {
+        let owner = self.owner.insecure_clone();
+        let owner_ata = CreateAssociatedTokenAccount::new(&mut self.svm, &owner, &mint)
+            .owner(&owner.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(&mut self.svm, &owner, &mint, &owner_ata, supply)
+            .send()
+            .unwrap();
+        (mint, owner_ata)
+    }
+
+    fn escrow_pda(&self, mint: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"nft_escrow", self.nft_vault_pda.as_ref(), mint.as_ref()],
+            &self.program_id,
+        )
+        .0
+    }
+
+    fn send(&mut self, instruction: Instruction, signer: &Keypair) -> Result<(), String> {
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        self.svm
+            .send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn create_nft_vault(&mut self) {
+        let owner = self.owner.insecure_clone();
+
+        // create_nft_vault discriminator: [150, 78, 66, 49, 224, 254, 62, 147]
+        let mut instruction_data = vec![150, 78, 66, 49, 224, 254, 62, 147];
+        instruction_data.extend_from_slice(self.beneficiary.pubkey().as_ref());
+        instruction_data.extend_from_slice(&3600i64.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(owner.pubkey(), true),
+                AccountMeta::new(self.nft_vault_pda, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: instruction_data,
+        };
+
+        self.send(instruction, &owner).unwrap();
+    }
+
+    fn add_nft(&mut self, mint: &Pubkey, owner_ata: &Pubkey) -> Result<(), String> {
+        self.add_nft_acknowledged(mint, owner_ata, false)
+    }
+
+    fn add_nft_acknowledged(
+        &mut self,
+        mint: &Pubkey,
+        owner_ata: &Pubkey,
+        acknowledge_freeze_authority: bool,
+    ) -> Result<(), String> {
+        let owner = self.owner.insecure_clone();
+        let (mint_policy, _) = Pubkey::find_program_address(&[b"mint_policy"], &self.program_id);
+
+        // add_nft discriminator: [55, 57, 85, 145, 81, 134, 220, 223]
+        let mut instruction_data = vec![55, 57, 85, 145, 81, 134, 220, 223];
+        instruction_data.push(acknowledge_freeze_authority as u8);
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(owner.pubkey(), true),
+                AccountMeta::new(self.nft_vault_pda, false),
+                AccountMeta::new_readonly(*mint, false),
+                AccountMeta::new(self.escrow_pda(mint), false),
+                AccountMeta::new(*owner_ata, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(mint_policy, false),
+            ],
+            data: instruction_data,
+        };
+
+        self.send(instruction, &owner)
+    }
+
+    fn remove_nft(&mut self, mint: &Pubkey, owner_ata: &Pubkey) -> Result<(), String> {
+        let owner = self.owner.insecure_clone();
+
+        // remove_nft discriminator: [22, 52, 77, 58, 242, 146, 178, 20]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(owner.pubkey(), true),
+                AccountMeta::new(self.nft_vault_pda, false),
+                AccountMeta::new_readonly(*mint, false),
+                AccountMeta::new(self.escrow_pda(mint), false),
+                AccountMeta::new(*owner_ata, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+            ],
+            data: vec![22, 52, 77, 58, 242, 146, 178, 20],
+        };
+
+        self.send(instruction, &owner)
+    }
+
+    fn nft_heartbeat(&mut self) -> Result<(), String> {
+        let owner = self.owner.insecure_clone();
+
+        // nft_heartbeat discriminator: [5, 73, 23, 167, 78, 233, 250, 130]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(owner.pubkey(), true),
+                AccountMeta::new(self.nft_vault_pda, false),
+            ],
+            data: vec![5, 73, 23, 167, 78, 233, 250, 130],
+        };
+
+        self.send(instruction, &owner)
+    }
+
+    /// Claims one batch of (escrow, destination) pairs as the beneficiary
+    fn claim_nfts(&mut self, pairs: &[(Pubkey, Pubkey)]) -> Result<(), String> {
+        let beneficiary = self.beneficiary.insecure_clone();
+
+        let mut accounts = vec![
+            AccountMeta::new(beneficiary.pubkey(), true),
+            AccountMeta::new(self.nft_vault_pda, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ];
+        for (escrow, destination) in pairs {
+            accounts.push(AccountMeta::new(*escrow, false));
+            accounts.push(AccountMeta::new(*destination, false));
+        }
+
+        // claim_nfts discriminator: [185, 98, 203, 203, 168, 52, 91, 162]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: vec![185, 98, 203, 203, 168, 52, 91, 162],
+        };
+
+        self.send(instruction, &beneficiary)
+    }
+
+    /// Freezes or thaws a token account with the owner as freeze authority
+    fn set_frozen(&mut self, account: &Pubkey, mint: &Pubkey, frozen: bool) {
+        let owner = self.owner.insecure_clone();
+        let instruction = if frozen {
+            spl_token::instruction::freeze_account(
+                &spl_token::id(),
+                account,
+                mint,
+                &owner.pubkey(),
+                &[],
+            )
+        } else {
+            spl_token::instruction::thaw_account(
+                &spl_token::id(),
+                account,
+                mint,
+                &owner.pubkey(),
+                &[],
+            )
+        };
+        self.send(instruction.unwrap(), &owner).unwrap();
+    }
+
+    fn advance_clock(&mut self, seconds: i64) {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp += seconds;
+        self.svm.set_sysvar(&clock);
+    }
+
+    /// (claim_cursor, number of mints) from the NftVault account
+    fn claim_progress(&self) -> (u32, u32) {
+        // discriminator, owner, beneficiary, inactivity_period, last_heartbeat,
+        // then claim_cursor, bump and the mints vector length
+        let data = self.svm.get_account(&self.nft_vault_pda).unwrap().data;
+        (
+            u32::from_le_bytes(data[88..92].try_into().unwrap()),
+            u32::from_le_bytes(data[93..97].try_into().unwrap()),
+        )
+    }
+
+    /// Number of escrows the claim has deferred, stored after the mints vector
+    fn deferred_count(&self) -> u32 {
+        let data = self.svm.get_account(&self.nft_vault_pda).unwrap().data;
+        let mints = u32::from_le_bytes(data[93..97].try_into().unwrap()) as usize;
+        let offset = 97 + 32 * mints;
+        u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+    }
+
+    fn balance(&self, token_account: &Pubkey) -> u64 {
+        get_spl_account::<spl_token::state::Account>(&self.svm, token_account)
+            .unwrap()
+            .amount
+    }
+}
+
+fn assert_vault_error(result: Result<(), String>, code: u32) {
+    let err = result.expect_err("instruction should have been rejected");
+    assert!(
+        err.contains(&format!("Custom({})", code)),
+        "expected error {}, got {}",
+        code,
+        err
+    );
+}
+
+#[test]
+fn test_add_and_remove_nfts() {
+    println!("🧪 Testing NFT escrows added and removed by the owner");
+
+    let mut fixture = NftVaultTestFixture::new();
+    fixture.create_nft_vault();
+
+    let (fungible, fungible_ata) = fixture.owner_mint(6, 1_000_000);
+    assert_vault_error(fixture.add_nft(&fungible, &fungible_ata), INVALID_NFT_MINT);
+    println!("✅ Fungible mint rejected");
+
+    let nfts: Vec<(Pubkey, Pubkey)> = (0..3).map(|_| fixture.owner_mint(0, 1)).collect();
+    for (mint, owner_ata) in &nfts {
+        fixture.add_nft(mint, owner_ata).unwrap();
+        assert_eq!(fixture.balance(&fixture.escrow_pda(mint)), 1);
+        assert_eq!(fixture.balance(owner_ata), 0);
+    }
+    assert_eq!(fixture.claim_progress(), (0, 3));
+    println!("✅ Each NFT sits in its own escrow");
+
+    let (mint, owner_ata) = nfts[1];
+    fixture.remove_nft(&mint, &owner_ata).unwrap();
+    assert_eq!(fixture.balance(&owner_ata), 1);
+    assert!(fixture
+        .svm
+        .get_account(&fixture.escrow_pda(&mint))
+        .is_none());
+    assert_eq!(fixture.claim_progress(), (0, 2));
+    println!("✅ Removed NFT returned and its escrow closed");
+}
+
+#[test]
+fn test_claim_collection_in_batches() {
+    println!("🧪 Testing a resumable claim of an NFT collection");
+
+    let mut fixture = NftVaultTestFixture::new();
+    fixture.create_nft_vault();
+
+    let nfts: Vec<(Pubkey, Pubkey)> = (0..5).map(|_| fixture.owner_mint(0, 1)).collect();
+    for (mint, owner_ata) in &nfts {
+        fixture.add_nft(mint, owner_ata).unwrap();
+    }
+
+    let beneficiary = fixture.beneficiary.insecure_clone();
+    let pairs: Vec<(Pubkey, Pubkey)> = nfts
+        .iter()
+        .map(|(mint, _)| {
+            let destination =
+                CreateAssociatedTokenAccount::new(&mut fixture.svm, &beneficiary, mint)
+                    .owner(&beneficiary.pubkey())
+                    .send()
+                    .unwrap();
+            (fixture.escrow_pda(mint), destination)
+        })
+        .collect();
+
+    assert_vault_error(fixture.claim_nfts(&pairs[..2]), VAULT_NOT_EXPIRED);
+    fixture.advance_clock(3600);
+
+    // An account that is not one of the vault's escrows is rejected
+    let (_, owner_ata) = nfts[0];
+    assert_vault_error(
+        fixture.claim_nfts(&[(owner_ata, pairs[0].1)]),
+        INVALID_NFT_ESCROW,
+    );
+
+    fixture.claim_nfts(&pairs[..2]).unwrap();
+    assert_eq!(fixture.claim_progress(), (2, 5));
+    println!("✅ First batch claimed");
+
+    // Batches follow the cursor; skipping ahead is rejected
+    assert_vault_error(fixture.claim_nfts(&pairs[3..4]), INVALID_NFT_ESCROW);
+    fixture.claim_nfts(&pairs[2..3]).unwrap();
+    assert_eq!(fixture.claim_progress(), (3, 5));
+
+    // The owner is locked out once the claim has started
+    assert_vault_error(fixture.nft_heartbeat(), VAULT_INACTIVE);
+    let (mint, owner_ata) = nfts[4];
+    assert_vault_error(fixture.remove_nft(&mint, &owner_ata), VAULT_INACTIVE);
+
+    fixture.claim_nfts(&pairs[3..]).unwrap();
+    for (escrow, destination) in &pairs {
+        assert_eq!(fixture.balance(destination), 1);
+        assert!(fixture.svm.get_account(escrow).is_none());
+    }
+    assert!(fixture.svm.get_account(&fixture.nft_vault_pda).is_none());
+    println!("✅ Last batch claimed the rest and closed the vault");
+}
+
+#[test]
+fn test_frozen_escrow_is_deferred_not_blocking() {
+    println!("🧪 Testing a claim past a frozen NFT escrow");
+
+    let mut fixture = NftVaultTestFixture::new();
+    fixture.create_nft_vault();
+
+    let nfts = vec![
+        fixture.owner_mint(0, 1),
+        fixture.freezable_nft(),
+        fixture.owner_mint(0, 1),
+    ];
+    let (frozen_mint, frozen_owner_ata) = nfts[1];
+    assert_vault_error(
+        fixture.add_nft(&frozen_mint, &frozen_owner_ata),
+        MINT_HAS_FREEZE_AUTHORITY,
+    );
+    println!("✅ Freeze authority must be acknowledged");
+
+    for (mint, owner_ata) in &nfts {
+        fixture
+            .add_nft_acknowledged(mint, owner_ata, *mint == frozen_mint)
+            .unwrap();
+    }
+
+    let beneficiary = fixture.beneficiary.insecure_clone();
+    let pairs: Vec<(Pubkey, Pubkey)> = nfts
+        .iter()
+        .map(|(mint, _)| {
+            let destination =
+                CreateAssociatedTokenAccount::new(&mut fixture.svm, &beneficiary, mint)
+                    .owner(&beneficiary.pubkey())
+                    .send()
+                    .unwrap();
+            (fixture.escrow_pda(mint), destination)
+        })
+        .collect();
+
+    let (frozen_escrow, frozen_destination) = pairs[1];
+    fixture.set_frozen(&frozen_escrow, &frozen_mint, true);
+    fixture.advance_clock(3600);
+
+    fixture.claim_nfts(&pairs).unwrap();
+    assert_eq!(fixture.claim_progress(), (3, 3));
+    assert_eq!(fixture.deferred_count(), 1);
+    assert_eq!(fixture.balance(&pairs[0].1), 1);
+    assert_eq!(fixture.balance(&pairs[2].1), 1);
+    assert_eq!(fixture.balance(&frozen_escrow), 1);
+    println!("✅ Cursor moved past the frozen escrow and the rest was paid");
+
+    // The vault stays open while the escrow is frozen
+    assert!(fixture.claim_nfts(&pairs[1..2]).is_err());
+    assert_vault_error(fixture.claim_nfts(&pairs[0..1]), INVALID_NFT_ESCROW);
+    assert!(fixture.svm.get_account(&fixture.nft_vault_pda).is_some());
+
+    fixture.set_frozen(&frozen_escrow, &frozen_mint, false);
+    fixture.claim_nfts(&pairs[1..2]).unwrap();
+    assert_eq!(fixture.balance(&frozen_destination), 1);
+    assert!(fixture.svm.get_account(&frozen_escrow).is_none());
+    assert!(fixture.svm.get_account(&fixture.nft_vault_pda).is_none());
+    println!("✅ Deferred escrow claimed once thawed, closing the vault");
+}
@@ -0,0 +1,473 @@
+use litesvm::LiteSVM;
+use litesvm_token::{spl_token, CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+
+// VaultError codes
+const UNAUTHORIZED_ACCESS: u32 = 6000;
+const INVALID_NEW_OWNER: u32 = 6011;
+const SWITCH_REQUIRED: u32 = 6021;
+const OWNER_TRANSFER_NOT_PENDING: u32 = 6049;
+
+/// Test fixture for two-step vault ownership transfer
+struct OwnershipTestFixture {
+    svm: LiteSVM,
+    program_id: Pubkey,
+    mint_authority: Keypair,
+    mint: Pubkey,
+    global_stats_pda: Pubkey,
+    mint_stats_pda: Pubkey,
+}
+
+/// PDAs for one (owner, beneficiary) vault
+struct VaultAccounts {
+    vault: Pubkey,
+    vault_token: Pubkey,
+    owner_registry: Pubkey,
+    beneficiary_index: Pubkey,
+}
+
+impl OwnershipTestFixture {
+    fn new() -> Self {
+        let mut svm = LiteSVM::new();
+
+        // Load the compiled program
+        let program_keypair =
+            read_keypair_file("target/deploy/cyber_vault_rs-keypair.json").unwrap();
+        let program_id = program_keypair.pubkey();
+        let program_bytes = include_bytes!("../target/deploy/cyber_vault_rs.so");
+        svm.add_program(program_id, program_bytes);
+
+        let mint_authority = Keypair::new();
+        svm.airdrop(&mint_authority.pubkey(), 10_000_000_000)
+            .unwrap();
+
+        let mint = CreateMint::new(&mut svm, &mint_authority)
+            .authority(&mint_authority.pubkey())
+            .decimals(6)
+            .send()
+            .unwrap();
+
+        let (global_stats_pda, _) = Pubkey::find_program_address(&[b"global_stats"], &program_id);
+        let (mint_stats_pda, _) =
+            Pubkey::find_program_address(&[b"mint_stats", mint.as_ref()], &program_id);
+
+        Self {
+            svm,
+            program_id,
+            mint_authority,
+            mint,
+            global_stats_pda,
+            mint_stats_pda,
+        }
+    }
+
+    fn funded_user(&mut self) -> (Keypair, Pubkey) {
+        let user = Keypair::new();
+        self.svm.airdrop(&user.pubkey(), 10_000_000_000).unwrap();
+        let ata = CreateAssociatedTokenAccount::new(&mut self.svm, &user, &self.mint)
+            .owner(&user.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(
+            &mut self.svm,
+            &self.mint_authority,
+            &self.mint,
+            &ata,
+            5_000_000,
+        )
+        .send()
+        .unwrap();
+        (user, ata)
+    }
+
+    fn accounts(&self, owner: &Pubkey, beneficiary: &Pubkey) -> VaultAccounts {
+        let (vault, _) = Pubkey::find_program_address(
+            &[
+                b"vault",
+                owner.as_ref(),
+                beneficiary.as_ref(),
+                self.mint.as_ref(),
+            ],
+            &self.program_id,
+        );
+        let (vault_token, _) =
+            Pubkey::find_program_address(&[b"vault_token", vault.as_ref()], &self.program_id);
+        let (owner_registry, _) =
+            Pubkey::find_program_address(&[b"owner_registry", owner.as_ref()], &self.program_id);
+        let (beneficiary_index, _) = Pubkey::find_program_address(
+            &[b"beneficiary_index", beneficiary.as_ref()],
+            &self.program_id,
+        );
+        VaultAccounts {
+            vault,
+            vault_token,
+            owner_registry,
+            beneficiary_index,
+        }
+    }
+
+    fn send(&mut self, instruction: Instruction, signer: &Keypair) -> Result<(), String> {
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        self.svm
+            .send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn create_vault(
+        &mut self,
+        owner: &Keypair,
+        owner_ata: &Pubkey,
+        beneficiary: &Pubkey,
+        amount: u64,
+    ) -> VaultAccounts {
+        let accounts = self.accounts(&owner.pubkey(), beneficiary);
+        let inactivity_period: i64 = 3600;
+
+        // create_vault discriminator: [29, 237, 247, 208, 193, 82, 54, 135]
+        let mut instruction_data = vec![29, 237, 247, 208, 193, 82, 54, 135];
+        instruction_data.extend_from_slice(&beneficiary.to_bytes());
+        instruction_data.extend_from_slice(&inactivity_period.to_le_bytes());
+        instruction_data.extend_from_slice(&amount.to_le_bytes());
+        instruction_data.push(0); // acknowledge_freeze_authority
+        let (mint_policy_pda, _) =
+            Pubkey::find_program_address(&[b"mint_policy"], &self.program_id);
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(owner.pubkey(), true),
+                AccountMeta::new(accounts.vault, false),
+                AccountMeta::new(accounts.vault_token, false),
+                AccountMeta::new(*owner_ata, false),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
+                AccountMeta::new(accounts.owner_registry, false),
+                AccountMeta::new(accounts.beneficiary_index, false),
+                AccountMeta::new(self.global_stats_pda, false),
+                AccountMeta::new(self.mint_stats_pda, false),
+                AccountMeta::new_readonly(mint_policy_pda, false),
+            ],
+            data: instruction_data,
+        };
+
+        self.send(instruction, owner).unwrap();
+        accounts
+    }
+
+    fn propose_owner(
+        &mut self,
+        accounts: &VaultAccounts,
+        owner: &Keypair,
+        new_owner: &Pubkey,
+    ) -> Result<(), String> {
+        // propose_owner discriminator: [90, 57, 141, 110, 196, 241, 172, 39]
+        let mut instruction_data = vec![90, 57, 141, 110, 196, 241, 172, 39];
+        instruction_data.extend_from_slice(new_owner.as_ref());
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(owner.pubkey(), true),
+                AccountMeta::new(accounts.vault, false),
+            ],
+            data: instruction_data,
+        };
+
+        self.send(instruction, owner)
+    }
+
+    fn accept_owner(
+        &mut self,
+        accounts: &VaultAccounts,
+        new_owner: &Keypair,
+        switch: Option<Pubkey>,
+    ) -> Result<(), String> {
+        let (new_owner_registry, _) = Pubkey::find_program_address(
+            &[b"owner_registry", new_owner.pubkey().as_ref()],
+            &self.program_id,
+        );
+
+        // accept_owner discriminator: [176, 23, 41, 28, 23, 111, 8, 4]
+        let mut account_metas = vec![
+            AccountMeta::new(new_owner.pubkey(), true),
+            AccountMeta::new(accounts.vault, false),
+            AccountMeta::new(accounts.owner_registry, false),
+            AccountMeta::new(new_owner_registry, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ];
+        if let Some(switch) = switch {
+            account_metas.push(AccountMeta::new(switch, false));
+        }
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: account_metas,
+            data: vec![176, 23, 41, 28, 23, 111, 8, 4],
+        };
+
+        self.send(instruction, new_owner)
+    }
+
+    /// Creates the owner's master switch and links the vault to it
+    fn link_new_switch(&mut self, accounts: &VaultAccounts, owner: &Keypair) -> Pubkey {
+        let (switch, _) =
+            Pubkey::find_program_address(&[b"switch", owner.pubkey().as_ref()], &self.program_id);
+
+        // create_switch discriminator: [120, 11, 193, 114, 80, 234, 99, 128]
+        let mut instruction_data = vec![120, 11, 193, 114, 80, 234, 99, 128];
+        instruction_data.extend_from_slice(&3600i64.to_le_bytes());
+        let create = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(owner.pubkey(), true),
+                AccountMeta::new(switch, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: instruction_data,
+        };
+        self.send(create, owner).unwrap();
+
+        // link_switch discriminator: [136, 247, 62, 224, 223, 183, 48, 117]
+        let link = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(owner.pubkey(), true),
+                AccountMeta::new(accounts.vault, false),
+                AccountMeta::new(switch, false),
+            ],
+            data: vec![136, 247, 62, 224, 223, 183, 48, 117],
+        };
+        self.send(link, owner).unwrap();
+
+        switch
+    }
+
+    fn set_duress_key(
+        &mut self,
+        accounts: &VaultAccounts,
+        owner: &Keypair,
+        duress_key: &Pubkey,
+    ) -> Result<(), String> {
+        // set_duress_key discriminator: [36, 185, 208, 162, 145, 74, 235, 67]
+        let mut instruction_data = vec![36, 185, 208, 162, 145, 74, 235, 67];
+        instruction_data.extend_from_slice(duress_key.as_ref());
+        instruction_data.extend_from_slice(&3600i64.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(owner.pubkey(), true),
+                AccountMeta::new(accounts.vault, false),
+            ],
+            data: instruction_data,
+        };
+
+        self.send(instruction, owner)
+    }
+
+    fn heartbeat(&mut self, accounts: &VaultAccounts, signer: &Keypair) -> Result<(), String> {
+        // heartbeat discriminator: [202, 104, 56, 6, 240, 170, 63, 134]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(accounts.vault, false),
+                AccountMeta::new_readonly(signer.pubkey(), true),
+            ],
+            data: vec![202, 104, 56, 6, 240, 170, 63, 134],
+        };
+
+        self.send(instruction, signer)
+    }
+
+    fn advance_clock(&mut self, seconds: i64) {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp += seconds;
+        self.svm.set_sysvar(&clock);
+    }
+
+    /// Returns (owner, last_heartbeat) from the vault account
+    fn vault_owner_and_heartbeat(&self, accounts: &VaultAccounts) -> (Pubkey, i64) {
+        let data = self.svm.get_account(&accounts.vault).unwrap().data;
+        (
+            Pubkey::try_from(&data[8..40]).unwrap(),
+            i64::from_le_bytes(data[208..216].try_into().unwrap()),
+        )
+    }
+
+    /// Returns (switch, duress_key) from the vault account
+    fn vault_switch_and_duress_key(&self, accounts: &VaultAccounts) -> (Pubkey, Pubkey) {
+        let data = self.svm.get_account(&accounts.vault).unwrap().data;
+        (
+            Pubkey::try_from(&data[168..200]).unwrap(),
+            Pubkey::try_from(&data[328..360]).unwrap(),
+        )
+    }
+
+    /// Switch layout: disc(8) + owner(32) + inactivity_period(8) + last_heartbeat(8) + linked_vaults
+    fn linked_vaults(&self, switch: &Pubkey) -> u32 {
+        let data = self.svm.get_account(switch).unwrap().data;
+        u32::from_le_bytes(data[56..60].try_into().unwrap())
+    }
+
+    /// OwnerRegistry layout: disc(8) + owner(32) + vec len(4) + entries
+    fn registered_vaults(&self, owner: &Pubkey) -> Vec<Pubkey> {
+        let (registry, _) =
+            Pubkey::find_program_address(&[b"owner_registry", owner.as_ref()], &self.program_id);
+        let data = self.svm.get_account(&registry).unwrap().data;
+        let len = u32::from_le_bytes(data[40..44].try_into().unwrap()) as usize;
+        (0..len)
+            .map(|i| Pubkey::try_from(&data[44 + 32 * i..76 + 32 * i]).unwrap())
+            .collect()
+    }
+}
+
+fn assert_vault_error(result: Result<(), String>, code: u32) {
+    let err = result.expect_err("instruction should have been rejected");
+    assert!(
+        err.contains(&format!("Custom({})", code)),
+        "expected error {}, got {}",
+        code,
+        err
+    );
+}
+
+#[test]
+fn test_ownership_moves_to_accepting_wallet() {
+    println!("🧪 Testing two-step ownership transfer");
+
+    let mut fixture = OwnershipTestFixture::new();
+    let (owner, owner_ata) = fixture.funded_user();
+    let (hardware_wallet, _) = fixture.funded_user();
+    let accounts = fixture.create_vault(&owner, &owner_ata, &Pubkey::new_unique(), 1_000_000);
+
+    fixture
+        .propose_owner(&accounts, &owner, &hardware_wallet.pubkey())
+        .unwrap();
+    let (current_owner, proposed_at) = fixture.vault_owner_and_heartbeat(&accounts);
+    assert_eq!(current_owner, owner.pubkey());
+    println!("✅ Proposal leaves the current owner in charge");
+
+    fixture.advance_clock(1800);
+    fixture
+        .accept_owner(&accounts, &hardware_wallet, None)
+        .unwrap();
+
+    let (new_owner, last_heartbeat) = fixture.vault_owner_and_heartbeat(&accounts);
+    assert_eq!(new_owner, hardware_wallet.pubkey());
+    assert_eq!(last_heartbeat, proposed_at + 1800);
+    println!("✅ New owner accepted and the heartbeat reset");
+
+    assert!(fixture.registered_vaults(&owner.pubkey()).is_empty());
+    assert_eq!(
+        fixture.registered_vaults(&hardware_wallet.pubkey()),
+        vec![accounts.vault]
+    );
+    println!("✅ Vault moved between owner registries at the same address");
+
+    assert_vault_error(fixture.heartbeat(&accounts, &owner), UNAUTHORIZED_ACCESS);
+    fixture.heartbeat(&accounts, &hardware_wallet).unwrap();
+    println!("✅ Only the new owner can send heartbeats");
+}
+
+#[test]
+fn test_transfer_requires_matching_pending_owner() {
+    println!("🧪 Testing ownership transfer validation");
+
+    let mut fixture = OwnershipTestFixture::new();
+    let (owner, owner_ata) = fixture.funded_user();
+    let (new_wallet, _) = fixture.funded_user();
+    let (stranger, _) = fixture.funded_user();
+    let beneficiary = Pubkey::new_unique();
+    let accounts = fixture.create_vault(&owner, &owner_ata, &beneficiary, 1_000_000);
+
+    assert_vault_error(
+        fixture.accept_owner(&accounts, &new_wallet, None),
+        OWNER_TRANSFER_NOT_PENDING,
+    );
+    assert_vault_error(
+        fixture.propose_owner(&accounts, &owner, &beneficiary),
+        INVALID_NEW_OWNER,
+    );
+    assert_vault_error(
+        fixture.propose_owner(&accounts, &stranger, &stranger.pubkey()),
+        UNAUTHORIZED_ACCESS,
+    );
+    println!("✅ Nothing to accept, beneficiary and non-owner proposals rejected");
+
+    fixture
+        .propose_owner(&accounts, &owner, &new_wallet.pubkey())
+        .unwrap();
+    assert_vault_error(
+        fixture.accept_owner(&accounts, &stranger, None),
+        UNAUTHORIZED_ACCESS,
+    );
+    println!("✅ Only the proposed wallet can accept");
+
+    fixture
+        .propose_owner(&accounts, &owner, &Pubkey::default())
+        .unwrap();
+    assert_vault_error(
+        fixture.accept_owner(&accounts, &new_wallet, None),
+        OWNER_TRANSFER_NOT_PENDING,
+    );
+    let (current_owner, _) = fixture.vault_owner_and_heartbeat(&accounts);
+    assert_eq!(current_owner, owner.pubkey());
+    println!("✅ Cancelled proposal cannot be accepted");
+}
+
+#[test]
+fn test_accept_drops_previous_owner_switch_and_duress_key() {
+    println!("🧪 Testing ownership transfer of a linked vault with duress mode on");
+
+    let mut fixture = OwnershipTestFixture::new();
+    let (owner, owner_ata) = fixture.funded_user();
+    let (new_wallet, _) = fixture.funded_user();
+    let duress = Pubkey::new_unique();
+    let accounts = fixture.create_vault(&owner, &owner_ata, &Pubkey::new_unique(), 1_000_000);
+
+    let switch = fixture.link_new_switch(&accounts, &owner);
+    fixture.set_duress_key(&accounts, &owner, &duress).unwrap();
+    assert_eq!(
+        fixture.vault_switch_and_duress_key(&accounts),
+        (switch, duress)
+    );
+    assert_eq!(fixture.linked_vaults(&switch), 1);
+
+    fixture
+        .propose_owner(&accounts, &owner, &new_wallet.pubkey())
+        .unwrap();
+    assert_vault_error(
+        fixture.accept_owner(&accounts, &new_wallet, None),
+        SWITCH_REQUIRED,
+    );
+    println!("✅ Linked vault cannot change hands without its switch");
+
+    fixture
+        .accept_owner(&accounts, &new_wallet, Some(switch))
+        .unwrap();
+    assert_eq!(
+        fixture.vault_switch_and_duress_key(&accounts),
+        (Pubkey::default(), Pubkey::default())
+    );
+    assert_eq!(fixture.linked_vaults(&switch), 0);
+    println!("✅ Previous owner's switch released and duress key cleared");
+
+    fixture.heartbeat(&accounts, &new_wallet).unwrap();
+    println!("✅ New owner keeps the vault alive on its own timer");
+}
@@ -0,0 +1,679 @@
+use litesvm::LiteSVM;
+use litesvm_token::{spl_token, CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+
+/// Test fixture for guardian recovery tests
+struct RecoveryTestFixture {
+    svm: LiteSVM,
+    program_id: Pubkey,
+    owner: Keypair,
+    beneficiary: Keypair,
+    guardians: Vec<Keypair>,
+    new_owner: Keypair,
+    mint: Pubkey,
+    owner_ata: Pubkey,
+    vault_pda: Pubkey,
+    vault_token_pda: Pubkey,
+    recovery_pda: Pubkey,
+}
+
+impl RecoveryTestFixture {
+    fn new() -> Self {
+        let mut svm = LiteSVM::new();
+
+        // Load the compiled program
+        let program_keypair =
+            read_keypair_file("target/deploy/cyber_vault_rs-keypair.json").unwrap();
+        let program_id = program_keypair.pubkey();
+        let program_bytes = include_bytes!("../target/deploy/cyber_vault_rs.so");
+        svm.add_program(program_id, program_bytes);
+
+        // Create test accounts
+        let owner = Keypair::new();
+        let beneficiary = Keypair::new();
+        let new_owner = Keypair::new();
+        let guardians: Vec<Keypair> = (0..3).map(|_| Keypair::new()).collect();
+        svm.airdrop(&owner.pubkey(), 10_000_000_000).unwrap();
+        svm.airdrop(&beneficiary.pubkey(), 10_000_000_000).unwrap();
+        svm.airdrop(&new_owner.pubkey(), 10_000_000_000).unwrap();
+        for guardian in &guardians {
+            svm.airdrop(&guardian.pubkey(), 1_000_000_000).unwrap();
+        }
+
+        // Create token mint and fund the owner
+        let mint = CreateMint::new(&mut svm, &owner)
+            .authority(&owner.pubkey())
+            .decimals(6)
+            .send()
+            .unwrap();
+
+        let owner_ata = CreateAssociatedTokenAccount::new(&mut svm, &owner, &mint)
+            .owner(&owner.pubkey())
+            .send()
+            .unwrap();
+
+        MintTo::new(&mut svm, &owner, &mint, &owner_ata, 2_000_000)
+            .send()
+            .unwrap();
+
+        // Calculate PDAs
+        let (vault_pda, _) = Pubkey::find_program_address(
+            &[
+                b"vault",
+                owner.pubkey().as_ref(),
+                beneficiary.pubkey().as_ref(),
+                mint.as_ref(),
+            ],
+            &program_id,
+        );
+
+        let (vault_token_pda, _) =
+            Pubkey::find_program_address(&[b"vault_token", vault_pda.as_ref()], &program_id);
+
+        let (recovery_pda, _) =
+            Pubkey::find_program_address(&[b"recovery", vault_pda.as_ref()], &program_id);
+
+        Self {
+            svm,
+            program_id,
+            owner,
+            beneficiary,
+            guardians,
+            new_owner,
+            mint,
+            owner_ata,
+            vault_pda,
+            vault_token_pda,
+            recovery_pda,
+        }
+    }
+
+    fn send(&mut self, instruction: Instruction, signer: &Keypair) -> Result<(), String> {
+        // Expire the blockhash so repeated identical instructions are not deduplicated
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        self.svm
+            .send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn warp(&mut self, seconds: i64) {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp += seconds;
+        self.svm.set_sysvar(&clock);
+    }
+
+    fn vault_owner(&self) -> Pubkey {
+        let data = self.svm.get_account(&self.vault_pda).unwrap().data;
+        Pubkey::try_from(&data[8..40]).unwrap()
+    }
+
+    fn registry_vaults(&self, owner: &Pubkey) -> Vec<Pubkey> {
+        let (registry, _) =
+            Pubkey::find_program_address(&[b"owner_registry", owner.as_ref()], &self.program_id);
+        // 8 discriminator + owner, then a borsh Vec<Pubkey>
+        let data = self.svm.get_account(&registry).unwrap().data;
+        let len = u32::from_le_bytes(data[40..44].try_into().unwrap()) as usize;
+        (0..len)
+            .map(|i| Pubkey::try_from(&data[44 + 32 * i..76 + 32 * i]).unwrap())
+            .collect()
+    }
+
+    fn create_vault(&mut self) -> Result<(), String> {
+        let inactivity_period: i64 = 3600; // 1 hour (minimum)
+        let deposit_amount: u64 = 1_000_000;
+
+        let (owner_registry_pda, _) = Pubkey::find_program_address(
+            &[b"owner_registry", self.owner.pubkey().as_ref()],
+            &self.program_id,
+        );
+        let (beneficiary_index_pda, _) = Pubkey::find_program_address(
+            &[b"beneficiary_index", self.beneficiary.pubkey().as_ref()],
+            &self.program_id,
+        );
+        let (global_stats_pda, _) =
+            Pubkey::find_program_address(&[b"global_stats"], &self.program_id);
+        let (mint_stats_pda, _) =
+            Pubkey::find_program_address(&[b"mint_stats", self.mint.as_ref()], &self.program_id);
+
+        // create_vault discriminator: [29, 237, 247, 208, 193, 82, 54, 135]
+        let mut instruction_data = vec![29, 237, 247, 208, 193, 82, 54, 135];
+        instruction_data.extend_from_slice(&self.beneficiary.pubkey().to_bytes());
+        instruction_data.extend_from_slice(&inactivity_period.to_le_bytes());
+        instruction_data.extend_from_slice(&deposit_amount.to_le_bytes());
+        instruction_data.push(0); // acknowledge_freeze_authority
+        let (mint_policy_pda, _) =
+            Pubkey::find_program_address(&[b"mint_policy"], &self.program_id);
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.owner.pubkey(), true),
+                AccountMeta::new(self.vault_pda, false),
+                AccountMeta::new(self.vault_token_pda, false),
+                AccountMeta::new(self.owner_ata, false),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
+                AccountMeta::new(owner_registry_pda, false),
+                AccountMeta::new(beneficiary_index_pda, false),
+                AccountMeta::new(global_stats_pda, false),
+                AccountMeta::new(mint_stats_pda, false),
+                AccountMeta::new_readonly(mint_policy_pda, false),
+            ],
+            data: instruction_data,
+        };
+
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner)
+    }
+
+    fn set_guardians(&mut self, threshold: u8, timelock: i64) -> Result<(), String> {
+        // set_guardians discriminator: [166, 69, 140, 183, 157, 169, 253, 40]
+        let mut instruction_data = vec![166, 69, 140, 183, 157, 169, 253, 40];
+        instruction_data.extend_from_slice(&(self.guardians.len() as u32).to_le_bytes());
+        for guardian in &self.guardians {
+            instruction_data.extend_from_slice(&guardian.pubkey().to_bytes());
+        }
+        instruction_data.push(threshold);
+        instruction_data.extend_from_slice(&timelock.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.owner.pubkey(), true),
+                AccountMeta::new(self.vault_pda, false),
+                AccountMeta::new(self.recovery_pda, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: instruction_data,
+        };
+
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner)
+    }
+
+    fn approve_recovery(&mut self, guardian: &Keypair, new_owner: &Pubkey) -> Result<(), String> {
+        self.approve_recovery_with_switch(guardian, new_owner, None)
+    }
+
+    fn approve_recovery_with_switch(
+        &mut self,
+        guardian: &Keypair,
+        new_owner: &Pubkey,
+        switch: Option<Pubkey>,
+    ) -> Result<(), String> {
+        // approve_recovery discriminator: [148, 96, 41, 38, 108, 189, 129, 214]
+        let mut instruction_data = vec![148, 96, 41, 38, 108, 189, 129, 214];
+        instruction_data.extend_from_slice(&new_owner.to_bytes());
+
+        let mut accounts = vec![
+            AccountMeta::new_readonly(guardian.pubkey(), true),
+            AccountMeta::new_readonly(self.vault_pda, false),
+            AccountMeta::new(self.recovery_pda, false),
+        ];
+        if let Some(switch) = switch {
+            accounts.push(AccountMeta::new_readonly(switch, false));
+        }
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: instruction_data,
+        };
+
+        self.send(instruction, guardian)
+    }
+
+    fn execute_recovery(&mut self, signer: &Keypair) -> Result<(), String> {
+        self.execute_recovery_with_switch(signer, None)
+    }
+
+    fn execute_recovery_with_switch(
+        &mut self,
+        signer: &Keypair,
+        switch: Option<Pubkey>,
+    ) -> Result<(), String> {
+        // execute_recovery discriminator: [203, 133, 133, 228, 153, 121, 182, 237]
+        let (old_owner_registry_pda, _) = Pubkey::find_program_address(
+            &[b"owner_registry", self.owner.pubkey().as_ref()],
+            &self.program_id,
+        );
+        let (new_owner_registry_pda, _) = Pubkey::find_program_address(
+            &[b"owner_registry", signer.pubkey().as_ref()],
+            &self.program_id,
+        );
+
+        let mut accounts = vec![
+            AccountMeta::new(signer.pubkey(), true),
+            AccountMeta::new(self.vault_pda, false),
+            AccountMeta::new(self.recovery_pda, false),
+            AccountMeta::new(old_owner_registry_pda, false),
+            AccountMeta::new(new_owner_registry_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ];
+        if let Some(switch) = switch {
+            accounts.push(AccountMeta::new(switch, false));
+        }
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: vec![203, 133, 133, 228, 153, 121, 182, 237],
+        };
+
+        self.send(instruction, signer)
+    }
+
+    /// Creates the owner's master switch and links the vault to it
+    fn link_new_switch(&mut self) -> Pubkey {
+        let owner = self.owner.insecure_clone();
+        let (switch, _) =
+            Pubkey::find_program_address(&[b"switch", owner.pubkey().as_ref()], &self.program_id);
+
+        // create_switch discriminator: [120, 11, 193, 114, 80, 234, 99, 128]
+        let mut instruction_data = vec![120, 11, 193, 114, 80, 234, 99, 128];
+        instruction_data.extend_from_slice(&3600i64.to_le_bytes());
+        let create = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(owner.pubkey(), true),
+                AccountMeta::new(switch, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: instruction_data,
+        };
+        self.send(create, &owner).unwrap();
+
+        // link_switch discriminator: [136, 247, 62, 224, 223, 183, 48, 117]
+        let link = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(owner.pubkey(), true),
+                AccountMeta::new(self.vault_pda, false),
+                AccountMeta::new(switch, false),
+            ],
+            data: vec![136, 247, 62, 224, 223, 183, 48, 117],
+        };
+        self.send(link, &owner).unwrap();
+
+        switch
+    }
+
+    fn switch_heartbeat(&mut self, switch: &Pubkey) {
+        let owner = self.owner.insecure_clone();
+
+        // switch_heartbeat discriminator: [152, 58, 183, 196, 71, 231, 96, 5]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(*switch, false),
+                AccountMeta::new_readonly(owner.pubkey(), true),
+            ],
+            data: vec![152, 58, 183, 196, 71, 231, 96, 5],
+        };
+
+        self.send(instruction, &owner).unwrap();
+    }
+
+    /// Vault.switch and the switch's linked_vaults counter
+    fn switch_link(&self, switch: &Pubkey) -> (Pubkey, u32) {
+        let vault = self.svm.get_account(&self.vault_pda).unwrap().data;
+        let switch = self.svm.get_account(switch).unwrap().data;
+        (
+            Pubkey::try_from(&vault[168..200]).unwrap(),
+            u32::from_le_bytes(switch[56..60].try_into().unwrap()),
+        )
+    }
+
+    fn cancel_recovery(&mut self) -> Result<(), String> {
+        // cancel_recovery discriminator: [176, 23, 203, 37, 121, 251, 227, 83]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(self.owner.pubkey(), true),
+                AccountMeta::new(self.vault_pda, false),
+                AccountMeta::new(self.recovery_pda, false),
+            ],
+            data: vec![176, 23, 203, 37, 121, 251, 227, 83],
+        };
+
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner)
+    }
+
+    fn propose_owner(&mut self, new_owner: &Pubkey) -> Result<(), String> {
+        // propose_owner discriminator: [90, 57, 141, 110, 196, 241, 172, 39]
+        let mut instruction_data = vec![90, 57, 141, 110, 196, 241, 172, 39];
+        instruction_data.extend_from_slice(new_owner.as_ref());
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(self.owner.pubkey(), true),
+                AccountMeta::new(self.vault_pda, false),
+            ],
+            data: instruction_data,
+        };
+
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner)
+    }
+
+    fn accept_owner(&mut self, signer: &Keypair) -> Result<(), String> {
+        let (current_owner_registry_pda, _) = Pubkey::find_program_address(
+            &[b"owner_registry", self.vault_owner().as_ref()],
+            &self.program_id,
+        );
+        let (new_owner_registry_pda, _) = Pubkey::find_program_address(
+            &[b"owner_registry", signer.pubkey().as_ref()],
+            &self.program_id,
+        );
+
+        // accept_owner discriminator: [176, 23, 41, 28, 23, 111, 8, 4]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(signer.pubkey(), true),
+                AccountMeta::new(self.vault_pda, false),
+                AccountMeta::new(current_owner_registry_pda, false),
+                AccountMeta::new(new_owner_registry_pda, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: vec![176, 23, 41, 28, 23, 111, 8, 4],
+        };
+
+        self.send(instruction, signer)
+    }
+
+    fn heartbeat(&mut self, signer: &Keypair) -> Result<(), String> {
+        // heartbeat discriminator: [202, 104, 56, 6, 240, 170, 63, 134]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.vault_pda, false),
+                AccountMeta::new_readonly(signer.pubkey(), true),
+            ],
+            data: vec![202, 104, 56, 6, 240, 170, 63, 134],
+        };
+
+        self.send(instruction, signer)
+    }
+}
+
+#[test]
+fn test_guardian_recovery_rotates_owner_after_timelock() {
+    println!("🧪 Testing M-of-N guardian recovery");
+
+    let mut fixture = RecoveryTestFixture::new();
+    fixture.create_vault().unwrap();
+    fixture.set_guardians(2, 3600).unwrap();
+    println!("✅ Vault created with 2-of-3 guardians");
+
+    let new_owner = fixture.new_owner.pubkey();
+    let guardian_a = fixture.guardians[0].insecure_clone();
+    let guardian_b = fixture.guardians[1].insecure_clone();
+
+    fixture.approve_recovery(&guardian_a, &new_owner).unwrap();
+    let new_owner_keypair = fixture.new_owner.insecure_clone();
+    assert!(
+        fixture.execute_recovery(&new_owner_keypair).is_err(),
+        "Recovery should not execute below threshold"
+    );
+
+    fixture.approve_recovery(&guardian_b, &new_owner).unwrap();
+    assert!(
+        fixture.execute_recovery(&new_owner_keypair).is_err(),
+        "Recovery should not execute before the timelock"
+    );
+    println!("✅ Threshold and timelock enforced");
+
+    fixture.warp(3601);
+    fixture.execute_recovery(&new_owner_keypair).unwrap();
+    assert_eq!(fixture.vault_owner(), new_owner);
+    assert_eq!(fixture.registry_vaults(&new_owner), vec![fixture.vault_pda]);
+    let old_owner_key = fixture.owner.pubkey();
+    assert!(fixture.registry_vaults(&old_owner_key).is_empty());
+    println!("✅ Owner rotated to {}", new_owner);
+
+    // The new key controls the vault, the old one no longer does
+    fixture.heartbeat(&new_owner_keypair).unwrap();
+    let old_owner = fixture.owner.insecure_clone();
+    assert!(
+        fixture.heartbeat(&old_owner).is_err(),
+        "Old owner should lose control after rotation"
+    );
+    println!("✅ Heartbeat now requires the recovered key");
+}
+
+#[test]
+fn test_recovery_unlinks_lost_owner_switch() {
+    println!("🧪 Testing guardian recovery of a vault linked to a master switch");
+
+    let mut fixture = RecoveryTestFixture::new();
+    fixture.create_vault().unwrap();
+    fixture.set_guardians(2, 3600).unwrap();
+    let switch = fixture.link_new_switch();
+    assert_eq!(fixture.switch_link(&switch), (switch, 1));
+
+    let new_owner = fixture.new_owner.insecure_clone();
+    let guardian_a = fixture.guardians[0].insecure_clone();
+    let guardian_b = fixture.guardians[1].insecure_clone();
+    assert!(
+        fixture
+            .approve_recovery(&guardian_a, &new_owner.pubkey())
+            .is_err(),
+        "Approval on a linked vault should require its switch"
+    );
+    for guardian in [&guardian_a, &guardian_b] {
+        fixture
+            .approve_recovery_with_switch(guardian, &new_owner.pubkey(), Some(switch))
+            .unwrap();
+    }
+    fixture.warp(3601);
+
+    assert!(
+        fixture.execute_recovery(&new_owner).is_err(),
+        "Recovery of a linked vault should require its switch"
+    );
+    fixture
+        .execute_recovery_with_switch(&new_owner, Some(switch))
+        .unwrap();
+    assert_eq!(fixture.vault_owner(), new_owner.pubkey());
+    assert_eq!(fixture.switch_link(&switch), (Pubkey::default(), 0));
+    println!("✅ Lost key's switch released; the vault runs on its own timer");
+
+    fixture.heartbeat(&new_owner).unwrap();
+    println!("✅ Recovered owner keeps the vault alive directly");
+}
+
+#[test]
+fn test_owner_heartbeat_cancels_recovery() {
+    println!("🧪 Testing that any owner signature cancels a pending rotation");
+
+    let mut fixture = RecoveryTestFixture::new();
+    fixture.create_vault().unwrap();
+    fixture.set_guardians(2, 3600).unwrap();
+
+    let new_owner = fixture.new_owner.pubkey();
+    let guardian_a = fixture.guardians[0].insecure_clone();
+    let guardian_b = fixture.guardians[1].insecure_clone();
+    fixture.approve_recovery(&guardian_a, &new_owner).unwrap();
+    fixture.approve_recovery(&guardian_b, &new_owner).unwrap();
+
+    // The owner is alive and checks in while the timelock is running
+    fixture.warp(60);
+    let owner = fixture.owner.insecure_clone();
+    fixture.heartbeat(&owner).unwrap();
+
+    fixture.warp(3601);
+    let new_owner_keypair = fixture.new_owner.insecure_clone();
+    assert!(
+        fixture.execute_recovery(&new_owner_keypair).is_err(),
+        "Recovery should be void after owner activity"
+    );
+    assert_eq!(fixture.vault_owner(), owner.pubkey());
+    println!("✅ Owner heartbeat voided the rotation");
+}
+
+#[test]
+fn test_owner_can_cancel_recovery() {
+    println!("🧪 Testing explicit recovery cancellation");
+
+    let mut fixture = RecoveryTestFixture::new();
+    fixture.create_vault().unwrap();
+    fixture.set_guardians(1, 3600).unwrap();
+
+    let new_owner = fixture.new_owner.pubkey();
+    let guardian_a = fixture.guardians[0].insecure_clone();
+    fixture.approve_recovery(&guardian_a, &new_owner).unwrap();
+    fixture.cancel_recovery().unwrap();
+
+    fixture.warp(3601);
+    let new_owner_keypair = fixture.new_owner.insecure_clone();
+    assert!(
+        fixture.execute_recovery(&new_owner_keypair).is_err(),
+        "Cancelled recovery should not execute"
+    );
+    println!("✅ Cancelled recovery rejected");
+}
+
+#[test]
+fn test_non_guardian_cannot_approve() {
+    println!("🧪 Testing recovery approval by a non-guardian");
+
+    let mut fixture = RecoveryTestFixture::new();
+    fixture.create_vault().unwrap();
+    fixture.set_guardians(1, 3600).unwrap();
+
+    let new_owner = fixture.new_owner.pubkey();
+    let beneficiary = fixture.beneficiary.insecure_clone();
+    assert!(
+        fixture.approve_recovery(&beneficiary, &new_owner).is_err(),
+        "Non-guardian approval should fail"
+    );
+    println!("✅ Non-guardian approval rejected");
+}
+
+#[test]
+fn test_invalid_guardian_configuration() {
+    println!("🧪 Testing guardian configuration validation");
+
+    let mut fixture = RecoveryTestFixture::new();
+    fixture.create_vault().unwrap();
+
+    assert!(
+        fixture.set_guardians(0, 3600).is_err(),
+        "Zero threshold should fail"
+    );
+    assert!(
+        fixture.set_guardians(4, 3600).is_err(),
+        "Threshold above guardian count should fail"
+    );
+    assert!(
+        fixture.set_guardians(2, 60).is_err(),
+        "Timelock below the minimum should fail"
+    );
+    println!("✅ Invalid guardian configurations rejected");
+}
+
+#[test]
+fn test_switch_heartbeat_voids_rotation_and_guardians_restart() {
+    println!("🧪 Testing a rotation voided through the master switch");
+
+    let mut fixture = RecoveryTestFixture::new();
+    fixture.create_vault().unwrap();
+    fixture.set_guardians(2, 3600).unwrap();
+    let switch = fixture.link_new_switch();
+
+    let new_owner = fixture.new_owner.insecure_clone();
+    let guardian_a = fixture.guardians[0].insecure_clone();
+    let guardian_b = fixture.guardians[1].insecure_clone();
+    for guardian in [&guardian_a, &guardian_b] {
+        fixture
+            .approve_recovery_with_switch(guardian, &new_owner.pubkey(), Some(switch))
+            .unwrap();
+    }
+
+    // The owner pings the switch, then loses the key
+    fixture.warp(60);
+    fixture.switch_heartbeat(&switch);
+    fixture.warp(3601);
+    assert!(
+        fixture
+            .execute_recovery_with_switch(&new_owner, Some(switch))
+            .is_err(),
+        "Switch heartbeat should void the rotation"
+    );
+    println!("✅ Rotation voided by the switch heartbeat");
+
+    // Guardians can start a fresh rotation, even for a different owner
+    let replacement = Keypair::new();
+    fixture
+        .svm
+        .airdrop(&replacement.pubkey(), 1_000_000_000)
+        .unwrap();
+    for guardian in [&guardian_a, &guardian_b] {
+        fixture
+            .approve_recovery_with_switch(guardian, &replacement.pubkey(), Some(switch))
+            .unwrap();
+    }
+    fixture.warp(3601);
+    fixture
+        .execute_recovery_with_switch(&replacement, Some(switch))
+        .unwrap();
+    assert_eq!(fixture.vault_owner(), replacement.pubkey());
+    println!("✅ Restarted rotation completed");
+}
+
+#[test]
+fn test_recovery_drops_handoff_proposed_by_lost_key() {
+    println!("🧪 Testing that recovery discards a pending ownership handoff");
+
+    let mut fixture = RecoveryTestFixture::new();
+    fixture.create_vault().unwrap();
+    fixture.set_guardians(2, 3600).unwrap();
+
+    // The compromised key proposes a handoff to the attacker before going quiet
+    let attacker = Keypair::new();
+    fixture
+        .svm
+        .airdrop(&attacker.pubkey(), 1_000_000_000)
+        .unwrap();
+    fixture.propose_owner(&attacker.pubkey()).unwrap();
+
+    fixture.warp(60);
+    let new_owner = fixture.new_owner.insecure_clone();
+    let guardian_a = fixture.guardians[0].insecure_clone();
+    let guardian_b = fixture.guardians[1].insecure_clone();
+    fixture
+        .approve_recovery(&guardian_a, &new_owner.pubkey())
+        .unwrap();
+    fixture
+        .approve_recovery(&guardian_b, &new_owner.pubkey())
+        .unwrap();
+    fixture.warp(3601);
+    fixture.execute_recovery(&new_owner).unwrap();
+    println!("✅ Guardians rotated the owner");
+
+    assert!(
+        fixture.accept_owner(&attacker).is_err(),
+        "Handoff proposed by the lost key should not survive recovery"
+    );
+    assert_eq!(fixture.vault_owner(), new_owner.pubkey());
+    println!("✅ Stale handoff cannot take the vault back");
+}
@@ -0,0 +1,381 @@
+use litesvm::LiteSVM;
+use litesvm_token::{spl_token, CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+
+/// Test fixture for owner registry and beneficiary index tests
+struct RegistryTestFixture {
+    svm: LiteSVM,
+    program_id: Pubkey,
+    mint_authority: Keypair,
+    mint: Pubkey,
+}
+
+/// PDAs for one (owner, beneficiary) vault
+struct VaultAccounts {
+    vault: Pubkey,
+    vault_token: Pubkey,
+    owner_registry: Pubkey,
+    beneficiary_index: Pubkey,
+    global_stats: Pubkey,
+    mint_stats: Pubkey,
+}
+
+impl RegistryTestFixture {
+    fn new() -> Self {
+        let mut svm = LiteSVM::new();
+
+        // Load the compiled program
+        let program_keypair =
+            read_keypair_file("target/deploy/cyber_vault_rs-keypair.json").unwrap();
+        let program_id = program_keypair.pubkey();
+        let program_bytes = include_bytes!("../target/deploy/cyber_vault_rs.so");
+        svm.add_program(program_id, program_bytes);
+
+        let mint_authority = Keypair::new();
+        svm.airdrop(&mint_authority.pubkey(), 10_000_000_000)
+            .unwrap();
+
+        let mint = CreateMint::new(&mut svm, &mint_authority)
+            .authority(&mint_authority.pubkey())
+            .decimals(6)
+            .send()
+            .unwrap();
+
+        Self {
+            svm,
+            program_id,
+            mint_authority,
+            mint,
+        }
+    }
+
+    fn funded_user(&mut self) -> (Keypair, Pubkey) {
+        let user = Keypair::new();
+        self.svm.airdrop(&user.pubkey(), 10_000_000_000).unwrap();
+        let ata = CreateAssociatedTokenAccount::new(&mut self.svm, &user, &self.mint)
+            .owner(&user.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(
+            &mut self.svm,
+            &self.mint_authority,
+            &self.mint,
+            &ata,
+            5_000_000,
+        )
+        .send()
+        .unwrap();
+        (user, ata)
+    }
+
+    fn accounts(&self, owner: &Pubkey, beneficiary: &Pubkey) -> VaultAccounts {
+        let (vault, _) = Pubkey::find_program_address(
+            &[
+                b"vault",
+                owner.as_ref(),
+                beneficiary.as_ref(),
+                self.mint.as_ref(),
+            ],
+            &self.program_id,
+        );
+        let (vault_token, _) =
+            Pubkey::find_program_address(&[b"vault_token", vault.as_ref()], &self.program_id);
+        let (owner_registry, _) =
+            Pubkey::find_program_address(&[b"owner_registry", owner.as_ref()], &self.program_id);
+        let (beneficiary_index, _) = Pubkey::find_program_address(
+            &[b"beneficiary_index", beneficiary.as_ref()],
+            &self.program_id,
+        );
+        let (global_stats, _) = Pubkey::find_program_address(&[b"global_stats"], &self.program_id);
+        let (mint_stats, _) =
+            Pubkey::find_program_address(&[b"mint_stats", self.mint.as_ref()], &self.program_id);
+        VaultAccounts {
+            vault,
+            vault_token,
+            owner_registry,
+            beneficiary_index,
+            global_stats,
+            mint_stats,
+        }
+    }
+
+    fn send(&mut self, instruction: Instruction, signer: &Keypair) -> Result<(), String> {
+        // Expire the blockhash so repeated identical instructions are not deduplicated
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        self.svm
+            .send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    /// Reads the Vec<Pubkey> stored after the 8-byte discriminator and the key field
+    fn listed_vaults(&self, registry: &Pubkey) -> Vec<Pubkey> {
+        let data = self.svm.get_account(registry).unwrap().data;
+        let len = u32::from_le_bytes(data[40..44].try_into().unwrap()) as usize;
+        (0..len)
+            .map(|i| Pubkey::try_from(&data[44 + 32 * i..76 + 32 * i]).unwrap())
+            .collect()
+    }
+
+    fn create_vault(
+        &mut self,
+        owner: &Keypair,
+        owner_ata: &Pubkey,
+        beneficiary: &Pubkey,
+        amount: u64,
+    ) -> VaultAccounts {
+        let accounts = self.accounts(&owner.pubkey(), beneficiary);
+        let inactivity_period: i64 = 3600;
+
+        // create_vault discriminator: [29, 237, 247, 208, 193, 82, 54, 135]
+        let mut instruction_data = vec![29, 237, 247, 208, 193, 82, 54, 135];
+        instruction_data.extend_from_slice(&beneficiary.to_bytes());
+        instruction_data.extend_from_slice(&inactivity_period.to_le_bytes());
+        instruction_data.extend_from_slice(&amount.to_le_bytes());
+        instruction_data.push(0); // acknowledge_freeze_authority
+        let (mint_policy_pda, _) =
+            Pubkey::find_program_address(&[b"mint_policy"], &self.program_id);
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(owner.pubkey(), true),
+                AccountMeta::new(accounts.vault, false),
+                AccountMeta::new(accounts.vault_token, false),
+                AccountMeta::new(*owner_ata, false),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
+                AccountMeta::new(accounts.owner_registry, false),
+                AccountMeta::new(accounts.beneficiary_index, false),
+                AccountMeta::new(accounts.global_stats, false),
+                AccountMeta::new(accounts.mint_stats, false),
+                AccountMeta::new_readonly(mint_policy_pda, false),
+            ],
+            data: instruction_data,
+        };
+
+        self.send(instruction, owner).unwrap();
+        accounts
+    }
+
+    fn claim(
+        &mut self,
+        accounts: &VaultAccounts,
+        beneficiary: &Keypair,
+        beneficiary_ata: &Pubkey,
+    ) -> Result<(), String> {
+        // claim discriminator: [62, 198, 214, 193, 213, 159, 108, 210]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(accounts.vault, false),
+                AccountMeta::new(accounts.vault_token, false),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new(*beneficiary_ata, false),
+                AccountMeta::new(beneficiary.pubkey(), true),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new(accounts.owner_registry, false),
+                AccountMeta::new(accounts.beneficiary_index, false),
+                AccountMeta::new(accounts.global_stats, false),
+                AccountMeta::new(accounts.mint_stats, false),
+            ],
+            data: vec![62, 198, 214, 193, 213, 159, 108, 210],
+        };
+
+        self.send(instruction, beneficiary)
+    }
+
+    fn emergency_withdraw(
+        &mut self,
+        accounts: &VaultAccounts,
+        owner: &Keypair,
+        owner_ata: &Pubkey,
+        amount: u64,
+    ) -> Result<(), String> {
+        // emergency_withdraw discriminator: [239, 45, 203, 64, 150, 73, 218, 92]
+        let mut instruction_data = vec![239, 45, 203, 64, 150, 73, 218, 92];
+        instruction_data.extend_from_slice(&amount.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(owner.pubkey(), true),
+                AccountMeta::new(accounts.vault, false),
+                AccountMeta::new(*owner_ata, false),
+                AccountMeta::new(accounts.vault_token, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new(accounts.mint_stats, false),
+            ],
+            data: instruction_data,
+        };
+
+        self.send(instruction, owner)
+    }
+
+    fn close_vault(&mut self, accounts: &VaultAccounts, owner: &Keypair) -> Result<(), String> {
+        // close_vault discriminator: [141, 103, 17, 126, 72, 75, 29, 29]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(owner.pubkey(), true),
+                AccountMeta::new(accounts.vault, false),
+                AccountMeta::new(accounts.vault_token, false),
+                AccountMeta::new(accounts.owner_registry, false),
+                AccountMeta::new(accounts.beneficiary_index, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new(accounts.global_stats, false),
+                AccountMeta::new(accounts.mint_stats, false),
+            ],
+            data: vec![141, 103, 17, 126, 72, 75, 29, 29],
+        };
+
+        self.send(instruction, owner)
+    }
+}
+
+#[test]
+fn test_create_indexes_vault_by_owner_and_beneficiary() {
+    println!("🧪 Testing vault enumeration from the registries");
+
+    let mut fixture = RegistryTestFixture::new();
+    let (alice, alice_ata) = fixture.funded_user();
+    let (bob, bob_ata) = fixture.funded_user();
+    let heir_one = Pubkey::new_unique();
+    let heir_two = Pubkey::new_unique();
+
+    let alice_one = fixture.create_vault(&alice, &alice_ata, &heir_one, 1_000_000);
+    let alice_two = fixture.create_vault(&alice, &alice_ata, &heir_two, 1_000_000);
+    let bob_one = fixture.create_vault(&bob, &bob_ata, &heir_one, 1_000_000);
+
+    let alice_vaults = fixture.listed_vaults(&alice_one.owner_registry);
+    assert_eq!(alice_vaults, vec![alice_one.vault, alice_two.vault]);
+    println!("✅ Owner registry lists both of the owner's vaults");
+
+    let inherited = fixture.listed_vaults(&alice_one.beneficiary_index);
+    assert_eq!(inherited, vec![alice_one.vault, bob_one.vault]);
+    println!("✅ Beneficiary index lists vaults across owners");
+}
+
+#[test]
+fn test_claim_removes_vault_from_registries() {
+    println!("🧪 Testing registry cleanup on claim");
+
+    let mut fixture = RegistryTestFixture::new();
+    let (owner, owner_ata) = fixture.funded_user();
+    let (heir, heir_ata) = fixture.funded_user();
+    let other_heir = Pubkey::new_unique();
+
+    let inherited = fixture.create_vault(&owner, &owner_ata, &heir.pubkey(), 1_000_000);
+    let kept = fixture.create_vault(&owner, &owner_ata, &other_heir, 1_000_000);
+
+    let mut clock = fixture.svm.get_sysvar::<Clock>();
+    clock.unix_timestamp += 3600;
+    fixture.svm.set_sysvar(&clock);
+
+    fixture.claim(&inherited, &heir, &heir_ata).unwrap();
+
+    assert_eq!(
+        fixture.listed_vaults(&kept.owner_registry),
+        vec![kept.vault]
+    );
+    assert!(fixture
+        .listed_vaults(&inherited.beneficiary_index)
+        .is_empty());
+    println!("✅ Claimed vault removed from both registries");
+}
+
+#[test]
+fn test_close_vault_removes_vault_from_registries() {
+    println!("🧪 Testing owner close of an emptied vault");
+
+    let mut fixture = RegistryTestFixture::new();
+    let (owner, owner_ata) = fixture.funded_user();
+    let heir = Pubkey::new_unique();
+    let accounts = fixture.create_vault(&owner, &owner_ata, &heir, 1_000_000);
+
+    assert!(
+        fixture.close_vault(&accounts, &owner).is_err(),
+        "Closing a vault that still holds tokens should fail"
+    );
+
+    fixture
+        .emergency_withdraw(&accounts, &owner, &owner_ata, 1_000_000)
+        .unwrap();
+    fixture.close_vault(&accounts, &owner).unwrap();
+
+    assert!(fixture.svm.get_account(&accounts.vault).is_none());
+    assert!(fixture.svm.get_account(&accounts.vault_token).is_none());
+    assert!(fixture.listed_vaults(&accounts.owner_registry).is_empty());
+    assert!(fixture
+        .listed_vaults(&accounts.beneficiary_index)
+        .is_empty());
+    println!("✅ Closed vault removed from both registries");
+}
+
+#[test]
+fn test_only_owner_can_close_vault() {
+    println!("🧪 Testing close by a non-owner");
+
+    let mut fixture = RegistryTestFixture::new();
+    let (owner, owner_ata) = fixture.funded_user();
+    let (stranger, _) = fixture.funded_user();
+    let heir = Pubkey::new_unique();
+    let accounts = fixture.create_vault(&owner, &owner_ata, &heir, 1_000_000);
+    fixture
+        .emergency_withdraw(&accounts, &owner, &owner_ata, 1_000_000)
+        .unwrap();
+
+    assert!(
+        fixture.close_vault(&accounts, &stranger).is_err(),
+        "Non-owner close should fail"
+    );
+    println!("✅ Non-owner close rejected");
+}
+
+#[test]
+fn test_full_beneficiary_index_does_not_block_create() {
+    println!("🧪 Testing vault creation against a full beneficiary index");
+
+    let mut fixture = RegistryTestFixture::new();
+    let heir = Pubkey::new_unique();
+
+    // Strangers fill the heir's index with dust vaults
+    let mut first = None;
+    for _ in 0..32 {
+        let (stranger, stranger_ata) = fixture.funded_user();
+        let accounts = fixture.create_vault(&stranger, &stranger_ata, &heir, 0);
+        first.get_or_insert(accounts.beneficiary_index);
+    }
+    let beneficiary_index = first.unwrap();
+    assert_eq!(fixture.listed_vaults(&beneficiary_index).len(), 32);
+
+    let (owner, owner_ata) = fixture.funded_user();
+    let accounts = fixture.create_vault(&owner, &owner_ata, &heir, 1_000_000);
+
+    assert!(fixture.svm.get_account(&accounts.vault).is_some());
+    assert_eq!(
+        fixture.listed_vaults(&accounts.owner_registry),
+        vec![accounts.vault]
+    );
+    let listed = fixture.listed_vaults(&beneficiary_index);
+    assert_eq!(listed.len(), 32);
+    assert!(!listed.contains(&accounts.vault));
+    println!("✅ Vault created even though the beneficiary index is full");
+}
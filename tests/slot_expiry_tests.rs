@@ -0,0 +1,338 @@
+use litesvm::LiteSVM;
+use litesvm_token::{spl_token, CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+
+const TIMESTAMP_MODE: u8 = 0;
+const SLOT_MODE: u8 = 1;
+const SLOT_PERIOD: i64 = 9000;
+
+/// Test fixture for slot-based and timestamp-based expiry
+struct SlotExpiryTestFixture {
+    svm: LiteSVM,
+    program_id: Pubkey,
+    owner: Keypair,
+    beneficiary: Keypair,
+    mint: Pubkey,
+    vault_pda: Pubkey,
+    vault_token_pda: Pubkey,
+    owner_registry_pda: Pubkey,
+    beneficiary_index_pda: Pubkey,
+    global_stats_pda: Pubkey,
+    mint_stats_pda: Pubkey,
+}
+
+impl SlotExpiryTestFixture {
+    fn new() -> Self {
+        let mut svm = LiteSVM::new();
+
+        // Load the compiled program
+        let program_keypair =
+            read_keypair_file("target/deploy/cyber_vault_rs-keypair.json").unwrap();
+        let program_id = program_keypair.pubkey();
+        let program_bytes = include_bytes!("../target/deploy/cyber_vault_rs.so");
+        svm.add_program(program_id, program_bytes);
+
+        let owner = Keypair::new();
+        let beneficiary = Keypair::new();
+        svm.airdrop(&owner.pubkey(), 10_000_000_000).unwrap();
+        svm.airdrop(&beneficiary.pubkey(), 10_000_000_000).unwrap();
+
+        let mint = CreateMint::new(&mut svm, &owner)
+            .authority(&owner.pubkey())
+            .decimals(6)
+            .send()
+            .unwrap();
+        let owner_ata = CreateAssociatedTokenAccount::new(&mut svm, &owner, &mint)
+            .owner(&owner.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &owner, &mint, &owner_ata, 1_000_000)
+            .send()
+            .unwrap();
+
+        let (vault_pda, _) = Pubkey::find_program_address(
+            &[
+                b"vault",
+                owner.pubkey().as_ref(),
+                beneficiary.pubkey().as_ref(),
+                mint.as_ref(),
+            ],
+            &program_id,
+        );
+        let (vault_token_pda, _) =
+            Pubkey::find_program_address(&[b"vault_token", vault_pda.as_ref()], &program_id);
+        let (owner_registry_pda, _) = Pubkey::find_program_address(
+            &[b"owner_registry", owner.pubkey().as_ref()],
+            &program_id,
+        );
+        let (beneficiary_index_pda, _) = Pubkey::find_program_address(
+            &[b"beneficiary_index", beneficiary.pubkey().as_ref()],
+            &program_id,
+        );
+        let (global_stats_pda, _) = Pubkey::find_program_address(&[b"global_stats"], &program_id);
+        let (mint_stats_pda, _) =
+            Pubkey::find_program_address(&[b"mint_stats", mint.as_ref()], &program_id);
+
+        let mut fixture = Self {
+            svm,
+            program_id,
+            owner,
+            beneficiary,
+            mint,
+            vault_pda,
+            vault_token_pda,
+            owner_registry_pda,
+            beneficiary_index_pda,
+            global_stats_pda,
+            mint_stats_pda,
+        };
+
+        // create_vault discriminator: [29, 237, 247, 208, 193, 82, 54, 135]
+        let mut instruction_data = vec![29, 237, 247, 208, 193, 82, 54, 135];
+        instruction_data.extend_from_slice(&fixture.beneficiary.pubkey().to_bytes());
+        instruction_data.extend_from_slice(&3600i64.to_le_bytes());
+        instruction_data.extend_from_slice(&1_000_000u64.to_le_bytes());
+        instruction_data.push(0); // acknowledge_freeze_authority
+        let (mint_policy_pda, _) = Pubkey::find_program_address(&[b"mint_policy"], &program_id);
+        let instruction = Instruction {
+            program_id,
+            accounts: vec![
+                AccountMeta::new(fixture.owner.pubkey(), true),
+                AccountMeta::new(vault_pda, false),
+                AccountMeta::new(vault_token_pda, false),
+                AccountMeta::new(owner_ata, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
+                AccountMeta::new(owner_registry_pda, false),
+                AccountMeta::new(beneficiary_index_pda, false),
+                AccountMeta::new(global_stats_pda, false),
+                AccountMeta::new(mint_stats_pda, false),
+                AccountMeta::new_readonly(mint_policy_pda, false),
+            ],
+            data: instruction_data,
+        };
+        let owner = fixture.owner.insecure_clone();
+        fixture.send(instruction, &owner).unwrap();
+
+        fixture
+    }
+
+    fn send(&mut self, instruction: Instruction, signer: &Keypair) -> Result<(), String> {
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        self.svm
+            .send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn owner_vault_accounts(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.owner.pubkey(), true),
+            AccountMeta::new(self.vault_pda, false),
+        ]
+    }
+
+    fn set_expiry_mode(&mut self, mode: u8, inactivity_period: i64) -> Result<(), String> {
+        // set_expiry_mode discriminator: [204, 200, 36, 228, 151, 37, 215, 47]
+        let mut instruction_data = vec![204, 200, 36, 228, 151, 37, 215, 47];
+        instruction_data.push(mode);
+        instruction_data.extend_from_slice(&inactivity_period.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: self.owner_vault_accounts(),
+            data: instruction_data,
+        };
+
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner)
+    }
+
+    fn schedule_absence(&mut self, start: i64, end: i64) -> Result<(), String> {
+        // schedule_absence discriminator: [62, 170, 61, 17, 196, 83, 148, 227]
+        let mut instruction_data = vec![62, 170, 61, 17, 196, 83, 148, 227];
+        instruction_data.extend_from_slice(&start.to_le_bytes());
+        instruction_data.extend_from_slice(&end.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: self.owner_vault_accounts(),
+            data: instruction_data,
+        };
+
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner)
+    }
+
+    fn heartbeat(&mut self) -> Result<(), String> {
+        // heartbeat discriminator: [202, 104, 56, 6, 240, 170, 63, 134]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.vault_pda, false),
+                AccountMeta::new_readonly(self.owner.pubkey(), true),
+            ],
+            data: vec![202, 104, 56, 6, 240, 170, 63, 134],
+        };
+
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner)
+    }
+
+    fn claim(&mut self) -> Result<(), String> {
+        let beneficiary = self.beneficiary.insecure_clone();
+        let beneficiary_ata = get_associated_token_address(&beneficiary.pubkey(), &self.mint);
+
+        // claim discriminator: [62, 198, 214, 193, 213, 159, 108, 210]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.vault_pda, false),
+                AccountMeta::new(self.vault_token_pda, false),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new(beneficiary_ata, false),
+                AccountMeta::new(beneficiary.pubkey(), true),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new(self.owner_registry_pda, false),
+                AccountMeta::new(self.beneficiary_index_pda, false),
+                AccountMeta::new(self.global_stats_pda, false),
+                AccountMeta::new(self.mint_stats_pda, false),
+            ],
+            data: vec![62, 198, 214, 193, 213, 159, 108, 210],
+        };
+
+        self.send(instruction, &beneficiary)
+    }
+
+    /// Moves only the slot; the timestamp stays where it is
+    fn warp_slots(&mut self, slots: u64) {
+        let clock = self.svm.get_sysvar::<Clock>();
+        self.svm.warp_to_slot(clock.slot + slots);
+        let mut warped = self.svm.get_sysvar::<Clock>();
+        warped.unix_timestamp = clock.unix_timestamp;
+        self.svm.set_sysvar(&warped);
+    }
+
+    /// Moves only the timestamp; the slot stays where it is
+    fn warp_seconds(&mut self, seconds: i64) {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp += seconds;
+        self.svm.set_sysvar(&clock);
+    }
+
+    fn last_heartbeat_slot(&self) -> u64 {
+        let data = self.svm.get_account(&self.vault_pda).unwrap().data;
+        u64::from_le_bytes(data[376..384].try_into().unwrap())
+    }
+}
+
+#[test]
+fn test_slot_mode_ignores_timestamp() {
+    println!("🧪 Testing slot-mode expiry against timestamp skew");
+
+    let mut fixture = SlotExpiryTestFixture::new();
+    fixture.set_expiry_mode(SLOT_MODE, SLOT_PERIOD).unwrap();
+
+    fixture.warp_seconds(365 * 86_400);
+    assert!(
+        fixture.claim().is_err(),
+        "Timestamp jump should not expire a slot-mode vault"
+    );
+
+    fixture.warp_slots(SLOT_PERIOD as u64 - 1);
+    assert!(fixture.claim().is_err());
+    println!("✅ Not expired before the slot count runs out");
+
+    fixture.warp_slots(1);
+    fixture.claim().unwrap();
+    println!("✅ Expired after the slot count");
+}
+
+#[test]
+fn test_timestamp_mode_ignores_slots() {
+    println!("🧪 Testing timestamp-mode expiry against slot warps");
+
+    let mut fixture = SlotExpiryTestFixture::new();
+
+    fixture.warp_slots(1_000_000);
+    assert!(
+        fixture.claim().is_err(),
+        "Slot jump should not expire a timestamp-mode vault"
+    );
+
+    fixture.warp_seconds(3600);
+    fixture.claim().unwrap();
+    println!("✅ Timestamp mode unaffected by slots");
+}
+
+#[test]
+fn test_heartbeat_records_slot() {
+    println!("🧪 Testing that heartbeats reset the slot timer");
+
+    let mut fixture = SlotExpiryTestFixture::new();
+    fixture.set_expiry_mode(SLOT_MODE, SLOT_PERIOD).unwrap();
+
+    fixture.warp_slots(8000);
+    fixture.heartbeat().unwrap();
+    let slot = fixture.svm.get_sysvar::<Clock>().slot;
+    assert_eq!(fixture.last_heartbeat_slot(), slot);
+
+    fixture.warp_slots(8000);
+    assert!(
+        fixture.claim().is_err(),
+        "Claim should count slots from the latest heartbeat"
+    );
+    println!("✅ Slot timer restarted by heartbeat");
+
+    // Switching back restores the seconds-based timer
+    fixture.set_expiry_mode(TIMESTAMP_MODE, 3600).unwrap();
+    fixture.warp_slots(SLOT_PERIOD as u64);
+    assert!(fixture.claim().is_err());
+    fixture.warp_seconds(3600);
+    fixture.claim().unwrap();
+    println!("✅ Timestamp mode restored");
+}
+
+#[test]
+fn test_expiry_mode_validation() {
+    println!("🧪 Testing expiry mode validation");
+
+    let mut fixture = SlotExpiryTestFixture::new();
+    assert!(fixture.set_expiry_mode(SLOT_MODE, SLOT_PERIOD - 1).is_err());
+    assert!(fixture.set_expiry_mode(TIMESTAMP_MODE, 3599).is_err());
+    println!("✅ Short periods rejected");
+
+    let now = fixture.svm.get_sysvar::<Clock>().unix_timestamp;
+    fixture.schedule_absence(now + 100, now + 86_400).unwrap();
+    assert!(
+        fixture.set_expiry_mode(SLOT_MODE, SLOT_PERIOD).is_err(),
+        "Slot mode with a pending absence should fail"
+    );
+
+    fixture.warp_seconds(86_400);
+    fixture.set_expiry_mode(SLOT_MODE, SLOT_PERIOD).unwrap();
+    let now = fixture.svm.get_sysvar::<Clock>().unix_timestamp;
+    assert!(
+        fixture.schedule_absence(now + 100, now + 86_400).is_err(),
+        "Absence on a slot-mode vault should fail"
+    );
+    println!("✅ Absence windows require timestamp mode");
+}
@@ -0,0 +1,245 @@
+use litesvm::LiteSVM;
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_program, sysvar,
+    transaction::Transaction,
+};
+use solana_stake_interface::{
+    instruction as stake_instruction, program as stake_program,
+    state::{Authorized, Lockup},
+};
+
+// VaultError codes
+const INVALID_HANDOFF_TARGET: u32 = 6050;
+
+// HandoffAuthority::Stake
+const STAKE: u8 = 5;
+
+const STAKE_LAMPORTS: u64 = 5_000_000_000;
+
+/// Test fixture for vaults holding the authorities of a native stake account
+struct StakeVaultTestFixture {
+    svm: LiteSVM,
+    program_id: Pubkey,
+    owner: Keypair,
+    beneficiary: Keypair,
+    stake: Pubkey,
+    authority_vault_pda: Pubkey,
+}
+
+impl StakeVaultTestFixture {
+    fn new() -> Self {
+        let mut svm = LiteSVM::new();
+
+        // Load the compiled program
+        let program_keypair =
+            read_keypair_file("target/deploy/cyber_vault_rs-keypair.json").unwrap();
+        let program_id = program_keypair.pubkey();
+        let program_bytes = include_bytes!("../target/deploy/cyber_vault_rs.so");
+        svm.add_program(program_id, program_bytes);
+
+        let owner = Keypair::new();
+        let beneficiary = Keypair::new();
+        svm.airdrop(&owner.pubkey(), 10_000_000_000).unwrap();
+        svm.airdrop(&beneficiary.pubkey(), 1_000_000_000).unwrap();
+
+        // A stake account with the owner as both staker and withdrawer
+        let stake = Keypair::new();
+        let instructions = stake_instruction::create_account(
+            &owner.pubkey(),
+            &stake.pubkey(),
+            &Authorized::auto(&owner.pubkey()),
+            &Lockup::default(),
+            STAKE_LAMPORTS,
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &instructions,
+            Some(&owner.pubkey()),
+            &[&owner, &stake],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+
+        let (authority_vault_pda, _) = Pubkey::find_program_address(
+            &[b"authority_vault", stake.pubkey().as_ref(), &[STAKE]],
+            &program_id,
+        );
+
+        Self {
+            svm,
+            program_id,
+            owner,
+            beneficiary,
+            stake: stake.pubkey(),
+            authority_vault_pda,
+        }
+    }
+
+    fn send(&mut self, instruction: Instruction, signer: &Keypair) -> Result<(), String> {
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        self.svm
+            .send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn create_stake_vault(&mut self, with_clock: bool) -> Result<(), String> {
+        let owner = self.owner.insecure_clone();
+
+        // create_authority_vault discriminator: [173, 158, 69, 243, 172, 21, 227, 171]
+        let mut instruction_data = vec![173, 158, 69, 243, 172, 21, 227, 171];
+        instruction_data.extend_from_slice(self.beneficiary.pubkey().as_ref());
+        instruction_data.extend_from_slice(&3600i64.to_le_bytes());
+        instruction_data.push(STAKE);
+
+        let mut accounts = vec![
+            AccountMeta::new(owner.pubkey(), true),
+            AccountMeta::new(self.authority_vault_pda, false),
+            AccountMeta::new(self.stake, false),
+            AccountMeta::new_readonly(stake_program::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ];
+        if with_clock {
+            accounts.push(AccountMeta::new_readonly(sysvar::clock::id(), false));
+        }
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: instruction_data,
+        };
+
+        self.send(instruction, &owner)
+    }
+
+    /// Sends reclaim_authority as the owner or claim_authority as the beneficiary
+    fn hand_back(&mut self, to_beneficiary: bool) -> Result<(), String> {
+        let signer = if to_beneficiary {
+            self.beneficiary.insecure_clone()
+        } else {
+            self.owner.insecure_clone()
+        };
+        let data = if to_beneficiary {
+            // claim_authority discriminator: [222, 132, 185, 123, 127, 107, 6, 31]
+            vec![222, 132, 185, 123, 127, 107, 6, 31]
+        } else {
+            // reclaim_authority discriminator: [156, 147, 67, 113, 251, 229, 162, 166]
+            vec![156, 147, 67, 113, 251, 229, 162, 166]
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(signer.pubkey(), true),
+                AccountMeta::new(self.authority_vault_pda, false),
+                AccountMeta::new(self.stake, false),
+                AccountMeta::new_readonly(stake_program::id(), false),
+                AccountMeta::new_readonly(sysvar::clock::id(), false),
+            ],
+            data,
+        };
+
+        self.send(instruction, &signer)
+    }
+
+    fn withdraw_stake(&mut self, withdrawer: &Keypair, lamports: u64) -> Result<(), String> {
+        let instruction = stake_instruction::withdraw(
+            &self.stake,
+            &withdrawer.pubkey(),
+            &withdrawer.pubkey(),
+            lamports,
+            None,
+        );
+        self.send(instruction, withdrawer)
+    }
+
+    fn advance_clock(&mut self, seconds: i64) {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp += seconds;
+        self.svm.set_sysvar(&clock);
+    }
+
+    /// (staker, withdrawer) from the stake account's Meta
+    fn authorities(&self) -> (Pubkey, Pubkey) {
+        // StakeStateV2 tag (4) + rent_exempt_reserve (8), then staker and withdrawer
+        let data = self.svm.get_account(&self.stake).unwrap().data;
+        (
+            Pubkey::try_from(&data[12..44]).unwrap(),
+            Pubkey::try_from(&data[44..76]).unwrap(),
+        )
+    }
+}
+
+fn assert_vault_error(result: Result<(), String>, code: u32) {
+    let err = result.expect_err("instruction should have been rejected");
+    assert!(
+        err.contains(&format!("Custom({})", code)),
+        "expected error {}, got {}",
+        code,
+        err
+    );
+}
+
+#[test]
+fn test_stake_authorities_pass_to_beneficiary() {
+    println!("🧪 Testing stake account inheritance");
+
+    let mut fixture = StakeVaultTestFixture::new();
+    let owner = fixture.owner.insecure_clone();
+    let beneficiary = fixture.beneficiary.insecure_clone();
+
+    assert_vault_error(fixture.create_stake_vault(false), INVALID_HANDOFF_TARGET);
+    println!("✅ Stake handoff without the Clock sysvar rejected");
+
+    fixture.create_stake_vault(true).unwrap();
+    let vault = fixture.authority_vault_pda;
+    assert_eq!(fixture.authorities(), (vault, vault));
+    assert!(fixture.withdraw_stake(&owner, 1_000_000_000).is_err());
+    println!("✅ Staker and withdrawer held by the vault PDA");
+
+    fixture.advance_clock(3600);
+    fixture.hand_back(true).unwrap();
+    assert_eq!(
+        fixture.authorities(),
+        (beneficiary.pubkey(), beneficiary.pubkey())
+    );
+    assert!(fixture.svm.get_account(&vault).is_none());
+    println!("✅ Beneficiary holds both authorities after the owner went silent");
+
+    let before = fixture.svm.get_balance(&beneficiary.pubkey()).unwrap();
+    fixture.withdraw_stake(&beneficiary, 1_000_000_000).unwrap();
+    assert!(fixture.svm.get_balance(&beneficiary.pubkey()).unwrap() > before);
+    println!("✅ Beneficiary can withdraw the stake");
+}
+
+#[test]
+fn test_owner_recovers_stake_authorities() {
+    println!("🧪 Testing emergency return of stake authorities");
+
+    let mut fixture = StakeVaultTestFixture::new();
+    let owner = fixture.owner.insecure_clone();
+
+    fixture.create_stake_vault(true).unwrap();
+    fixture.advance_clock(1800);
+    fixture.hand_back(false).unwrap();
+
+    assert_eq!(fixture.authorities(), (owner.pubkey(), owner.pubkey()));
+    assert!(fixture
+        .svm
+        .get_account(&fixture.authority_vault_pda)
+        .is_none());
+    assert_eq!(
+        fixture.svm.get_balance(&fixture.stake).unwrap(),
+        STAKE_LAMPORTS
+    );
+    println!("✅ Owner got both authorities back with the stake untouched");
+}
@@ -0,0 +1,340 @@
+use litesvm::LiteSVM;
+use litesvm_token::{spl_token, CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+
+/// Test fixture for protocol-wide and per-mint statistics
+struct StatsTestFixture {
+    svm: LiteSVM,
+    program_id: Pubkey,
+    mint_authority: Keypair,
+    mint: Pubkey,
+    global_stats_pda: Pubkey,
+    mint_stats_pda: Pubkey,
+}
+
+/// PDAs for one (owner, beneficiary) vault
+struct VaultAccounts {
+    vault: Pubkey,
+    vault_token: Pubkey,
+    owner_registry: Pubkey,
+    beneficiary_index: Pubkey,
+}
+
+/// GlobalStats counters: (created, active, claimed, closed)
+type GlobalCounters = (u64, u64, u64, u64);
+
+impl StatsTestFixture {
+    fn new() -> Self {
+        let mut svm = LiteSVM::new();
+
+        // Load the compiled program
+        let program_keypair =
+            read_keypair_file("target/deploy/cyber_vault_rs-keypair.json").unwrap();
+        let program_id = program_keypair.pubkey();
+        let program_bytes = include_bytes!("../target/deploy/cyber_vault_rs.so");
+        svm.add_program(program_id, program_bytes);
+
+        let mint_authority = Keypair::new();
+        svm.airdrop(&mint_authority.pubkey(), 10_000_000_000)
+            .unwrap();
+
+        let mint = CreateMint::new(&mut svm, &mint_authority)
+            .authority(&mint_authority.pubkey())
+            .decimals(6)
+            .send()
+            .unwrap();
+
+        let (global_stats_pda, _) = Pubkey::find_program_address(&[b"global_stats"], &program_id);
+        let (mint_stats_pda, _) =
+            Pubkey::find_program_address(&[b"mint_stats", mint.as_ref()], &program_id);
+
+        Self {
+            svm,
+            program_id,
+            mint_authority,
+            mint,
+            global_stats_pda,
+            mint_stats_pda,
+        }
+    }
+
+    fn funded_user(&mut self) -> (Keypair, Pubkey) {
+        let user = Keypair::new();
+        self.svm.airdrop(&user.pubkey(), 10_000_000_000).unwrap();
+        let ata = CreateAssociatedTokenAccount::new(&mut self.svm, &user, &self.mint)
+            .owner(&user.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(
+            &mut self.svm,
+            &self.mint_authority,
+            &self.mint,
+            &ata,
+            5_000_000,
+        )
+        .send()
+        .unwrap();
+        (user, ata)
+    }
+
+    fn accounts(&self, owner: &Pubkey, beneficiary: &Pubkey) -> VaultAccounts {
+        let (vault, _) = Pubkey::find_program_address(
+            &[
+                b"vault",
+                owner.as_ref(),
+                beneficiary.as_ref(),
+                self.mint.as_ref(),
+            ],
+            &self.program_id,
+        );
+        let (vault_token, _) =
+            Pubkey::find_program_address(&[b"vault_token", vault.as_ref()], &self.program_id);
+        let (owner_registry, _) =
+            Pubkey::find_program_address(&[b"owner_registry", owner.as_ref()], &self.program_id);
+        let (beneficiary_index, _) = Pubkey::find_program_address(
+            &[b"beneficiary_index", beneficiary.as_ref()],
+            &self.program_id,
+        );
+        VaultAccounts {
+            vault,
+            vault_token,
+            owner_registry,
+            beneficiary_index,
+        }
+    }
+
+    fn send(&mut self, instruction: Instruction, signer: &Keypair) -> Result<(), String> {
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        self.svm
+            .send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn create_vault(
+        &mut self,
+        owner: &Keypair,
+        owner_ata: &Pubkey,
+        beneficiary: &Pubkey,
+        amount: u64,
+    ) -> VaultAccounts {
+        let accounts = self.accounts(&owner.pubkey(), beneficiary);
+        let inactivity_period: i64 = 3600;
+
+        // create_vault discriminator: [29, 237, 247, 208, 193, 82, 54, 135]
+        let mut instruction_data = vec![29, 237, 247, 208, 193, 82, 54, 135];
+        instruction_data.extend_from_slice(&beneficiary.to_bytes());
+        instruction_data.extend_from_slice(&inactivity_period.to_le_bytes());
+        instruction_data.extend_from_slice(&amount.to_le_bytes());
+        instruction_data.push(0); // acknowledge_freeze_authority
+        let (mint_policy_pda, _) =
+            Pubkey::find_program_address(&[b"mint_policy"], &self.program_id);
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(owner.pubkey(), true),
+                AccountMeta::new(accounts.vault, false),
+                AccountMeta::new(accounts.vault_token, false),
+                AccountMeta::new(*owner_ata, false),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
+                AccountMeta::new(accounts.owner_registry, false),
+                AccountMeta::new(accounts.beneficiary_index, false),
+                AccountMeta::new(self.global_stats_pda, false),
+                AccountMeta::new(self.mint_stats_pda, false),
+                AccountMeta::new_readonly(mint_policy_pda, false),
+            ],
+            data: instruction_data,
+        };
+
+        self.send(instruction, owner).unwrap();
+        accounts
+    }
+
+    fn claim(&mut self, accounts: &VaultAccounts, beneficiary: &Keypair) -> Result<(), String> {
+        let beneficiary_ata = get_associated_token_address(&beneficiary.pubkey(), &self.mint);
+
+        // claim discriminator: [62, 198, 214, 193, 213, 159, 108, 210]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(accounts.vault, false),
+                AccountMeta::new(accounts.vault_token, false),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new(beneficiary_ata, false),
+                AccountMeta::new(beneficiary.pubkey(), true),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new(accounts.owner_registry, false),
+                AccountMeta::new(accounts.beneficiary_index, false),
+                AccountMeta::new(self.global_stats_pda, false),
+                AccountMeta::new(self.mint_stats_pda, false),
+            ],
+            data: vec![62, 198, 214, 193, 213, 159, 108, 210],
+        };
+
+        self.send(instruction, beneficiary)
+    }
+
+    fn emergency_withdraw(
+        &mut self,
+        accounts: &VaultAccounts,
+        owner: &Keypair,
+        owner_ata: &Pubkey,
+        amount: u64,
+    ) -> Result<(), String> {
+        // emergency_withdraw discriminator: [239, 45, 203, 64, 150, 73, 218, 92]
+        let mut instruction_data = vec![239, 45, 203, 64, 150, 73, 218, 92];
+        instruction_data.extend_from_slice(&amount.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(owner.pubkey(), true),
+                AccountMeta::new(accounts.vault, false),
+                AccountMeta::new(*owner_ata, false),
+                AccountMeta::new(accounts.vault_token, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new(self.mint_stats_pda, false),
+            ],
+            data: instruction_data,
+        };
+
+        self.send(instruction, owner)
+    }
+
+    fn close_vault(&mut self, accounts: &VaultAccounts, owner: &Keypair) -> Result<(), String> {
+        // close_vault discriminator: [141, 103, 17, 126, 72, 75, 29, 29]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(owner.pubkey(), true),
+                AccountMeta::new(accounts.vault, false),
+                AccountMeta::new(accounts.vault_token, false),
+                AccountMeta::new(accounts.owner_registry, false),
+                AccountMeta::new(accounts.beneficiary_index, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new(self.global_stats_pda, false),
+                AccountMeta::new(self.mint_stats_pda, false),
+            ],
+            data: vec![141, 103, 17, 126, 72, 75, 29, 29],
+        };
+
+        self.send(instruction, owner)
+    }
+
+    fn advance_clock(&mut self, seconds: i64) {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp += seconds;
+        self.svm.set_sysvar(&clock);
+    }
+
+    fn read_u64(data: &[u8], offset: usize) -> u64 {
+        u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+    }
+
+    fn global(&self) -> GlobalCounters {
+        let data = self.svm.get_account(&self.global_stats_pda).unwrap().data;
+        (
+            Self::read_u64(&data, 8),
+            Self::read_u64(&data, 16),
+            Self::read_u64(&data, 24),
+            Self::read_u64(&data, 32),
+        )
+    }
+
+    /// Returns (locked_amount, active_vaults); the mint key occupies bytes 8..40
+    fn mint_stats(&self) -> (u64, u64) {
+        let data = self.svm.get_account(&self.mint_stats_pda).unwrap().data;
+        assert_eq!(&data[8..40], self.mint.as_ref());
+        (Self::read_u64(&data, 40), Self::read_u64(&data, 48))
+    }
+}
+
+#[test]
+fn test_create_vault_counts_and_locks() {
+    println!("🧪 Testing stats on vault creation");
+
+    let mut fixture = StatsTestFixture::new();
+    let (alice, alice_ata) = fixture.funded_user();
+    let (bob, bob_ata) = fixture.funded_user();
+
+    fixture.create_vault(&alice, &alice_ata, &Pubkey::new_unique(), 1_000_000);
+    fixture.create_vault(&bob, &bob_ata, &Pubkey::new_unique(), 2_500_000);
+
+    assert_eq!(fixture.global(), (2, 2, 0, 0));
+    assert_eq!(fixture.mint_stats(), (3_500_000, 2));
+    println!("✅ Both vaults counted and their deposits locked");
+}
+
+#[test]
+fn test_emergency_withdraw_unlocks_amount() {
+    println!("🧪 Testing stats on emergency withdrawal");
+
+    let mut fixture = StatsTestFixture::new();
+    let (owner, owner_ata) = fixture.funded_user();
+    let accounts = fixture.create_vault(&owner, &owner_ata, &Pubkey::new_unique(), 1_000_000);
+
+    fixture
+        .emergency_withdraw(&accounts, &owner, &owner_ata, 400_000)
+        .unwrap();
+
+    assert_eq!(fixture.mint_stats(), (600_000, 1));
+    assert_eq!(fixture.global(), (1, 1, 0, 0));
+    println!("✅ Withdrawn amount no longer counted as locked");
+}
+
+#[test]
+fn test_claim_retires_vault() {
+    println!("🧪 Testing stats on claim");
+
+    let mut fixture = StatsTestFixture::new();
+    let (owner, owner_ata) = fixture.funded_user();
+    let (heir, _) = fixture.funded_user();
+    let (other, other_ata) = fixture.funded_user();
+    let accounts = fixture.create_vault(&owner, &owner_ata, &heir.pubkey(), 1_000_000);
+    fixture.create_vault(&other, &other_ata, &Pubkey::new_unique(), 300_000);
+
+    fixture.advance_clock(3600);
+    fixture.claim(&accounts, &heir).unwrap();
+
+    assert_eq!(fixture.global(), (2, 1, 1, 0));
+    assert_eq!(fixture.mint_stats(), (300_000, 1));
+    println!("✅ Claimed vault moved from active to claimed");
+}
+
+#[test]
+fn test_close_vault_retires_vault() {
+    println!("🧪 Testing stats on close");
+
+    let mut fixture = StatsTestFixture::new();
+    let (owner, owner_ata) = fixture.funded_user();
+    let accounts = fixture.create_vault(&owner, &owner_ata, &Pubkey::new_unique(), 1_000_000);
+
+    fixture
+        .emergency_withdraw(&accounts, &owner, &owner_ata, 1_000_000)
+        .unwrap();
+    fixture.close_vault(&accounts, &owner).unwrap();
+
+    assert_eq!(fixture.global(), (1, 0, 0, 1));
+    assert_eq!(fixture.mint_stats(), (0, 0));
+    println!("✅ Closed vault counted and nothing left locked");
+}
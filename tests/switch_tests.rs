@@ -0,0 +1,538 @@
+use litesvm::LiteSVM;
+use litesvm_token::{spl_token, CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+
+const HOUR: i64 = 3_600;
+
+// VaultError codes
+const SWITCH_ALREADY_LINKED: u32 = 6023;
+
+/// One vault created by the fixture owner for a distinct beneficiary
+struct LinkedVault {
+    beneficiary: Keypair,
+    beneficiary_ata: Pubkey,
+    vault_pda: Pubkey,
+    vault_token_pda: Pubkey,
+}
+
+/// Test fixture for master switch tests
+struct SwitchTestFixture {
+    svm: LiteSVM,
+    program_id: Pubkey,
+    owner: Keypair,
+    mint: Pubkey,
+    owner_ata: Pubkey,
+    switch_pda: Pubkey,
+}
+
+impl SwitchTestFixture {
+    fn new() -> Self {
+        let mut svm = LiteSVM::new();
+
+        // Load the compiled program
+        let program_keypair =
+            read_keypair_file("target/deploy/cyber_vault_rs-keypair.json").unwrap();
+        let program_id = program_keypair.pubkey();
+        let program_bytes = include_bytes!("../target/deploy/cyber_vault_rs.so");
+        svm.add_program(program_id, program_bytes);
+
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), 10_000_000_000).unwrap();
+
+        // Create token mint and fund the owner
+        let mint = CreateMint::new(&mut svm, &owner)
+            .authority(&owner.pubkey())
+            .decimals(6)
+            .send()
+            .unwrap();
+
+        let owner_ata = CreateAssociatedTokenAccount::new(&mut svm, &owner, &mint)
+            .owner(&owner.pubkey())
+            .send()
+            .unwrap();
+
+        MintTo::new(&mut svm, &owner, &mint, &owner_ata, 10_000_000)
+            .send()
+            .unwrap();
+
+        let (switch_pda, _) =
+            Pubkey::find_program_address(&[b"switch", owner.pubkey().as_ref()], &program_id);
+
+        Self {
+            svm,
+            program_id,
+            owner,
+            mint,
+            owner_ata,
+            switch_pda,
+        }
+    }
+
+    fn send(&mut self, instruction: Instruction, signer: &Keypair) -> Result<(), String> {
+        // Expire the blockhash so repeated identical instructions are not deduplicated
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        self.svm
+            .send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn warp(&mut self, seconds: i64) {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp += seconds;
+        self.svm.set_sysvar(&clock);
+    }
+
+    fn create_vault(&mut self, deposit_amount: u64) -> LinkedVault {
+        let beneficiary = Keypair::new();
+        self.svm
+            .airdrop(&beneficiary.pubkey(), 1_000_000_000)
+            .unwrap();
+        let beneficiary_ata =
+            CreateAssociatedTokenAccount::new(&mut self.svm, &self.owner, &self.mint)
+                .owner(&beneficiary.pubkey())
+                .send()
+                .unwrap();
+
+        let (vault_pda, _) = Pubkey::find_program_address(
+            &[
+                b"vault",
+                self.owner.pubkey().as_ref(),
+                beneficiary.pubkey().as_ref(),
+                self.mint.as_ref(),
+            ],
+            &self.program_id,
+        );
+        let (vault_token_pda, _) =
+            Pubkey::find_program_address(&[b"vault_token", vault_pda.as_ref()], &self.program_id);
+
+        let (owner_registry_pda, _) = Pubkey::find_program_address(
+            &[b"owner_registry", self.owner.pubkey().as_ref()],
+            &self.program_id,
+        );
+        let (beneficiary_index_pda, _) = Pubkey::find_program_address(
+            &[b"beneficiary_index", beneficiary.pubkey().as_ref()],
+            &self.program_id,
+        );
+        let (global_stats_pda, _) =
+            Pubkey::find_program_address(&[b"global_stats"], &self.program_id);
+        let (mint_stats_pda, _) =
+            Pubkey::find_program_address(&[b"mint_stats", self.mint.as_ref()], &self.program_id);
+
+        // create_vault discriminator: [29, 237, 247, 208, 193, 82, 54, 135]
+        let mut instruction_data = vec![29, 237, 247, 208, 193, 82, 54, 135];
+        instruction_data.extend_from_slice(&beneficiary.pubkey().to_bytes());
+        instruction_data.extend_from_slice(&HOUR.to_le_bytes());
+        instruction_data.extend_from_slice(&deposit_amount.to_le_bytes());
+        instruction_data.push(0); // acknowledge_freeze_authority
+        let (mint_policy_pda, _) =
+            Pubkey::find_program_address(&[b"mint_policy"], &self.program_id);
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.owner.pubkey(), true),
+                AccountMeta::new(vault_pda, false),
+                AccountMeta::new(vault_token_pda, false),
+                AccountMeta::new(self.owner_ata, false),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
+                AccountMeta::new(owner_registry_pda, false),
+                AccountMeta::new(beneficiary_index_pda, false),
+                AccountMeta::new(global_stats_pda, false),
+                AccountMeta::new(mint_stats_pda, false),
+                AccountMeta::new_readonly(mint_policy_pda, false),
+            ],
+            data: instruction_data,
+        };
+
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner).unwrap();
+
+        LinkedVault {
+            beneficiary,
+            beneficiary_ata,
+            vault_pda,
+            vault_token_pda,
+        }
+    }
+
+    fn create_switch(&mut self, owner: &Keypair, inactivity_period: i64) -> Result<(), String> {
+        let (switch_pda, _) =
+            Pubkey::find_program_address(&[b"switch", owner.pubkey().as_ref()], &self.program_id);
+
+        // create_switch discriminator: [120, 11, 193, 114, 80, 234, 99, 128]
+        let mut instruction_data = vec![120, 11, 193, 114, 80, 234, 99, 128];
+        instruction_data.extend_from_slice(&inactivity_period.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(owner.pubkey(), true),
+                AccountMeta::new(switch_pda, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: instruction_data,
+        };
+
+        self.send(instruction, owner)
+    }
+
+    fn switch_heartbeat(&mut self) -> Result<(), String> {
+        // switch_heartbeat discriminator: [152, 58, 183, 196, 71, 231, 96, 5]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.switch_pda, false),
+                AccountMeta::new_readonly(self.owner.pubkey(), true),
+            ],
+            data: vec![152, 58, 183, 196, 71, 231, 96, 5],
+        };
+
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner)
+    }
+
+    fn heartbeat(&mut self, vault: &LinkedVault) -> Result<(), String> {
+        // heartbeat discriminator: [202, 104, 56, 6, 240, 170, 63, 134]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(vault.vault_pda, false),
+                AccountMeta::new_readonly(self.owner.pubkey(), true),
+            ],
+            data: vec![202, 104, 56, 6, 240, 170, 63, 134],
+        };
+
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner)
+    }
+
+    fn link_switch(&mut self, vault: &LinkedVault, signer: &Keypair) -> Result<(), String> {
+        let (switch_pda, _) =
+            Pubkey::find_program_address(&[b"switch", signer.pubkey().as_ref()], &self.program_id);
+
+        // link_switch discriminator: [136, 247, 62, 224, 223, 183, 48, 117]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(signer.pubkey(), true),
+                AccountMeta::new(vault.vault_pda, false),
+                AccountMeta::new(switch_pda, false),
+            ],
+            data: vec![136, 247, 62, 224, 223, 183, 48, 117],
+        };
+
+        self.send(instruction, signer)
+    }
+
+    fn unlink_switch(&mut self, vault: &LinkedVault) -> Result<(), String> {
+        // unlink_switch discriminator: [178, 63, 65, 24, 77, 44, 37, 233]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(self.owner.pubkey(), true),
+                AccountMeta::new(vault.vault_pda, false),
+                AccountMeta::new(self.switch_pda, false),
+            ],
+            data: vec![178, 63, 65, 24, 77, 44, 37, 233],
+        };
+
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner)
+    }
+
+    fn close_vault(&mut self, vault: &LinkedVault, switch: Option<Pubkey>) -> Result<(), String> {
+        let (owner_registry_pda, _) = Pubkey::find_program_address(
+            &[b"owner_registry", self.owner.pubkey().as_ref()],
+            &self.program_id,
+        );
+        let (beneficiary_index_pda, _) = Pubkey::find_program_address(
+            &[b"beneficiary_index", vault.beneficiary.pubkey().as_ref()],
+            &self.program_id,
+        );
+        let (global_stats_pda, _) =
+            Pubkey::find_program_address(&[b"global_stats"], &self.program_id);
+        let (mint_stats_pda, _) =
+            Pubkey::find_program_address(&[b"mint_stats", self.mint.as_ref()], &self.program_id);
+
+        // close_vault discriminator: [141, 103, 17, 126, 72, 75, 29, 29]
+        let mut accounts = vec![
+            AccountMeta::new(self.owner.pubkey(), true),
+            AccountMeta::new(vault.vault_pda, false),
+            AccountMeta::new(vault.vault_token_pda, false),
+            AccountMeta::new(owner_registry_pda, false),
+            AccountMeta::new(beneficiary_index_pda, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new(global_stats_pda, false),
+            AccountMeta::new(mint_stats_pda, false),
+        ];
+        if let Some(switch) = switch {
+            accounts.push(AccountMeta::new(switch, false));
+        }
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: vec![141, 103, 17, 126, 72, 75, 29, 29],
+        };
+
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner)
+    }
+
+    /// Switch layout: disc(8) + owner(32) + inactivity_period(8) + last_heartbeat(8) + linked_vaults
+    fn linked_vaults(&self) -> u32 {
+        let data = self.svm.get_account(&self.switch_pda).unwrap().data;
+        u32::from_le_bytes(data[56..60].try_into().unwrap())
+    }
+
+    fn claim(&mut self, vault: &LinkedVault, switch: Option<Pubkey>) -> Result<(), String> {
+        let (owner_registry_pda, _) = Pubkey::find_program_address(
+            &[b"owner_registry", self.owner.pubkey().as_ref()],
+            &self.program_id,
+        );
+        let (beneficiary_index_pda, _) = Pubkey::find_program_address(
+            &[b"beneficiary_index", vault.beneficiary.pubkey().as_ref()],
+            &self.program_id,
+        );
+        let (global_stats_pda, _) =
+            Pubkey::find_program_address(&[b"global_stats"], &self.program_id);
+        let (mint_stats_pda, _) =
+            Pubkey::find_program_address(&[b"mint_stats", self.mint.as_ref()], &self.program_id);
+
+        // claim discriminator: [62, 198, 214, 193, 213, 159, 108, 210]
+        let mut accounts = vec![
+            AccountMeta::new(vault.vault_pda, false),
+            AccountMeta::new(vault.vault_token_pda, false),
+            AccountMeta::new_readonly(self.mint, false),
+            AccountMeta::new(vault.beneficiary_ata, false),
+            AccountMeta::new(vault.beneficiary.pubkey(), true),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(owner_registry_pda, false),
+            AccountMeta::new(beneficiary_index_pda, false),
+            AccountMeta::new(global_stats_pda, false),
+            AccountMeta::new(mint_stats_pda, false),
+        ];
+        if let Some(switch) = switch {
+            accounts.push(AccountMeta::new(switch, false));
+        }
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: vec![62, 198, 214, 193, 213, 159, 108, 210],
+        };
+
+        let beneficiary = vault.beneficiary.insecure_clone();
+        self.send(instruction, &beneficiary)
+    }
+}
+
+#[test]
+fn test_one_switch_heartbeat_keeps_all_vaults_alive() {
+    println!("🧪 Testing a master switch shared by two vaults");
+
+    let mut fixture = SwitchTestFixture::new();
+    let owner = fixture.owner.insecure_clone();
+    fixture.create_switch(&owner, HOUR).unwrap();
+
+    let vault_a = fixture.create_vault(1_000_000);
+    let vault_b = fixture.create_vault(2_000_000);
+    fixture.link_switch(&vault_a, &owner).unwrap();
+    fixture.link_switch(&vault_b, &owner).unwrap();
+    println!("✅ Two vaults linked to the master switch");
+
+    // Only the switch is kept alive; the vaults' own timers lapse
+    fixture.warp(HOUR - 600);
+    fixture.switch_heartbeat().unwrap();
+    fixture.warp(HOUR - 600);
+
+    let switch = fixture.switch_pda;
+    assert!(
+        fixture.claim(&vault_a, Some(switch)).is_err(),
+        "Claim should fail while the switch is alive"
+    );
+    assert!(
+        fixture.claim(&vault_b, Some(switch)).is_err(),
+        "Claim should fail while the switch is alive"
+    );
+    println!("✅ One switch heartbeat protected both vaults");
+
+    fixture.warp(600);
+    fixture.claim(&vault_a, Some(switch)).unwrap();
+    fixture.claim(&vault_b, Some(switch)).unwrap();
+    assert_eq!(fixture.linked_vaults(), 0);
+    println!("✅ Both vaults claimable once the switch expired");
+}
+
+#[test]
+fn test_linked_vault_claim_requires_switch() {
+    println!("🧪 Testing that claim must read the linked switch");
+
+    let mut fixture = SwitchTestFixture::new();
+    let owner = fixture.owner.insecure_clone();
+    fixture.create_switch(&owner, HOUR).unwrap();
+    let vault = fixture.create_vault(1_000_000);
+    fixture.link_switch(&vault, &owner).unwrap();
+
+    fixture.warp(HOUR);
+    assert!(
+        fixture.claim(&vault, None).is_err(),
+        "Claim without the switch account should fail"
+    );
+
+    // Someone else's expired switch is not a substitute
+    let stranger = Keypair::new();
+    fixture
+        .svm
+        .airdrop(&stranger.pubkey(), 1_000_000_000)
+        .unwrap();
+    fixture.create_switch(&stranger, HOUR).unwrap();
+    fixture.warp(HOUR);
+    let (stranger_switch, _) = Pubkey::find_program_address(
+        &[b"switch", stranger.pubkey().as_ref()],
+        &fixture.program_id,
+    );
+    assert!(
+        fixture.claim(&vault, Some(stranger_switch)).is_err(),
+        "Claim with a foreign switch should fail"
+    );
+
+    let switch = fixture.switch_pda;
+    fixture.claim(&vault, Some(switch)).unwrap();
+    println!("✅ Claim enforced the linked switch");
+}
+
+#[test]
+fn test_linking_refreshes_stale_switch() {
+    println!("🧪 Testing a link to a switch that has gone quiet");
+
+    let mut fixture = SwitchTestFixture::new();
+    let owner = fixture.owner.insecure_clone();
+    fixture.create_switch(&owner, HOUR).unwrap();
+    fixture.warp(2 * HOUR);
+
+    let vault = fixture.create_vault(1_000_000);
+    fixture.link_switch(&vault, &owner).unwrap();
+
+    let switch = fixture.switch_pda;
+    assert!(
+        fixture.claim(&vault, Some(switch)).is_err(),
+        "Linking should restart the switch timer"
+    );
+    println!("✅ Link counted as a switch heartbeat");
+
+    fixture.warp(HOUR);
+    fixture.claim(&vault, Some(switch)).unwrap();
+    println!("✅ Vault claimable once the refreshed switch expired");
+}
+
+#[test]
+fn test_closing_linked_vault_releases_switch() {
+    println!("🧪 Testing close of a linked vault");
+
+    let mut fixture = SwitchTestFixture::new();
+    let owner = fixture.owner.insecure_clone();
+    fixture.create_switch(&owner, HOUR).unwrap();
+    let vault = fixture.create_vault(0);
+    fixture.link_switch(&vault, &owner).unwrap();
+    assert_eq!(fixture.linked_vaults(), 1);
+
+    assert!(
+        fixture.close_vault(&vault, None).is_err(),
+        "Closing a linked vault should require its switch"
+    );
+    let switch = fixture.switch_pda;
+    fixture.close_vault(&vault, Some(switch)).unwrap();
+    assert!(fixture.svm.get_account(&vault.vault_pda).is_none());
+    assert_eq!(fixture.linked_vaults(), 0);
+    println!("✅ Switch stopped counting the closed vault");
+}
+
+#[test]
+fn test_only_owner_can_link_switch() {
+    println!("🧪 Testing that linking is owner-only");
+
+    let mut fixture = SwitchTestFixture::new();
+    let vault = fixture.create_vault(1_000_000);
+
+    let stranger = Keypair::new();
+    fixture
+        .svm
+        .airdrop(&stranger.pubkey(), 1_000_000_000)
+        .unwrap();
+    fixture.create_switch(&stranger, HOUR).unwrap();
+
+    assert!(
+        fixture.link_switch(&vault, &stranger).is_err(),
+        "A stranger should not link their switch to someone else's vault"
+    );
+    println!("✅ Foreign switch link rejected");
+}
+
+#[test]
+fn test_unlinked_vault_uses_own_timer() {
+    println!("🧪 Testing unlink restores the vault timer");
+
+    let mut fixture = SwitchTestFixture::new();
+    let owner = fixture.owner.insecure_clone();
+    fixture.create_switch(&owner, HOUR).unwrap();
+    let vault = fixture.create_vault(1_000_000);
+    fixture.link_switch(&vault, &owner).unwrap();
+
+    fixture.warp(HOUR / 2);
+    fixture.unlink_switch(&vault).unwrap();
+
+    // The switch expires but the vault now follows its own restarted timer
+    fixture.warp(HOUR / 2);
+    assert!(
+        fixture.claim(&vault, None).is_err(),
+        "Unlinked vault should follow its own timer"
+    );
+
+    fixture.warp(HOUR / 2);
+    fixture.claim(&vault, None).unwrap();
+    println!("✅ Unlinked vault expired on its own timer");
+}
+
+#[test]
+fn test_linked_vault_rejects_its_own_heartbeat() {
+    println!("🧪 Testing a direct heartbeat on a linked vault");
+
+    let mut fixture = SwitchTestFixture::new();
+    let owner = fixture.owner.insecure_clone();
+    fixture.create_switch(&owner, HOUR).unwrap();
+    let vault = fixture.create_vault(1_000_000);
+    fixture.link_switch(&vault, &owner).unwrap();
+
+    // It would not reset the switch the vault expires by, so it must not look like it did
+    let err = fixture.heartbeat(&vault).unwrap_err();
+    assert!(
+        err.contains(&format!("Custom({})", SWITCH_ALREADY_LINKED)),
+        "expected SwitchAlreadyLinked, got {}",
+        err
+    );
+    println!("✅ Heartbeat on a linked vault rejected");
+
+    fixture.unlink_switch(&vault).unwrap();
+    fixture.heartbeat(&vault).unwrap();
+    println!("✅ Heartbeat accepted again once unlinked");
+}
@@ -0,0 +1,597 @@
+use litesvm::LiteSVM;
+use litesvm_token::{spl_token, CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+
+const DAY: i64 = 86_400;
+const HOUR: i64 = 3_600;
+const WITHDRAW_WINDOW_TOO_LONG: u32 = 6057;
+
+/// Test fixture for withdrawal limit and delay tests
+struct WithdrawLimitTestFixture {
+    svm: LiteSVM,
+    program_id: Pubkey,
+    owner: Keypair,
+    beneficiary: Keypair,
+    guardian: Keypair,
+    mint: Pubkey,
+    owner_ata: Pubkey,
+    vault_pda: Pubkey,
+    vault_token_pda: Pubkey,
+    recovery_pda: Pubkey,
+    withdrawal_pda: Pubkey,
+    mint_stats_pda: Pubkey,
+}
+
+impl WithdrawLimitTestFixture {
+    fn new() -> Self {
+        let mut svm = LiteSVM::new();
+
+        // Load the compiled program
+        let program_keypair =
+            read_keypair_file("target/deploy/cyber_vault_rs-keypair.json").unwrap();
+        let program_id = program_keypair.pubkey();
+        let program_bytes = include_bytes!("../target/deploy/cyber_vault_rs.so");
+        svm.add_program(program_id, program_bytes);
+
+        // Create test accounts
+        let owner = Keypair::new();
+        let beneficiary = Keypair::new();
+        let guardian = Keypair::new();
+        svm.airdrop(&owner.pubkey(), 10_000_000_000).unwrap();
+        svm.airdrop(&beneficiary.pubkey(), 10_000_000_000).unwrap();
+        svm.airdrop(&guardian.pubkey(), 1_000_000_000).unwrap();
+
+        // Create token mint and fund the owner
+        let mint = CreateMint::new(&mut svm, &owner)
+            .authority(&owner.pubkey())
+            .decimals(6)
+            .send()
+            .unwrap();
+
+        let owner_ata = CreateAssociatedTokenAccount::new(&mut svm, &owner, &mint)
+            .owner(&owner.pubkey())
+            .send()
+            .unwrap();
+
+        MintTo::new(&mut svm, &owner, &mint, &owner_ata, 2_000_000)
+            .send()
+            .unwrap();
+
+        // Calculate PDAs
+        let (vault_pda, _) = Pubkey::find_program_address(
+            &[
+                b"vault",
+                owner.pubkey().as_ref(),
+                beneficiary.pubkey().as_ref(),
+                mint.as_ref(),
+            ],
+            &program_id,
+        );
+        let (vault_token_pda, _) =
+            Pubkey::find_program_address(&[b"vault_token", vault_pda.as_ref()], &program_id);
+        let (recovery_pda, _) =
+            Pubkey::find_program_address(&[b"recovery", vault_pda.as_ref()], &program_id);
+        let (withdrawal_pda, _) =
+            Pubkey::find_program_address(&[b"withdrawal", vault_pda.as_ref()], &program_id);
+        let (mint_stats_pda, _) =
+            Pubkey::find_program_address(&[b"mint_stats", mint.as_ref()], &program_id);
+
+        Self {
+            svm,
+            program_id,
+            owner,
+            beneficiary,
+            guardian,
+            mint,
+            owner_ata,
+            vault_pda,
+            vault_token_pda,
+            recovery_pda,
+            withdrawal_pda,
+            mint_stats_pda,
+        }
+    }
+
+    fn send(&mut self, instruction: Instruction, signer: &Keypair) -> Result<(), String> {
+        // Expire the blockhash so repeated identical instructions are not deduplicated
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        self.svm
+            .send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn send_as_owner(&mut self, instruction: Instruction) -> Result<(), String> {
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner)
+    }
+
+    fn warp(&mut self, seconds: i64) {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp += seconds;
+        self.svm.set_sysvar(&clock);
+    }
+
+    fn token_balance(&self, token_account: &Pubkey) -> u64 {
+        let data = self.svm.get_account(token_account).unwrap().data;
+        u64::from_le_bytes(data[64..72].try_into().unwrap())
+    }
+
+    fn create_vault(&mut self, deposit_amount: u64) -> Result<(), String> {
+        let inactivity_period: i64 = HOUR;
+
+        let (owner_registry_pda, _) = Pubkey::find_program_address(
+            &[b"owner_registry", self.owner.pubkey().as_ref()],
+            &self.program_id,
+        );
+        let (beneficiary_index_pda, _) = Pubkey::find_program_address(
+            &[b"beneficiary_index", self.beneficiary.pubkey().as_ref()],
+            &self.program_id,
+        );
+        let (global_stats_pda, _) =
+            Pubkey::find_program_address(&[b"global_stats"], &self.program_id);
+
+        // create_vault discriminator: [29, 237, 247, 208, 193, 82, 54, 135]
+        let mut instruction_data = vec![29, 237, 247, 208, 193, 82, 54, 135];
+        instruction_data.extend_from_slice(&self.beneficiary.pubkey().to_bytes());
+        instruction_data.extend_from_slice(&inactivity_period.to_le_bytes());
+        instruction_data.extend_from_slice(&deposit_amount.to_le_bytes());
+        instruction_data.push(0); // acknowledge_freeze_authority
+        let (mint_policy_pda, _) =
+            Pubkey::find_program_address(&[b"mint_policy"], &self.program_id);
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.owner.pubkey(), true),
+                AccountMeta::new(self.vault_pda, false),
+                AccountMeta::new(self.vault_token_pda, false),
+                AccountMeta::new(self.owner_ata, false),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
+                AccountMeta::new(owner_registry_pda, false),
+                AccountMeta::new(beneficiary_index_pda, false),
+                AccountMeta::new(global_stats_pda, false),
+                AccountMeta::new(self.mint_stats_pda, false),
+                AccountMeta::new_readonly(mint_policy_pda, false),
+            ],
+            data: instruction_data,
+        };
+
+        self.send_as_owner(instruction)
+    }
+
+    fn set_withdraw_policy(&mut self, limit: u64, period: i64, delay: i64) -> Result<(), String> {
+        // set_withdraw_policy discriminator: [44, 31, 170, 11, 16, 115, 56, 197]
+        let mut instruction_data = vec![44, 31, 170, 11, 16, 115, 56, 197];
+        instruction_data.extend_from_slice(&limit.to_le_bytes());
+        instruction_data.extend_from_slice(&period.to_le_bytes());
+        instruction_data.extend_from_slice(&delay.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(self.owner.pubkey(), true),
+                AccountMeta::new(self.vault_pda, false),
+            ],
+            data: instruction_data,
+        };
+
+        self.send_as_owner(instruction)
+    }
+
+    fn emergency_withdraw(&mut self, amount: u64) -> Result<(), String> {
+        // emergency_withdraw discriminator: [239, 45, 203, 64, 150, 73, 218, 92]
+        let mut instruction_data = vec![239, 45, 203, 64, 150, 73, 218, 92];
+        instruction_data.extend_from_slice(&amount.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.owner.pubkey(), true),
+                AccountMeta::new(self.vault_pda, false),
+                AccountMeta::new(self.owner_ata, false),
+                AccountMeta::new(self.vault_token_pda, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new(self.mint_stats_pda, false),
+            ],
+            data: instruction_data,
+        };
+
+        self.send_as_owner(instruction)
+    }
+
+    fn queue_withdrawal(&mut self, amount: u64) -> Result<(), String> {
+        // queue_withdrawal discriminator: [153, 8, 176, 235, 189, 140, 146, 223]
+        let mut instruction_data = vec![153, 8, 176, 235, 189, 140, 146, 223];
+        instruction_data.extend_from_slice(&amount.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.owner.pubkey(), true),
+                AccountMeta::new(self.vault_pda, false),
+                AccountMeta::new_readonly(self.vault_token_pda, false),
+                AccountMeta::new(self.withdrawal_pda, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: instruction_data,
+        };
+
+        self.send_as_owner(instruction)
+    }
+
+    fn execute_withdrawal(&mut self) -> Result<(), String> {
+        // execute_withdrawal discriminator: [113, 121, 203, 232, 137, 139, 248, 249]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.owner.pubkey(), true),
+                AccountMeta::new(self.vault_pda, false),
+                AccountMeta::new(self.withdrawal_pda, false),
+                AccountMeta::new(self.owner_ata, false),
+                AccountMeta::new(self.vault_token_pda, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new(self.mint_stats_pda, false),
+            ],
+            data: vec![113, 121, 203, 232, 137, 139, 248, 249],
+        };
+
+        self.send_as_owner(instruction)
+    }
+
+    fn veto_withdrawal(&mut self, signer: &Keypair, with_recovery: bool) -> Result<(), String> {
+        // veto_withdrawal discriminator: [110, 183, 59, 83, 93, 65, 59, 212]
+        let mut accounts = vec![
+            AccountMeta::new_readonly(signer.pubkey(), true),
+            AccountMeta::new(self.vault_pda, false),
+            AccountMeta::new(self.owner.pubkey(), false),
+            AccountMeta::new(self.withdrawal_pda, false),
+        ];
+        if with_recovery {
+            accounts.push(AccountMeta::new_readonly(self.recovery_pda, false));
+        }
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: vec![110, 183, 59, 83, 93, 65, 59, 212],
+        };
+
+        self.send(instruction, signer)
+    }
+
+    fn set_guardian(&mut self) -> Result<(), String> {
+        // set_guardians discriminator: [166, 69, 140, 183, 157, 169, 253, 40]
+        let mut instruction_data = vec![166, 69, 140, 183, 157, 169, 253, 40];
+        instruction_data.extend_from_slice(&1u32.to_le_bytes());
+        instruction_data.extend_from_slice(&self.guardian.pubkey().to_bytes());
+        instruction_data.push(1);
+        instruction_data.extend_from_slice(&HOUR.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.owner.pubkey(), true),
+                AccountMeta::new(self.vault_pda, false),
+                AccountMeta::new(self.recovery_pda, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: instruction_data,
+        };
+
+        self.send_as_owner(instruction)
+    }
+
+    fn registry_accounts(&self) -> Vec<AccountMeta> {
+        let (owner_registry_pda, _) = Pubkey::find_program_address(
+            &[b"owner_registry", self.owner.pubkey().as_ref()],
+            &self.program_id,
+        );
+        let (beneficiary_index_pda, _) = Pubkey::find_program_address(
+            &[b"beneficiary_index", self.beneficiary.pubkey().as_ref()],
+            &self.program_id,
+        );
+        let (global_stats_pda, _) =
+            Pubkey::find_program_address(&[b"global_stats"], &self.program_id);
+        vec![
+            AccountMeta::new(owner_registry_pda, false),
+            AccountMeta::new(beneficiary_index_pda, false),
+            AccountMeta::new(global_stats_pda, false),
+            AccountMeta::new(self.mint_stats_pda, false),
+        ]
+    }
+
+    /// Closes the emptied vault along with its recovery and queued withdrawal
+    fn close_vault(&mut self) -> Result<(), String> {
+        let registries = self.registry_accounts();
+
+        // close_vault discriminator: [141, 103, 17, 126, 72, 75, 29, 29]
+        let mut accounts = vec![
+            AccountMeta::new(self.owner.pubkey(), true),
+            AccountMeta::new(self.vault_pda, false),
+            AccountMeta::new(self.vault_token_pda, false),
+            registries[0].clone(),
+            registries[1].clone(),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            registries[2].clone(),
+            registries[3].clone(),
+        ];
+        accounts.extend([
+            AccountMeta::new_readonly(self.program_id, false), // switch: none
+            AccountMeta::new(self.recovery_pda, false),
+            AccountMeta::new(self.withdrawal_pda, false),
+        ]);
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: vec![141, 103, 17, 126, 72, 75, 29, 29],
+        };
+
+        self.send_as_owner(instruction)
+    }
+
+    /// Claims the expired vault along with its recovery and queued withdrawal
+    fn claim(&mut self) -> Result<(), String> {
+        let beneficiary_ata = get_associated_token_address(&self.beneficiary.pubkey(), &self.mint);
+
+        // claim discriminator: [62, 198, 214, 193, 213, 159, 108, 210]
+        let mut accounts = vec![
+            AccountMeta::new(self.vault_pda, false),
+            AccountMeta::new(self.vault_token_pda, false),
+            AccountMeta::new_readonly(self.mint, false),
+            AccountMeta::new(beneficiary_ata, false),
+            AccountMeta::new(self.beneficiary.pubkey(), true),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ];
+        accounts.extend(self.registry_accounts());
+        accounts.extend([
+            AccountMeta::new_readonly(self.program_id, false), // switch: none
+            AccountMeta::new_readonly(self.program_id, false), // unwrap_account: none
+            AccountMeta::new(self.recovery_pda, false),
+            AccountMeta::new(self.withdrawal_pda, false),
+        ]);
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: vec![62, 198, 214, 193, 213, 159, 108, 210],
+        };
+
+        let beneficiary = self.beneficiary.insecure_clone();
+        self.send(instruction, &beneficiary)
+    }
+}
+
+fn assert_vault_error(result: Result<(), String>, code: u32) {
+    let err = result.expect_err("instruction should have been rejected");
+    assert!(
+        err.contains(&format!("Custom({})", code)),
+        "expected error {}, got {}",
+        code,
+        err
+    );
+}
+
+#[test]
+fn test_withdraw_limit_resets_each_period() {
+    println!("🧪 Testing per-period emergency withdraw limit");
+
+    let mut fixture = WithdrawLimitTestFixture::new();
+    fixture.create_vault(1_000_000).unwrap();
+    fixture.set_withdraw_policy(300_000, DAY, HOUR).unwrap();
+    println!("✅ Vault limited to 300000 per day");
+
+    fixture.emergency_withdraw(200_000).unwrap();
+    assert!(
+        fixture.emergency_withdraw(200_000).is_err(),
+        "Withdrawal above the remaining limit should fail"
+    );
+    fixture.emergency_withdraw(100_000).unwrap();
+    println!("✅ Limit enforced within the period");
+
+    fixture.warp(DAY);
+    fixture.emergency_withdraw(200_000).unwrap();
+    assert_eq!(fixture.token_balance(&fixture.vault_token_pda), 500_000);
+    println!("✅ Limit reset after the period elapsed");
+}
+
+#[test]
+fn test_queued_withdrawal_waits_for_delay() {
+    println!("🧪 Testing queued withdrawal above the limit");
+
+    let mut fixture = WithdrawLimitTestFixture::new();
+    fixture.create_vault(1_000_000).unwrap();
+    fixture.set_withdraw_policy(100_000, DAY, 2 * HOUR).unwrap();
+
+    assert!(
+        fixture.emergency_withdraw(800_000).is_err(),
+        "Instant withdrawal above the limit should fail"
+    );
+    fixture.queue_withdrawal(800_000).unwrap();
+    assert!(
+        fixture.queue_withdrawal(100_000).is_err(),
+        "Only one withdrawal can be queued at a time"
+    );
+
+    fixture.warp(HOUR);
+    assert!(
+        fixture.execute_withdrawal().is_err(),
+        "Queued withdrawal should not execute during the delay"
+    );
+
+    fixture.warp(HOUR);
+    fixture.execute_withdrawal().unwrap();
+    assert_eq!(fixture.token_balance(&fixture.vault_token_pda), 200_000);
+    assert!(fixture.svm.get_account(&fixture.withdrawal_pda).is_none());
+    println!("✅ Queued withdrawal executed after the delay");
+}
+
+#[test]
+fn test_beneficiary_vetoes_queued_withdrawal() {
+    println!("🧪 Testing beneficiary veto of a queued withdrawal");
+
+    let mut fixture = WithdrawLimitTestFixture::new();
+    fixture.create_vault(1_000_000).unwrap();
+    fixture.set_withdraw_policy(100_000, DAY, HOUR).unwrap();
+    fixture.queue_withdrawal(1_000_000).unwrap();
+
+    let beneficiary = fixture.beneficiary.insecure_clone();
+    fixture.veto_withdrawal(&beneficiary, false).unwrap();
+    assert!(fixture.svm.get_account(&fixture.withdrawal_pda).is_none());
+
+    fixture.warp(HOUR);
+    assert!(
+        fixture.execute_withdrawal().is_err(),
+        "Vetoed withdrawal should not execute"
+    );
+    assert_eq!(fixture.token_balance(&fixture.vault_token_pda), 1_000_000);
+    println!("✅ Beneficiary veto cancelled the withdrawal");
+}
+
+#[test]
+fn test_guardian_vetoes_queued_withdrawal() {
+    println!("🧪 Testing guardian veto of a queued withdrawal");
+
+    let mut fixture = WithdrawLimitTestFixture::new();
+    fixture.create_vault(1_000_000).unwrap();
+    fixture.set_guardian().unwrap();
+    fixture.set_withdraw_policy(100_000, DAY, HOUR).unwrap();
+    fixture.queue_withdrawal(500_000).unwrap();
+
+    let guardian = fixture.guardian.insecure_clone();
+    assert!(
+        fixture.veto_withdrawal(&guardian, false).is_err(),
+        "Guardian veto needs the recovery account"
+    );
+    fixture.veto_withdrawal(&guardian, true).unwrap();
+    assert!(fixture.svm.get_account(&fixture.withdrawal_pda).is_none());
+    println!("✅ Guardian veto cancelled the withdrawal");
+}
+
+#[test]
+fn test_stranger_cannot_veto() {
+    println!("🧪 Testing veto by an unrelated signer");
+
+    let mut fixture = WithdrawLimitTestFixture::new();
+    fixture.create_vault(1_000_000).unwrap();
+    fixture.set_withdraw_policy(100_000, DAY, HOUR).unwrap();
+    fixture.queue_withdrawal(500_000).unwrap();
+
+    let stranger = Keypair::new();
+    fixture
+        .svm
+        .airdrop(&stranger.pubkey(), 1_000_000_000)
+        .unwrap();
+    assert!(
+        fixture.veto_withdrawal(&stranger, false).is_err(),
+        "Unrelated signer should not veto"
+    );
+    assert!(fixture.svm.get_account(&fixture.withdrawal_pda).is_some());
+    println!("✅ Stranger veto rejected");
+}
+
+#[test]
+fn test_loosening_policy_is_delayed() {
+    println!("🧪 Testing that raising the limit waits out the delay");
+
+    let mut fixture = WithdrawLimitTestFixture::new();
+    fixture.create_vault(1_000_000).unwrap();
+    fixture.set_withdraw_policy(100_000, DAY, HOUR).unwrap();
+
+    // Lifting the limit is staged rather than applied
+    fixture.set_withdraw_policy(0, 0, 0).unwrap();
+    assert!(
+        fixture.emergency_withdraw(500_000).is_err(),
+        "Loosened policy should not apply before the delay"
+    );
+
+    fixture.warp(HOUR);
+    fixture.emergency_withdraw(500_000).unwrap();
+    println!("✅ Loosened policy applied after the delay");
+}
+
+#[test]
+fn test_invalid_withdraw_policy() {
+    println!("🧪 Testing withdrawal policy validation");
+
+    let mut fixture = WithdrawLimitTestFixture::new();
+    fixture.create_vault(1_000_000).unwrap();
+
+    assert!(
+        fixture.set_withdraw_policy(100_000, 0, HOUR).is_err(),
+        "Zero period should fail"
+    );
+    assert!(
+        fixture.set_withdraw_policy(100_000, DAY, 60).is_err(),
+        "Delay below the minimum should fail"
+    );
+    assert_vault_error(
+        fixture.set_withdraw_policy(100_000, DAY, i64::MAX),
+        WITHDRAW_WINDOW_TOO_LONG,
+    );
+    assert_vault_error(
+        fixture.set_withdraw_policy(100_000, 366 * DAY, HOUR),
+        WITHDRAW_WINDOW_TOO_LONG,
+    );
+    println!("✅ Invalid withdrawal policies rejected");
+}
+
+#[test]
+fn test_close_vault_closes_recovery_and_queued_withdrawal() {
+    println!("🧪 Testing close of a vault with guardians and a queued withdrawal");
+
+    let mut fixture = WithdrawLimitTestFixture::new();
+    fixture.create_vault(1_000_000).unwrap();
+    fixture.set_guardian().unwrap();
+    fixture.queue_withdrawal(500_000).unwrap();
+    fixture.emergency_withdraw(1_000_000).unwrap();
+
+    let owner_lamports = fixture.svm.get_balance(&fixture.owner.pubkey()).unwrap();
+    fixture.close_vault().unwrap();
+    assert!(fixture.svm.get_account(&fixture.recovery_pda).is_none());
+    assert!(fixture.svm.get_account(&fixture.withdrawal_pda).is_none());
+    assert!(fixture.svm.get_balance(&fixture.owner.pubkey()).unwrap() > owner_lamports);
+    println!("✅ Recovery and queued withdrawal closed to the owner");
+
+    // A vault re-created at the same address starts without the old request
+    fixture.create_vault(1_000_000).unwrap();
+    fixture.queue_withdrawal(500_000).unwrap();
+    println!("✅ Re-created vault can queue a fresh withdrawal");
+}
+
+#[test]
+fn test_claim_closes_recovery_and_queued_withdrawal() {
+    println!("🧪 Testing claim of a vault with guardians and a queued withdrawal");
+
+    let mut fixture = WithdrawLimitTestFixture::new();
+    fixture.create_vault(1_000_000).unwrap();
+    fixture.set_guardian().unwrap();
+    fixture.set_withdraw_policy(100_000, DAY, DAY).unwrap();
+    fixture.queue_withdrawal(500_000).unwrap();
+    fixture.warp(HOUR);
+
+    fixture.claim().unwrap();
+    assert!(fixture.svm.get_account(&fixture.recovery_pda).is_none());
+    assert!(fixture.svm.get_account(&fixture.withdrawal_pda).is_none());
+    println!("✅ Recovery and queued withdrawal closed with the claimed vault");
+}
@@ -0,0 +1,408 @@
+use litesvm::LiteSVM;
+use litesvm_token::{get_spl_account, spl_token, CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    account::Account,
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    program_option::COption,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+
+const DEPOSIT: u64 = 2_000_000_000;
+const TX_FEE: u64 = 5_000;
+
+// VaultError codes
+const INVALID_CLAIM_DESTINATION: u32 = 6059;
+
+/// Test fixture for wSOL wrapping on deposit and unwrapping on payout
+struct WsolTestFixture {
+    svm: LiteSVM,
+    program_id: Pubkey,
+    owner: Keypair,
+    beneficiary: Keypair,
+    mint: Pubkey,
+    vault_pda: Pubkey,
+    vault_token_pda: Pubkey,
+    owner_registry_pda: Pubkey,
+    beneficiary_index_pda: Pubkey,
+    global_stats_pda: Pubkey,
+    mint_stats_pda: Pubkey,
+    unwrap_pda: Pubkey,
+}
+
+impl WsolTestFixture {
+    fn new() -> Self {
+        let mut svm = LiteSVM::new();
+
+        // Load the compiled program
+        let program_keypair =
+            read_keypair_file("target/deploy/cyber_vault_rs-keypair.json").unwrap();
+        let program_id = program_keypair.pubkey();
+        let program_bytes = include_bytes!("../target/deploy/cyber_vault_rs.so");
+        svm.add_program(program_id, program_bytes);
+
+        // LiteSVM does not ship the classic token program's native mint
+        let mut data = vec![0; spl_token::state::Mint::LEN];
+        spl_token::state::Mint {
+            mint_authority: COption::None,
+            supply: 0,
+            decimals: 9,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        }
+        .pack_into_slice(&mut data);
+        svm.set_account(
+            spl_token::native_mint::id(),
+            Account {
+                lamports: 1_000_000_000,
+                data,
+                owner: spl_token::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+        let owner = Keypair::new();
+        let beneficiary = Keypair::new();
+        svm.airdrop(&owner.pubkey(), 10_000_000_000).unwrap();
+        svm.airdrop(&beneficiary.pubkey(), 1_000_000_000).unwrap();
+
+        Self::with_mint(
+            svm,
+            program_id,
+            owner,
+            beneficiary,
+            spl_token::native_mint::id(),
+        )
+    }
+
+    fn with_mint(
+        svm: LiteSVM,
+        program_id: Pubkey,
+        owner: Keypair,
+        beneficiary: Keypair,
+        mint: Pubkey,
+    ) -> Self {
+        let (vault_pda, _) = Pubkey::find_program_address(
+            &[
+                b"vault",
+                owner.pubkey().as_ref(),
+                beneficiary.pubkey().as_ref(),
+                mint.as_ref(),
+            ],
+            &program_id,
+        );
+        let (vault_token_pda, _) =
+            Pubkey::find_program_address(&[b"vault_token", vault_pda.as_ref()], &program_id);
+        let (owner_registry_pda, _) = Pubkey::find_program_address(
+            &[b"owner_registry", owner.pubkey().as_ref()],
+            &program_id,
+        );
+        let (beneficiary_index_pda, _) = Pubkey::find_program_address(
+            &[b"beneficiary_index", beneficiary.pubkey().as_ref()],
+            &program_id,
+        );
+        let (global_stats_pda, _) = Pubkey::find_program_address(&[b"global_stats"], &program_id);
+        let (mint_stats_pda, _) =
+            Pubkey::find_program_address(&[b"mint_stats", mint.as_ref()], &program_id);
+        let (unwrap_pda, _) =
+            Pubkey::find_program_address(&[b"unwrap", vault_pda.as_ref()], &program_id);
+
+        Self {
+            svm,
+            program_id,
+            owner,
+            beneficiary,
+            mint,
+            vault_pda,
+            vault_token_pda,
+            owner_registry_pda,
+            beneficiary_index_pda,
+            global_stats_pda,
+            mint_stats_pda,
+            unwrap_pda,
+        }
+    }
+
+    /// Same fixture with an ordinary SPL mint instead of wSOL
+    fn new_spl() -> Self {
+        let fixture = Self::new();
+        let mut svm = fixture.svm;
+        let mint = CreateMint::new(&mut svm, &fixture.owner)
+            .authority(&fixture.owner.pubkey())
+            .decimals(6)
+            .send()
+            .unwrap();
+        Self::with_mint(
+            svm,
+            fixture.program_id,
+            fixture.owner,
+            fixture.beneficiary,
+            mint,
+        )
+    }
+
+    fn send(&mut self, instruction: Instruction, signer: &Keypair) -> Result<(), String> {
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        self.svm
+            .send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    /// Creates the vault; `None` deposits plain SOL instead of tokens
+    fn create_vault(&mut self, owner_token_account: Option<Pubkey>) -> Result<(), String> {
+        let inactivity_period: i64 = 3600;
+
+        // create_vault discriminator: [29, 237, 247, 208, 193, 82, 54, 135]
+        let mut instruction_data = vec![29, 237, 247, 208, 193, 82, 54, 135];
+        instruction_data.extend_from_slice(&self.beneficiary.pubkey().to_bytes());
+        instruction_data.extend_from_slice(&inactivity_period.to_le_bytes());
+        instruction_data.extend_from_slice(&DEPOSIT.to_le_bytes());
+        instruction_data.push(0); // acknowledge_freeze_authority
+        let (mint_policy_pda, _) =
+            Pubkey::find_program_address(&[b"mint_policy"], &self.program_id);
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.owner.pubkey(), true),
+                AccountMeta::new(self.vault_pda, false),
+                AccountMeta::new(self.vault_token_pda, false),
+                match owner_token_account {
+                    Some(account) => AccountMeta::new(account, false),
+                    None => AccountMeta::new_readonly(self.program_id, false),
+                },
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
+                AccountMeta::new(self.owner_registry_pda, false),
+                AccountMeta::new(self.beneficiary_index_pda, false),
+                AccountMeta::new(self.global_stats_pda, false),
+                AccountMeta::new(self.mint_stats_pda, false),
+                AccountMeta::new_readonly(mint_policy_pda, false),
+            ],
+            data: instruction_data,
+        };
+
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner)
+    }
+
+    fn claim_unwrapped(&mut self) -> Result<(), String> {
+        self.claim(false, true)
+    }
+
+    /// Claims to the beneficiary's ATA, the unwrap account, or (invalidly) both or neither
+    fn claim(&mut self, to_ata: bool, unwrap: bool) -> Result<(), String> {
+        let beneficiary = self.beneficiary.insecure_clone();
+        let beneficiary_ata = if to_ata {
+            AccountMeta::new(
+                get_associated_token_address(&beneficiary.pubkey(), &self.mint),
+                false,
+            )
+        } else {
+            AccountMeta::new_readonly(self.program_id, false)
+        };
+        let unwrap_account = if unwrap {
+            AccountMeta::new(self.unwrap_pda, false)
+        } else {
+            AccountMeta::new_readonly(self.program_id, false)
+        };
+
+        // claim discriminator: [62, 198, 214, 193, 213, 159, 108, 210]
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.vault_pda, false),
+                AccountMeta::new(self.vault_token_pda, false),
+                AccountMeta::new_readonly(self.mint, false),
+                beneficiary_ata,
+                AccountMeta::new(beneficiary.pubkey(), true),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new(self.owner_registry_pda, false),
+                AccountMeta::new(self.beneficiary_index_pda, false),
+                AccountMeta::new(self.global_stats_pda, false),
+                AccountMeta::new(self.mint_stats_pda, false),
+                AccountMeta::new_readonly(self.program_id, false), // no switch
+                unwrap_account,
+            ],
+            data: vec![62, 198, 214, 193, 213, 159, 108, 210],
+        };
+
+        self.send(instruction, &beneficiary)
+    }
+
+    fn emergency_withdraw_unwrapped(&mut self, amount: u64) -> Result<(), String> {
+        // emergency_withdraw discriminator: [239, 45, 203, 64, 150, 73, 218, 92]
+        let mut instruction_data = vec![239, 45, 203, 64, 150, 73, 218, 92];
+        instruction_data.extend_from_slice(&amount.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.owner.pubkey(), true),
+                AccountMeta::new(self.vault_pda, false),
+                AccountMeta::new_readonly(self.program_id, false), // no owner token account
+                AccountMeta::new(self.vault_token_pda, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new(self.mint_stats_pda, false),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new(self.unwrap_pda, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: instruction_data,
+        };
+
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner)
+    }
+
+    fn expire(&mut self) {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp += 3600;
+        self.svm.set_sysvar(&clock);
+    }
+
+    fn lamports(&self, account: &Pubkey) -> u64 {
+        self.svm.get_account(account).map_or(0, |a| a.lamports)
+    }
+
+    fn token_balance(&self, account: &Pubkey) -> u64 {
+        get_spl_account::<spl_token::state::Account>(&self.svm, account)
+            .unwrap()
+            .amount
+    }
+}
+
+#[test]
+fn test_create_vault_wraps_plain_sol() {
+    println!("🧪 Testing a vault funded with plain SOL");
+
+    let mut fixture = WsolTestFixture::new();
+    let owner = fixture.owner.pubkey();
+    let before = fixture.lamports(&owner);
+
+    fixture.create_vault(None).unwrap();
+
+    assert_eq!(fixture.token_balance(&fixture.vault_token_pda), DEPOSIT);
+    assert!(before - fixture.lamports(&owner) > DEPOSIT);
+    println!("✅ Deposited SOL wrapped into the vault's wSOL account");
+}
+
+#[test]
+fn test_claim_unwraps_to_native_sol() {
+    println!("🧪 Testing a claim paid out as native SOL");
+
+    let mut fixture = WsolTestFixture::new();
+    fixture.create_vault(None).unwrap();
+    fixture.expire();
+
+    let beneficiary = fixture.beneficiary.pubkey();
+    let before = fixture.lamports(&beneficiary);
+    fixture.claim_unwrapped().unwrap();
+
+    // The beneficiary also receives the vault's rent, and no ATA is created
+    assert!(fixture.lamports(&beneficiary) > before + DEPOSIT);
+    let ata = get_associated_token_address(&beneficiary, &fixture.mint);
+    assert!(fixture.svm.get_account(&ata).is_none());
+    assert!(fixture.svm.get_account(&fixture.unwrap_pda).is_none());
+    println!("✅ Beneficiary received native SOL, no wSOL left behind");
+}
+
+#[test]
+fn test_emergency_withdraw_unwraps_to_native_sol() {
+    println!("🧪 Testing an emergency withdrawal paid out as native SOL");
+
+    let mut fixture = WsolTestFixture::new();
+    fixture.create_vault(None).unwrap();
+
+    let owner = fixture.owner.pubkey();
+    let before = fixture.lamports(&owner);
+    fixture.emergency_withdraw_unwrapped(DEPOSIT / 2).unwrap();
+
+    // Rent for the temporary account is refunded when it closes
+    assert_eq!(fixture.lamports(&owner), before + DEPOSIT / 2 - TX_FEE);
+    assert_eq!(
+        fixture.token_balance(&fixture.vault_token_pda),
+        DEPOSIT - DEPOSIT / 2
+    );
+    assert!(fixture.svm.get_account(&fixture.unwrap_pda).is_none());
+    println!("✅ Owner received native SOL");
+
+    // The temporary account is gone, so the path can be used again
+    fixture.emergency_withdraw_unwrapped(DEPOSIT / 4).unwrap();
+    println!("✅ Repeated unwrap succeeded");
+}
+
+#[test]
+fn test_wrap_and_unwrap_require_native_mint() {
+    println!("🧪 Testing wSOL paths on a non-wSOL vault");
+
+    let mut fixture = WsolTestFixture::new_spl();
+    assert!(
+        fixture.create_vault(None).is_err(),
+        "Plain SOL deposit into a non-wSOL vault should fail"
+    );
+
+    let owner = fixture.owner.insecure_clone();
+    let mint = fixture.mint;
+    let owner_ata = CreateAssociatedTokenAccount::new(&mut fixture.svm, &owner, &mint)
+        .owner(&owner.pubkey())
+        .send()
+        .unwrap();
+    MintTo::new(&mut fixture.svm, &owner, &mint, &owner_ata, DEPOSIT)
+        .send()
+        .unwrap();
+    fixture.create_vault(Some(owner_ata)).unwrap();
+
+    assert!(
+        fixture.emergency_withdraw_unwrapped(1_000).is_err(),
+        "Unwrapping a non-wSOL withdrawal should fail"
+    );
+    fixture.expire();
+    assert!(
+        fixture.claim_unwrapped().is_err(),
+        "Unwrapping a non-wSOL claim should fail"
+    );
+    println!("✅ wSOL paths rejected for other mints");
+}
+
+#[test]
+fn test_claim_needs_exactly_one_destination() {
+    println!("🧪 Testing claim destinations");
+
+    let mut fixture = WsolTestFixture::new();
+    fixture.create_vault(None).unwrap();
+    fixture.expire();
+
+    for (to_ata, unwrap) in [(true, true), (false, false)] {
+        let err = fixture.claim(to_ata, unwrap).unwrap_err();
+        assert!(
+            err.contains(&format!("Custom({})", INVALID_CLAIM_DESTINATION)),
+            "expected InvalidClaimDestination, got {}",
+            err
+        );
+    }
+    println!("✅ Both or neither destination rejected");
+
+    fixture.claim(true, false).unwrap();
+    let ata = get_associated_token_address(&fixture.beneficiary.pubkey(), &fixture.mint);
+    assert_eq!(fixture.token_balance(&ata), DEPOSIT);
+    println!("✅ Claim to the ATA alone still pays out wSOL");
+}